@@ -0,0 +1,164 @@
+//! Benchmark baseline persistence and regression detection
+//!
+//! [`BenchmarkResult`] sets produced by a [`PerformanceMonitor`] run (most
+//! usefully after warming up via
+//! [`PerformanceMonitor::warm_up_and_benchmark`]) can be saved to a JSON
+//! baseline file with [`save_baseline`] and compared against on a later run
+//! with [`compare_to_baseline`], so a CI job for this crate can fail the
+//! build when ops/sec or latency regresses beyond a configurable threshold.
+
+use crate::{BenchmarkResult, CacheError, CacheResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A saved set of benchmark results, keyed by [`BenchmarkResult::name`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkBaseline {
+    /// Results this baseline was saved with, by benchmark name
+    pub results: HashMap<String, BenchmarkResult>,
+}
+
+impl BenchmarkBaseline {
+    /// Build a baseline from a set of results, keyed by their name.
+    pub fn from_results(results: &[BenchmarkResult]) -> Self {
+        Self {
+            results: results
+                .iter()
+                .map(|result| (result.name.clone(), result.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// Thresholds for deciding whether a benchmark result has regressed
+/// relative to a saved [`BenchmarkBaseline`].
+#[derive(Debug, Clone)]
+pub struct RegressionThresholds {
+    /// Maximum allowed relative drop in ops/sec (e.g. `0.1` = 10% slower
+    /// throughput is allowed before flagging a regression)
+    pub max_ops_per_second_drop: f64,
+    /// Maximum allowed relative increase in average latency (e.g. `0.1`
+    /// = 10% higher average latency is allowed before flagging a
+    /// regression)
+    pub max_avg_latency_increase: f64,
+}
+
+impl Default for RegressionThresholds {
+    fn default() -> Self {
+        Self {
+            max_ops_per_second_drop: 0.1,
+            max_avg_latency_increase: 0.1,
+        }
+    }
+}
+
+/// One benchmark's comparison against its baseline entry.
+#[derive(Debug, Clone)]
+pub struct RegressionReport {
+    /// Benchmark name
+    pub name: String,
+    /// Relative change in ops/sec versus the baseline (negative = slower)
+    pub ops_per_second_change: f64,
+    /// Relative change in average latency versus the baseline (positive
+    /// = slower)
+    pub avg_latency_change: f64,
+    /// Whether either threshold in the [`RegressionThresholds`] passed
+    /// to [`compare_to_baseline`] was exceeded
+    pub regressed: bool,
+}
+
+/// Save `results` as a JSON [`BenchmarkBaseline`] at `path`, overwriting
+/// any existing file there.
+pub fn save_baseline(path: impl AsRef<Path>, results: &[BenchmarkResult]) -> CacheResult<()> {
+    let baseline = BenchmarkBaseline::from_results(results);
+    let json = serde_json::to_vec_pretty(&baseline)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Load a previously saved [`BenchmarkBaseline`] from `path`.
+pub fn load_baseline(path: impl AsRef<Path>) -> CacheResult<BenchmarkBaseline> {
+    let json = std::fs::read(path)?;
+    let baseline: BenchmarkBaseline = serde_json::from_slice(&json)?;
+    Ok(baseline)
+}
+
+/// Compare `results` against `baseline`, reporting a [`RegressionReport`]
+/// for every result that has a matching baseline entry (results without
+/// one, e.g. a benchmark added since the baseline was saved, are skipped
+/// since there's nothing to compare against).
+pub fn compare_to_baseline(
+    results: &[BenchmarkResult],
+    baseline: &BenchmarkBaseline,
+    thresholds: &RegressionThresholds,
+) -> Vec<RegressionReport> {
+    results
+        .iter()
+        .filter_map(|result| {
+            let baseline_result = baseline.results.get(&result.name)?;
+
+            let ops_per_second_change = relative_change(
+                baseline_result.ops_per_second,
+                result.ops_per_second,
+            );
+            let avg_latency_change = relative_change(
+                baseline_result.avg_latency_us,
+                result.avg_latency_us,
+            );
+
+            let regressed = ops_per_second_change < -thresholds.max_ops_per_second_drop
+                || avg_latency_change > thresholds.max_avg_latency_increase;
+
+            Some(RegressionReport {
+                name: result.name.clone(),
+                ops_per_second_change,
+                avg_latency_change,
+                regressed,
+            })
+        })
+        .collect()
+}
+
+/// Run [`compare_to_baseline`] against the baseline saved at `path` and
+/// return an error naming every regressed benchmark, so this can be used
+/// directly as a CI regression gate.
+pub fn check_for_regressions(
+    path: impl AsRef<Path>,
+    results: &[BenchmarkResult],
+    thresholds: &RegressionThresholds,
+) -> CacheResult<()> {
+    let baseline = load_baseline(path)?;
+    let reports = compare_to_baseline(results, &baseline, thresholds);
+
+    let regressed: Vec<&RegressionReport> = reports.iter().filter(|report| report.regressed).collect();
+    if regressed.is_empty() {
+        return Ok(());
+    }
+
+    let details = regressed
+        .iter()
+        .map(|report| {
+            format!(
+                "{}: ops/sec {:+.1}%, avg latency {:+.1}%",
+                report.name,
+                report.ops_per_second_change * 100.0,
+                report.avg_latency_change * 100.0
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    Err(CacheError::generic(format!(
+        "benchmark regression detected: {details}"
+    )))
+}
+
+/// Relative change of `current` versus `baseline`, or `0.0` if `baseline`
+/// is zero.
+fn relative_change(baseline: f64, current: f64) -> f64 {
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current - baseline) / baseline
+}
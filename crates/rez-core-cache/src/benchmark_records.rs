@@ -0,0 +1,194 @@
+//! Benchmark result persistence and cross-run reporting
+//!
+//! `run_comprehensive_benchmarks` prints its `BenchmarkResult`s to stdout
+//! and discards them. [`BenchmarkRecord`] wraps a result with the context
+//! needed to make sense of it later — which preset `UnifiedCacheConfig`
+//! and `BenchmarkConfig` parameters produced it, what host and git commit
+//! it ran on, and when — and [`append_record`]/[`load_records`] persist a
+//! line-delimited JSON store of them so results accumulate across runs.
+//! [`group_by_config_and_benchmark`]/[`render_comparison_table`] then turn
+//! a loaded store into a trend report grouped by config and benchmark
+//! name, across however many runs have been recorded.
+
+use crate::{BenchmarkConfig, BenchmarkResult, CacheResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// One [`BenchmarkResult`] plus the context needed to compare it against
+/// others later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    /// Which preset `UnifiedCacheConfig` produced this result (e.g.
+    /// `"High Performance"`, `"Low Memory"`, `"Default"`)
+    pub config_name: String,
+    /// The `BenchmarkConfig` parameters the benchmark ran with
+    pub bench_config: BenchmarkConfig,
+    /// The measured result
+    pub result: BenchmarkResult,
+    /// Hostname the benchmark ran on, if it could be determined
+    pub host: Option<String>,
+    /// Git commit hash the benchmark ran at, if it could be determined
+    pub git_commit: Option<String>,
+    /// When this record was created (Unix seconds)
+    pub recorded_at_unix_secs: u64,
+}
+
+impl BenchmarkRecord {
+    /// Build a record for `result`, stamping it with the current host,
+    /// git commit, and time.
+    pub fn new(config_name: impl Into<String>, bench_config: BenchmarkConfig, result: BenchmarkResult) -> Self {
+        Self {
+            config_name: config_name.into(),
+            bench_config,
+            result,
+            host: current_host(),
+            git_commit: current_git_commit(),
+            recorded_at_unix_secs: SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// The machine's hostname, via `$HOSTNAME` or the `hostname` command,
+/// or `None` if neither is available.
+fn current_host() -> Option<String> {
+    std::env::var("HOSTNAME").ok().or_else(|| {
+        std::process::Command::new("hostname")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|host| host.trim().to_string())
+    })
+}
+
+/// The current `git rev-parse HEAD`, or `None` if not in a git repo or
+/// `git` isn't available.
+fn current_git_commit() -> Option<String> {
+    std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+}
+
+/// Append `record` as one line of JSON to the line-delimited store at
+/// `path`, creating the file if it doesn't already exist.
+pub fn append_record(path: impl AsRef<Path>, record: &BenchmarkRecord) -> CacheResult<()> {
+    let json = serde_json::to_string(record)?;
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{json}")?;
+    Ok(())
+}
+
+/// Load every record from the line-delimited store at `path`, in the
+/// order they were appended. Blank lines are skipped.
+pub fn load_records(path: impl AsRef<Path>) -> CacheResult<Vec<BenchmarkRecord>> {
+    let file = std::fs::File::open(path)?;
+    let reader = BufReader::new(file);
+
+    let mut records = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(serde_json::from_str(&line)?);
+    }
+
+    Ok(records)
+}
+
+/// One `(config_name, benchmark_name)` group's history across however
+/// many matching records were loaded, in the order they were appended.
+#[derive(Debug, Clone)]
+pub struct BenchmarkTrend {
+    /// Preset config name this trend tracks (e.g. `"High Performance"`)
+    pub config_name: String,
+    /// Benchmark name this trend tracks (e.g. `"sequential_operations"`)
+    pub benchmark_name: String,
+    /// Ops/sec across matching records, oldest first
+    pub ops_per_second_over_time: Vec<f64>,
+    /// Average latency (microseconds) across matching records, oldest first
+    pub avg_latency_us_over_time: Vec<f64>,
+}
+
+impl BenchmarkTrend {
+    /// Relative change in ops/sec from the earliest to the most recent
+    /// record in this trend, or `0.0` if fewer than two records were
+    /// recorded or the earliest was zero.
+    pub fn ops_per_second_change(&self) -> f64 {
+        if self.ops_per_second_over_time.len() < 2 {
+            return 0.0;
+        }
+
+        let first = self.ops_per_second_over_time[0];
+        let last = *self.ops_per_second_over_time.last().unwrap();
+        if first == 0.0 {
+            return 0.0;
+        }
+
+        (last - first) / first
+    }
+}
+
+/// Group `records` by `(config_name, result.name)`, preserving the order
+/// each group first appears in, so each config/benchmark pair's trend
+/// over time can be inspected independently of the others.
+pub fn group_by_config_and_benchmark(records: &[BenchmarkRecord]) -> Vec<BenchmarkTrend> {
+    let mut order: Vec<(String, String)> = Vec::new();
+    let mut grouped: HashMap<(String, String), BenchmarkTrend> = HashMap::new();
+
+    for record in records {
+        let key = (record.config_name.clone(), record.result.name.clone());
+        let trend = grouped.entry(key.clone()).or_insert_with(|| {
+            order.push(key.clone());
+            BenchmarkTrend {
+                config_name: record.config_name.clone(),
+                benchmark_name: record.result.name.clone(),
+                ops_per_second_over_time: Vec::new(),
+                avg_latency_us_over_time: Vec::new(),
+            }
+        });
+        trend.ops_per_second_over_time.push(record.result.ops_per_second);
+        trend.avg_latency_us_over_time.push(record.result.avg_latency_us);
+    }
+
+    order
+        .into_iter()
+        .map(|key| grouped.remove(&key).expect("every order entry was just inserted into grouped"))
+        .collect()
+}
+
+/// Render `trends` as a plain-text comparison table, one row per
+/// `(config_name, benchmark_name)` group, showing the most recent
+/// ops/sec and average latency plus the relative change in ops/sec since
+/// the earliest recorded run.
+pub fn render_comparison_table(trends: &[BenchmarkTrend]) -> String {
+    let mut lines = vec![format!(
+        "{:<20} {:<30} {:>15} {:>18} {:>12}",
+        "config", "benchmark", "ops/sec", "avg latency (us)", "ops/sec Δ"
+    )];
+
+    for trend in trends {
+        let latest_ops = trend.ops_per_second_over_time.last().copied().unwrap_or(0.0);
+        let latest_latency = trend.avg_latency_us_over_time.last().copied().unwrap_or(0.0);
+        lines.push(format!(
+            "{:<20} {:<30} {:>15.2} {:>18.2} {:>+11.1}%",
+            trend.config_name,
+            trend.benchmark_name,
+            latest_ops,
+            latest_latency,
+            trend.ops_per_second_change() * 100.0
+        ));
+    }
+
+    lines.join("\n")
+}
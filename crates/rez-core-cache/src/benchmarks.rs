@@ -6,9 +6,12 @@ use crate::{
     IntelligentCacheManager, UnifiedCacheConfig, UnifiedCache,
     PerformanceMonitor, BenchmarkResult,
 };
+use crate::profiler::Profiler;
+use crate::{fit_linear_cost_model, BenchmarkRecord, CacheTierStats, LinearCostModel, Workpool};
 use std::{
+    sync::atomic::{AtomicU64, Ordering},
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::time::sleep;
 
@@ -108,36 +111,29 @@ impl CacheBenchmarkSuite {
     pub async fn benchmark_concurrent_operations(&self) -> BenchmarkResult {
         self.monitor.run_benchmark("concurrent_operations", || async {
             let test_data = Arc::new(self.generate_test_data());
-            let cache = Arc::clone(&self.cache);
-            
-            let mut handles = Vec::new();
-            
-            for worker_id in 0..self.config.worker_count {
-                let cache = Arc::clone(&cache);
+            let pool = Workpool::new(self.config.worker_count, self.config.worker_count * 4);
+
+            let jobs = (0..self.config.operations_count).map(|i| {
+                let cache = Arc::clone(&self.cache);
                 let test_data = Arc::clone(&test_data);
-                let ops_per_worker = self.config.operations_count / self.config.worker_count;
-                
-                let handle = tokio::spawn(async move {
-                    for i in 0..ops_per_worker {
-                        let key_index = (worker_id * ops_per_worker + i) % test_data.len();
-                        let (key, value) = &test_data[key_index];
-                        
-                        if i % 5 == 0 {
-                            // Write operation
-                            let _ = cache.put(key.clone(), value.clone()).await;
-                        } else {
-                            // Read operation
-                            let _ = cache.get(key).await;
-                        }
+                async move {
+                    let (key, value) = &test_data[i % test_data.len()];
+
+                    if i % 5 == 0 {
+                        // Write operation
+                        let _ = cache.put(key.clone(), value.clone()).await;
+                    } else {
+                        // Read operation
+                        let _ = cache.get(key).await;
                     }
-                });
-                
-                handles.push(handle);
-            }
-            
-            // Wait for all workers to complete
-            for handle in handles {
-                let _ = handle.await;
+                }
+            });
+
+            let not_submitted = pool.execute_and_finish(jobs).await;
+            if not_submitted > 0 {
+                eprintln!(
+                    "concurrent_operations: pool closed early, {not_submitted} operations not submitted"
+                );
             }
         }).await
     }
@@ -274,35 +270,32 @@ impl CacheBenchmarkSuite {
     /// Benchmark high contention scenarios
     pub async fn benchmark_high_contention(&self) -> BenchmarkResult {
         self.monitor.run_benchmark("high_contention", || async {
-            let hot_keys = vec!["hot_key_1", "hot_key_2", "hot_key_3"];
-            let test_value = vec![0u8; self.config.value_size];
-            
-            let cache = Arc::clone(&self.cache);
-            let mut handles = Vec::new();
-            
+            let hot_keys = Arc::new(vec!["hot_key_1", "hot_key_2", "hot_key_3"]);
+            let test_value = Arc::new(vec![0u8; self.config.value_size]);
+            let worker_count = self.config.worker_count * 2;
+            let pool = Workpool::new(worker_count, worker_count * 4);
+
             // Multiple workers accessing the same hot keys
-            for _ in 0..self.config.worker_count * 2 {
-                let cache = Arc::clone(&cache);
-                let hot_keys = hot_keys.clone();
-                let test_value = test_value.clone();
-                
-                let handle = tokio::spawn(async move {
-                    for _ in 0..1000 {
-                        let key = &hot_keys[rand::random::<usize>() % hot_keys.len()];
-                        
-                        if rand::random::<bool>() {
-                            let _ = cache.get(&key.to_string()).await;
-                        } else {
-                            let _ = cache.put(key.to_string(), test_value.clone()).await;
-                        }
+            let jobs = (0..worker_count * 1000).map(|_| {
+                let cache = Arc::clone(&self.cache);
+                let hot_keys = Arc::clone(&hot_keys);
+                let test_value = Arc::clone(&test_value);
+                async move {
+                    let key = hot_keys[rand::random::<usize>() % hot_keys.len()];
+
+                    if rand::random::<bool>() {
+                        let _ = cache.get(&key.to_string()).await;
+                    } else {
+                        let _ = cache.put(key.to_string(), (*test_value).clone()).await;
                     }
-                });
-                
-                handles.push(handle);
-            }
-            
-            for handle in handles {
-                let _ = handle.await;
+                }
+            });
+
+            let not_submitted = pool.execute_and_finish(jobs).await;
+            if not_submitted > 0 {
+                eprintln!(
+                    "high_contention: pool closed early, {not_submitted} operations not submitted"
+                );
             }
         }).await
     }
@@ -349,6 +342,214 @@ impl CacheBenchmarkSuite {
     pub async fn get_performance_metrics(&self) -> crate::PerformanceMetrics {
         self.monitor.get_performance_metrics().await
     }
+
+    /// Run `benchmark_sequential_operations` and report the per-tier
+    /// operation counts (L1/L2 hits, promotions, demotions, backing-store
+    /// writes) accumulated strictly during the run, alongside the usual
+    /// aggregate [`BenchmarkResult`].
+    pub async fn benchmark_sequential_operations_with_tier_stats(
+        &self,
+    ) -> (BenchmarkResult, CacheTierStats) {
+        let before = self.cache.tier_counters().snapshot();
+        let result = self.benchmark_sequential_operations().await;
+        let after = self.cache.tier_counters().snapshot();
+
+        (result, after.saturating_sub(before))
+    }
+
+    /// Drive the cache at a fixed target throughput for a fixed wall-clock
+    /// duration, sampling `profilers` on `sample_interval` along the way.
+    ///
+    /// Unlike the `benchmark_*` methods above, which race `worker_count`
+    /// workers through a fixed `operations_count` as fast as possible, this
+    /// throttles each worker to an even share of `target_ops_per_second` and
+    /// runs for `bench_length` regardless of how fast the cache could go —
+    /// useful for reproducing a steady-state production load rather than
+    /// measuring peak throughput.
+    pub async fn run_load_driver(
+        &self,
+        load_config: LoadDriverConfig,
+        mut profilers: Vec<Box<dyn Profiler>>,
+    ) -> LoadDriverReport {
+        let test_data = Arc::new(self.generate_test_data());
+        let worker_count = self.config.worker_count.max(1);
+        let per_worker_ops_per_second =
+            (load_config.target_ops_per_second / worker_count as f64).max(f64::MIN_POSITIVE);
+        let per_worker_interval = Duration::from_secs_f64(1.0 / per_worker_ops_per_second);
+        let read_write_ratio = self.config.read_write_ratio;
+
+        let operations_completed = Arc::new(AtomicU64::new(0));
+        let start = Instant::now();
+        let deadline = start + load_config.bench_length;
+
+        let mut handles = Vec::with_capacity(worker_count);
+        for _ in 0..worker_count {
+            let cache = Arc::clone(&self.cache);
+            let test_data = Arc::clone(&test_data);
+            let operations_completed = Arc::clone(&operations_completed);
+
+            handles.push(tokio::spawn(async move {
+                let mut next_tick = Instant::now();
+                while Instant::now() < deadline {
+                    let now = Instant::now();
+                    if next_tick > now {
+                        sleep(next_tick - now).await;
+                    }
+                    next_tick += per_worker_interval;
+
+                    let (key, value) = &test_data[rand::random::<usize>() % test_data.len()];
+                    if rand::random::<f64>() < read_write_ratio {
+                        let _ = cache.get(key).await;
+                    } else {
+                        let _ = cache.put(key.clone(), value.clone()).await;
+                    }
+
+                    operations_completed.fetch_add(1, Ordering::Relaxed);
+                }
+            }));
+        }
+
+        while Instant::now() < deadline {
+            let stats = self.cache.get_stats().await;
+            for profiler in profilers.iter_mut() {
+                profiler.sample(&stats, start.elapsed());
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            sleep(load_config.sample_interval.min(remaining)).await;
+        }
+
+        for handle in handles {
+            let _ = handle.await;
+        }
+
+        let elapsed = start.elapsed();
+        let operations_completed = operations_completed.load(Ordering::Relaxed);
+
+        LoadDriverReport {
+            operations_completed,
+            achieved_ops_per_second: operations_completed as f64 / elapsed.as_secs_f64(),
+            elapsed,
+            profiler_reports: profilers.iter().map(|profiler| profiler.report()).collect(),
+        }
+    }
+}
+
+/// Configuration for [`CacheBenchmarkSuite::run_load_driver`]'s fixed-rate,
+/// fixed-duration run, as distinct from [`BenchmarkConfig`]'s
+/// fixed-operation-count benchmarks.
+#[derive(Debug, Clone)]
+pub struct LoadDriverConfig {
+    /// Target aggregate operations per second across all workers
+    pub target_ops_per_second: f64,
+    /// How long to drive load for
+    pub bench_length: Duration,
+    /// How often to sample registered profilers
+    pub sample_interval: Duration,
+}
+
+impl Default for LoadDriverConfig {
+    fn default() -> Self {
+        Self {
+            target_ops_per_second: 1_000.0,
+            bench_length: Duration::from_secs(30),
+            sample_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Outcome of a [`CacheBenchmarkSuite::run_load_driver`] run.
+#[derive(Debug, Clone)]
+pub struct LoadDriverReport {
+    /// Total operations actually completed across all workers
+    pub operations_completed: u64,
+    /// Actual achieved operations per second over the run
+    pub achieved_ops_per_second: f64,
+    /// Wall-clock time the run actually took
+    pub elapsed: Duration,
+    /// Reports from each profiler passed in, in the same order
+    pub profiler_reports: Vec<String>,
+}
+
+/// Run `benchmark_sequential_operations` once per entry in `value_sizes`
+/// (each against a fresh [`CacheBenchmarkSuite`] built from
+/// `cache_config`/`base_config` with `value_size` overridden) and fit a
+/// [`LinearCostModel`] of average latency against `value_size`, so
+/// "fixed overhead" and "cost per byte" can be reported separately
+/// instead of one opaque per-size latency number.
+pub async fn fit_value_size_cost_model(
+    cache_config: UnifiedCacheConfig,
+    base_config: BenchmarkConfig,
+    value_sizes: &[usize],
+) -> Option<LinearCostModel> {
+    let mut points = Vec::with_capacity(value_sizes.len());
+    for &value_size in value_sizes {
+        let bench_config = BenchmarkConfig {
+            value_size,
+            ..base_config.clone()
+        };
+        let suite = CacheBenchmarkSuite::new(cache_config.clone(), bench_config);
+        let result = suite.benchmark_sequential_operations().await;
+        points.push((value_size as f64, result.avg_latency_us));
+    }
+
+    fit_linear_cost_model(&points)
+}
+
+/// Run `benchmark_sequential_operations` once per entry in
+/// `key_space_sizes` and fit a [`LinearCostModel`] of average latency
+/// against `key_space_size`, the same way
+/// [`fit_value_size_cost_model`] does for `value_size`.
+pub async fn fit_key_space_size_cost_model(
+    cache_config: UnifiedCacheConfig,
+    base_config: BenchmarkConfig,
+    key_space_sizes: &[usize],
+) -> Option<LinearCostModel> {
+    let mut points = Vec::with_capacity(key_space_sizes.len());
+    for &key_space_size in key_space_sizes {
+        let bench_config = BenchmarkConfig {
+            key_space_size,
+            ..base_config.clone()
+        };
+        let suite = CacheBenchmarkSuite::new(cache_config.clone(), bench_config);
+        let result = suite.benchmark_sequential_operations().await;
+        points.push((key_space_size as f64, result.avg_latency_us));
+    }
+
+    fit_linear_cost_model(&points)
+}
+
+/// Runs the same configurations as [`run_comprehensive_benchmarks`], but
+/// instead of printing the results persists each one as a
+/// [`BenchmarkRecord`] appended to the line-delimited store at
+/// `store_path` (see [`crate::append_record`]), so results accumulate
+/// across runs. Returns the records created by this run.
+pub async fn run_comprehensive_benchmarks_with_records(
+    store_path: impl AsRef<std::path::Path>,
+) -> crate::CacheResult<Vec<BenchmarkRecord>> {
+    let configs = vec![
+        ("High Performance", UnifiedCacheConfig::high_performance()),
+        ("Low Memory", UnifiedCacheConfig::low_memory()),
+        ("Default", UnifiedCacheConfig::default()),
+    ];
+
+    let mut records = Vec::new();
+    for (config_name, cache_config) in configs {
+        let bench_config = BenchmarkConfig::default();
+        let suite = CacheBenchmarkSuite::new(cache_config, bench_config.clone());
+        let results = suite.run_all_benchmarks().await;
+
+        for result in results {
+            let record = BenchmarkRecord::new(config_name, bench_config.clone(), result);
+            crate::append_record(store_path.as_ref(), &record)?;
+            records.push(record);
+        }
+    }
+
+    Ok(records)
 }
 
 /// Run a comprehensive benchmark suite
@@ -0,0 +1,90 @@
+//! Cache Load Driver
+//!
+//! Drives the intelligent cache at a configurable fixed rate for a fixed
+//! duration, reporting from whichever profilers are selected. Flags:
+//!
+//! - `--bench-length-seconds <N>` (default 30)
+//! - `--operations-per-second <N>` (default 1000)
+//! - `--profilers <comma-separated>` (default "cpu,system,cache")
+
+use rez_core_cache::{
+    parse_profiler_list, CacheBenchmarkSuite, LoadDriverConfig, UnifiedCacheConfig,
+};
+use std::time::Duration;
+
+struct CliArgs {
+    bench_length_seconds: u64,
+    operations_per_second: f64,
+    profilers: String,
+}
+
+impl Default for CliArgs {
+    fn default() -> Self {
+        Self {
+            bench_length_seconds: 30,
+            operations_per_second: 1_000.0,
+            profilers: "cpu,system,cache".to_string(),
+        }
+    }
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<CliArgs, String> {
+    let mut parsed = CliArgs::default();
+    let mut args = args.peekable();
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{flag} requires a value"));
+
+        match flag.as_str() {
+            "--bench-length-seconds" => {
+                parsed.bench_length_seconds = value()?
+                    .parse()
+                    .map_err(|_| "--bench-length-seconds expects an integer".to_string())?;
+            }
+            "--operations-per-second" => {
+                parsed.operations_per_second = value()?
+                    .parse()
+                    .map_err(|_| "--operations-per-second expects a number".to_string())?;
+            }
+            "--profilers" => {
+                parsed.profilers = value()?;
+            }
+            other => return Err(format!("unknown flag '{other}'")),
+        }
+    }
+
+    Ok(parsed)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = parse_args(std::env::args().skip(1)).map_err(|err| -> Box<dyn std::error::Error> { err.into() })?;
+
+    let profilers = parse_profiler_list(&args.profilers)?;
+
+    println!(
+        "Driving cache load: {} ops/sec for {}s, profilers: {}",
+        args.operations_per_second, args.bench_length_seconds, args.profilers
+    );
+
+    let suite = CacheBenchmarkSuite::new(UnifiedCacheConfig::default(), Default::default());
+    let load_config = LoadDriverConfig {
+        target_ops_per_second: args.operations_per_second,
+        bench_length: Duration::from_secs(args.bench_length_seconds),
+        ..Default::default()
+    };
+
+    let report = suite.run_load_driver(load_config, profilers).await;
+
+    println!(
+        "Completed {} operations in {:.2}s ({:.1} ops/sec achieved)",
+        report.operations_completed,
+        report.elapsed.as_secs_f64(),
+        report.achieved_ops_per_second
+    );
+    for profiler_report in &report.profiler_reports {
+        println!("  {profiler_report}");
+    }
+
+    Ok(())
+}
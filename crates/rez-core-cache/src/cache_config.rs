@@ -92,6 +92,8 @@ pub struct L2CacheConfig {
     pub cleanup_interval: u64,
     /// Enable background cleanup
     pub enable_background_cleanup: bool,
+    /// Serialization backend used to encode entries for disk storage
+    pub serialization_backend: crate::serialization::SerializationBackend,
 }
 
 impl Default for L2CacheConfig {
@@ -104,6 +106,7 @@ impl Default for L2CacheConfig {
             enable_compression: true,
             cleanup_interval: 300, // 5 minutes
             enable_background_cleanup: true,
+            serialization_backend: crate::serialization::SerializationBackend::default(),
         }
     }
 }
@@ -274,6 +277,7 @@ impl UnifiedCacheConfig {
                 max_disk_bytes: 5 * 1024 * 1024 * 1024, // 5 GB
                 enable_compression: true,
                 enable_background_cleanup: true,
+                serialization_backend: crate::serialization::SerializationBackend::Bincode,
                 ..Default::default()
             },
             preheating_config: PreheatingConfig {
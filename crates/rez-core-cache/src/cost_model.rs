@@ -0,0 +1,74 @@
+//! Least-squares linear cost model fitting
+//!
+//! A single `BenchmarkResult` at one parameter value (e.g. one
+//! `value_size`) reports an opaque latency number. Running the same
+//! benchmark at several parameter values and fitting a linear model
+//! `latency = a + b * param` separates that into a fixed cost `a`
+//! (per-operation setup/lookup overhead, independent of the parameter)
+//! and a marginal cost `b` (e.g. cost per byte or per key), which is far
+//! more actionable for deciding where to optimize.
+
+/// A fitted `latency = fixed_cost + marginal_cost * param` model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearCostModel {
+    /// `a`: the fixed, per-operation cost independent of the parameter
+    pub fixed_cost: f64,
+    /// `b`: the marginal cost per unit of the parameter (e.g. per byte
+    /// of `value_size`, or per key of `key_space_size`)
+    pub marginal_cost: f64,
+    /// Coefficient of determination, how well the line fits the points
+    /// (`1.0` = perfect fit, `0.0` = no better than the mean)
+    pub r_squared: f64,
+}
+
+/// Fit a [`LinearCostModel`] to `points` (`(param, latency)` pairs) via
+/// ordinary least squares:
+///
+/// ```text
+/// b = (nΣxy − ΣxΣy) / (nΣx² − (Σx)²)
+/// a = (Σy − bΣx) / n
+/// ```
+///
+/// Returns `None` if fewer than two points are given, or if every point
+/// shares the same parameter value (the denominator would be zero).
+pub fn fit_linear_cost_model(points: &[(f64, f64)]) -> Option<LinearCostModel> {
+    let n = points.len();
+    if n < 2 {
+        return None;
+    }
+    let n = n as f64;
+
+    let sum_x: f64 = points.iter().map(|(x, _)| x).sum();
+    let sum_y: f64 = points.iter().map(|(_, y)| y).sum();
+    let sum_xy: f64 = points.iter().map(|(x, y)| x * y).sum();
+    let sum_x2: f64 = points.iter().map(|(x, _)| x * x).sum();
+
+    let denominator = n * sum_x2 - sum_x * sum_x;
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let marginal_cost = (n * sum_xy - sum_x * sum_y) / denominator;
+    let fixed_cost = (sum_y - marginal_cost * sum_x) / n;
+
+    let mean_y = sum_y / n;
+    let ss_tot: f64 = points.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+    let r_squared = if ss_tot == 0.0 {
+        1.0
+    } else {
+        let ss_res: f64 = points
+            .iter()
+            .map(|(x, y)| {
+                let predicted = fixed_cost + marginal_cost * x;
+                (y - predicted).powi(2)
+            })
+            .sum();
+        1.0 - ss_res / ss_tot
+    };
+
+    Some(LinearCostModel {
+        fixed_cost,
+        marginal_cost,
+        r_squared,
+    })
+}
@@ -58,6 +58,10 @@ pub enum CacheError {
     #[error("Cache level {level} error: {message}")]
     CacheLevelError { level: String, message: String },
 
+    /// Cross-process advisory file lock error
+    #[error("Cache file lock error on {path}: {message}")]
+    FileLock { path: String, message: String },
+
     /// Generic cache error
     #[error("Cache error: {0}")]
     Generic(String),
@@ -132,6 +136,14 @@ impl CacheError {
         Self::Generic(message.into())
     }
 
+    /// Create a new file lock error
+    pub fn file_lock<P: Into<String>, S: Into<String>>(path: P, message: S) -> Self {
+        Self::FileLock {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
     /// Check if the error is recoverable
     pub fn is_recoverable(&self) -> bool {
         match self {
@@ -148,6 +160,7 @@ impl CacheError {
             CacheError::PreheatingError(_) => true,
             CacheError::TuningError(_) => true,
             CacheError::CacheLevelError { .. } => true,
+            CacheError::FileLock { .. } => true,
             CacheError::Generic(_) => false,
         }
     }
@@ -168,6 +181,7 @@ impl CacheError {
             CacheError::PreheatingError(_) => ErrorSeverity::Low,
             CacheError::TuningError(_) => ErrorSeverity::Low,
             CacheError::CacheLevelError { .. } => ErrorSeverity::Medium,
+            CacheError::FileLock { .. } => ErrorSeverity::Medium,
             CacheError::Generic(_) => ErrorSeverity::Medium,
         }
     }
@@ -0,0 +1,103 @@
+//! Cross-process advisory file locking for the L2 (disk) cache
+//!
+//! Multiple `rez` processes can share the same L2 cache directory. Without
+//! coordination, two processes racing to write the same cache entry can
+//! corrupt it (partial writes interleaving) or waste work re-populating an
+//! entry the other process just wrote. This module provides a thin wrapper
+//! around OS-level advisory file locks (`flock` on Unix, `LockFileEx` on
+//! Windows via the `fs2` crate), one lock file per cache key inside a lock
+//! directory, so concurrent processes serialize around the same entry.
+
+use crate::error::CacheError;
+use fs2::FileExt;
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+/// An advisory lock held on a single file for the lifetime of this guard.
+/// Dropping the guard releases the lock.
+pub struct CacheFileLockGuard {
+    _file: File,
+}
+
+/// Acquires advisory locks scoped to a cache directory, one lock file per
+/// logical cache key so unrelated entries don't serialize against each
+/// other.
+#[derive(Debug, Clone)]
+pub struct CacheFileLock {
+    /// Directory the lock files live in (normally the L2 cache directory)
+    lock_dir: PathBuf,
+}
+
+impl CacheFileLock {
+    /// Create a lock manager rooted at `lock_dir`. The directory is
+    /// created lazily on first use, not here.
+    pub fn new(lock_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            lock_dir: lock_dir.into(),
+        }
+    }
+
+    /// Path of the lock file for a given cache key.
+    fn lock_file_path(&self, key: &str) -> PathBuf {
+        let safe_key = key.replace(['/', '\\'], "_");
+        self.lock_dir.join(format!("{}.lock", safe_key))
+    }
+
+    /// Block until an exclusive lock for `key` is acquired, creating the
+    /// lock directory and file if necessary. Returns a guard that releases
+    /// the lock on drop.
+    pub fn lock_exclusive(&self, key: &str) -> Result<CacheFileLockGuard, CacheError> {
+        let path = self.lock_file_path(key);
+        let file = self.open_lock_file(key)?;
+        file.lock_exclusive().map_err(|e| {
+            CacheError::file_lock(
+                path.display().to_string(),
+                format!("Failed to acquire exclusive lock for '{}': {}", key, e),
+            )
+        })?;
+        Ok(CacheFileLockGuard { _file: file })
+    }
+
+    /// Block until a shared (read) lock for `key` is acquired.
+    pub fn lock_shared(&self, key: &str) -> Result<CacheFileLockGuard, CacheError> {
+        let path = self.lock_file_path(key);
+        let file = self.open_lock_file(key)?;
+        file.lock_shared().map_err(|e| {
+            CacheError::file_lock(
+                path.display().to_string(),
+                format!("Failed to acquire shared lock for '{}': {}", key, e),
+            )
+        })?;
+        Ok(CacheFileLockGuard { _file: file })
+    }
+
+    /// Try to acquire an exclusive lock without blocking. Returns `Ok(None)`
+    /// rather than erroring if another process already holds it.
+    pub fn try_lock_exclusive(&self, key: &str) -> Result<Option<CacheFileLockGuard>, CacheError> {
+        let path = self.lock_file_path(key);
+        let file = self.open_lock_file(key)?;
+        match file.try_lock_exclusive() {
+            Ok(()) => Ok(Some(CacheFileLockGuard { _file: file })),
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
+            Err(e) => Err(CacheError::file_lock(
+                path.display().to_string(),
+                format!("Failed to try-lock '{}': {}", key, e),
+            )),
+        }
+    }
+
+    fn open_lock_file(&self, key: &str) -> Result<File, CacheError> {
+        std::fs::create_dir_all(&self.lock_dir)?;
+        let path = self.lock_file_path(key);
+        Ok(OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(path)?)
+    }
+
+    /// Directory lock files are written to
+    pub fn lock_dir(&self) -> &Path {
+        &self.lock_dir
+    }
+}
@@ -5,15 +5,18 @@
 
 use crate::{
     UnifiedCache, UnifiedCacheConfig, UnifiedCacheStats, CacheError,
-    PredictivePreheater, AdaptiveTuner, PerformanceMonitor,
+    PredictivePreheater, AdaptiveTuner, PerformanceMonitor, TierOperationCounters,
+    serialization::CacheSerializer,
 };
 use dashmap::DashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use std::{
     collections::HashMap,
     hash::Hash,
+    path::PathBuf,
     sync::{Arc, RwLock},
-    time::{Duration, Instant, SystemTime},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tokio::sync::RwLock as AsyncRwLock;
 use async_trait::async_trait;
@@ -88,6 +91,56 @@ impl<V> MultiLevelCacheEntry<V> {
     }
 }
 
+/// On-disk representation of a [`MultiLevelCacheEntry`]. `SystemTime` has
+/// no `Serialize` impl, so timestamps are stored as Unix-epoch seconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedL2Entry<V> {
+    value: V,
+    created_at_secs: u64,
+    last_accessed_secs: u64,
+    access_count: u64,
+    size_bytes: u64,
+    ttl: u64,
+    prediction_score: f64,
+}
+
+impl<V: Clone> From<&MultiLevelCacheEntry<V>> for PersistedL2Entry<V> {
+    fn from(entry: &MultiLevelCacheEntry<V>) -> Self {
+        Self {
+            value: entry.value.clone(),
+            created_at_secs: system_time_to_secs(entry.created_at),
+            last_accessed_secs: system_time_to_secs(entry.last_accessed),
+            access_count: entry.access_count,
+            size_bytes: entry.size_bytes,
+            ttl: entry.ttl,
+            prediction_score: entry.prediction_score,
+        }
+    }
+}
+
+impl<V> From<PersistedL2Entry<V>> for MultiLevelCacheEntry<V> {
+    fn from(persisted: PersistedL2Entry<V>) -> Self {
+        Self {
+            value: persisted.value,
+            created_at: secs_to_system_time(persisted.created_at_secs),
+            last_accessed: secs_to_system_time(persisted.last_accessed_secs),
+            access_count: persisted.access_count,
+            level: 2,
+            size_bytes: persisted.size_bytes,
+            ttl: persisted.ttl,
+            prediction_score: persisted.prediction_score,
+        }
+    }
+}
+
+fn system_time_to_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn secs_to_system_time(secs: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
 /// Intelligent Cache Manager
 ///
 /// Coordinates multi-level caching with predictive preheating and adaptive tuning.
@@ -96,7 +149,7 @@ impl<V> MultiLevelCacheEntry<V> {
 pub struct IntelligentCacheManager<K, V> 
 where
     K: Clone + Hash + Eq + Send + Sync + std::fmt::Debug + 'static,
-    V: Clone + Send + Sync + std::fmt::Debug + 'static,
+    V: Clone + Send + Sync + std::fmt::Debug + Serialize + DeserializeOwned + 'static,
 {
     /// Configuration
     config: UnifiedCacheConfig,
@@ -112,21 +165,30 @@ where
     monitor: Arc<PerformanceMonitor>,
     /// Cache statistics
     stats: Arc<RwLock<UnifiedCacheStats>>,
+    /// Per-tier operation counters, independent of `stats`, surfaced by
+    /// benchmarks that need to know which tier actually served each op
+    tier_counters: Arc<TierOperationCounters>,
     /// Access pattern tracking
     access_patterns: Arc<RwLock<HashMap<K, Vec<SystemTime>>>>,
+    /// Cross-process advisory locks guarding L2 entries, so multiple
+    /// `rez` processes sharing the same L2 cache directory don't race to
+    /// demote the same key. Eviction, promotion, `remove` and `clear`
+    /// delete entry files without taking these locks.
+    file_lock: crate::file_lock::CacheFileLock,
 }
 
 impl<K, V> IntelligentCacheManager<K, V>
 where
     K: Clone + Hash + Eq + Send + Sync + std::fmt::Debug + 'static,
-    V: Clone + Send + Sync + std::fmt::Debug + 'static,
+    V: Clone + Send + Sync + std::fmt::Debug + Serialize + DeserializeOwned + 'static,
 {
     /// Create a new intelligent cache manager
     pub fn new(config: UnifiedCacheConfig) -> Self {
         let preheater = Arc::new(PredictivePreheater::new(config.preheating_config.clone()));
         let tuner = Arc::new(AdaptiveTuner::new(config.tuning_config.clone()));
         let monitor = Arc::new(PerformanceMonitor::new(config.monitoring_config.clone()));
-        
+        let file_lock = crate::file_lock::CacheFileLock::new(config.l2_config.cache_dir.join("locks"));
+
         Self {
             config,
             l1_cache: Arc::new(DashMap::new()),
@@ -135,7 +197,9 @@ where
             tuner,
             monitor,
             stats: Arc::new(RwLock::new(UnifiedCacheStats::default())),
+            tier_counters: Arc::new(TierOperationCounters::new()),
             access_patterns: Arc::new(RwLock::new(HashMap::new())),
+            file_lock,
         }
     }
 
@@ -159,6 +223,11 @@ where
         Arc::clone(&self.monitor)
     }
 
+    /// Get per-tier operation counters
+    pub fn tier_counters(&self) -> Arc<TierOperationCounters> {
+        Arc::clone(&self.tier_counters)
+    }
+
     /// Record access pattern for predictive preheating
     async fn record_access_pattern(&self, key: &K) {
         if !self.config.preheating_config.enable_pattern_learning {
@@ -180,6 +249,88 @@ where
         }
     }
 
+    /// Path of the on-disk L2 entry file for `key`, under the L2 cache
+    /// directory (separate from `file_lock`'s `locks` subdirectory).
+    fn l2_entry_path(&self, key: &K) -> PathBuf {
+        let safe_key = format!("{:?}", key).replace(['/', '\\'], "_");
+        self.config.l2_config.cache_dir.join(format!("{}.entry", safe_key))
+    }
+
+    /// Write an L2 entry to disk using the configured serialization
+    /// backend. Callers are expected to hold the `file_lock` for `key`.
+    async fn write_l2_entry_to_disk(
+        &self,
+        key: &K,
+        entry: &MultiLevelCacheEntry<V>,
+    ) -> Result<(), CacheError> {
+        let path = self.l2_entry_path(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let persisted = PersistedL2Entry::from(entry);
+        let bytes = self.config.l2_config.serialization_backend.serialize(&persisted)?;
+        tokio::fs::write(&path, bytes).await?;
+        Ok(())
+    }
+
+    /// Read an L2 entry back from disk, if present.
+    async fn read_l2_entry_from_disk(
+        &self,
+        key: &K,
+    ) -> Result<Option<MultiLevelCacheEntry<V>>, CacheError> {
+        let path = self.l2_entry_path(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = tokio::fs::read(&path).await?;
+        let persisted: PersistedL2Entry<V> =
+            self.config.l2_config.serialization_backend.deserialize(&bytes)?;
+        Ok(Some(persisted.into()))
+    }
+
+    /// Remove an L2 entry's on-disk file, if present.
+    async fn remove_l2_entry_from_disk(&self, key: &K) -> Result<(), CacheError> {
+        let path = self.l2_entry_path(key);
+        if path.exists() {
+            tokio::fs::remove_file(&path).await?;
+        }
+        Ok(())
+    }
+
+    /// Best-effort read-through: if `key` isn't currently locked by
+    /// another process, refresh the in-process L2 map from disk so a
+    /// write from another `rez` invocation becomes visible here. If the
+    /// key is locked (someone else is mid-write), this is a no-op — the
+    /// caller reads whatever is already in memory instead of blocking.
+    async fn try_refresh_l2_entry_from_disk(&self, key: &K) -> Result<(), CacheError> {
+        let lock_key = format!("{:?}", key);
+        let file_lock = self.file_lock.clone();
+        let guard = tokio::task::spawn_blocking(move || file_lock.try_lock_exclusive(&lock_key))
+            .await
+            .map_err(|e| CacheError::CacheLevelError {
+                level: "L2".to_string(),
+                message: format!("Lock task panicked: {}", e),
+            })??;
+
+        let Some(_guard) = guard else {
+            // Another process holds the lock; degrade to the stale
+            // in-memory entry rather than waiting for it.
+            return Ok(());
+        };
+
+        match self.read_l2_entry_from_disk(key).await? {
+            Some(entry) => {
+                let mut l2_cache = self.l2_cache.write().await;
+                l2_cache.insert(key.clone(), entry);
+            }
+            None => {
+                let mut l2_cache = self.l2_cache.write().await;
+                l2_cache.remove(key);
+            }
+        }
+        Ok(())
+    }
+
     /// Promote data from L2 to L1 cache
     async fn promote_to_l1(&self, key: K, mut entry: MultiLevelCacheEntry<V>) -> Result<(), CacheError> {
         // Check L1 capacity
@@ -194,21 +345,38 @@ where
         // Insert into L1
         self.l1_cache.insert(key.clone(), entry);
 
-        // Remove from L2
-        let mut l2_cache = self.l2_cache.write().await;
-        l2_cache.remove(&key);
+        // Remove from L2, in memory and on disk
+        {
+            let mut l2_cache = self.l2_cache.write().await;
+            l2_cache.remove(&key);
+        }
+        self.remove_l2_entry_from_disk(&key).await?;
 
         // Update statistics
         {
             let mut stats = self.stats.write().unwrap();
             stats.overall_stats.promotions += 1;
         }
+        self.tier_counters.record_promotion();
 
         Ok(())
     }
 
     /// Demote data from L1 to L2 cache
     async fn demote_to_l2(&self, key: K, mut entry: MultiLevelCacheEntry<V>) -> Result<(), CacheError> {
+        // Hold an exclusive advisory lock for this key while we demote it,
+        // so another process sharing the same L2 cache directory can't
+        // concurrently demote the same entry out from under us (eviction
+        // does not take this lock).
+        let lock_key = format!("{:?}", key);
+        let file_lock = self.file_lock.clone();
+        let _guard = tokio::task::spawn_blocking(move || file_lock.lock_exclusive(&lock_key))
+            .await
+            .map_err(|e| CacheError::CacheLevelError {
+                level: "L2".to_string(),
+                message: format!("Lock task panicked: {}", e),
+            })??;
+
         // Check L2 capacity
         {
             let l2_cache = self.l2_cache.read().await;
@@ -221,7 +389,11 @@ where
         // Update entry metadata for L2
         entry.level = 2;
 
-        // Insert into L2
+        // Write to the on-disk L2 entry (the part other processes
+        // sharing this cache directory can actually see), then mirror
+        // it into the in-process L2 map so this process doesn't have to
+        // re-read it from disk on its own next lookup.
+        self.write_l2_entry_to_disk(&key, &entry).await?;
         {
             let mut l2_cache = self.l2_cache.write().await;
             l2_cache.insert(key.clone(), entry);
@@ -235,6 +407,8 @@ where
             let mut stats = self.stats.write().unwrap();
             stats.overall_stats.demotions += 1;
         }
+        self.tier_counters.record_demotion();
+        self.tier_counters.record_backing_store_write();
 
         Ok(())
     }
@@ -285,9 +459,18 @@ where
         // Sort by priority (lowest first for eviction)
         entries.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
 
-        // Remove lowest priority entries
-        for (key, _) in entries.into_iter().take(eviction_count) {
-            l2_cache.remove(&key);
+        // Remove lowest priority entries, in memory and on disk
+        let evicted: Vec<K> = entries
+            .into_iter()
+            .take(eviction_count)
+            .map(|(key, _)| key)
+            .collect();
+        for key in &evicted {
+            l2_cache.remove(key);
+        }
+        drop(l2_cache);
+        for key in &evicted {
+            self.remove_l2_entry_from_disk(key).await?;
         }
 
         Ok(())
@@ -335,7 +518,7 @@ where
 impl<K, V> UnifiedCache<K, V> for IntelligentCacheManager<K, V>
 where
     K: Clone + Hash + Eq + Send + Sync + std::fmt::Debug + 'static,
-    V: Clone + Send + Sync + std::fmt::Debug + 'static,
+    V: Clone + Send + Sync + std::fmt::Debug + Serialize + DeserializeOwned + 'static,
 {
     /// Get a value from the cache
     async fn get(&self, key: &K) -> Option<V> {
@@ -354,6 +537,7 @@ where
                     let mut stats = self.stats.write().unwrap();
                     stats.l1_stats.hits += 1;
                 }
+                self.tier_counters.record_l1_hit();
 
                 // Record performance metrics
                 self.monitor.record_get_latency(start_time.elapsed()).await;
@@ -366,6 +550,16 @@ where
             }
         }
 
+        // Refresh the in-process L2 view from disk before reading it, so
+        // a value another process wrote is actually seen. If the entry
+        // is currently locked for writing (another process is demoting
+        // it right now), don't block on it — fall through
+        // and read whatever is already in the in-process map, stale or
+        // not, rather than deadlock against a concurrent writer.
+        if let Err(e) = self.try_refresh_l2_entry_from_disk(key).await {
+            eprintln!("Failed to refresh L2 entry from disk: {:?}", e);
+        }
+
         // Try L2 cache
         {
             let mut l2_cache = self.l2_cache.write().await;
@@ -390,6 +584,7 @@ where
                         let mut stats = self.stats.write().unwrap();
                         stats.l2_stats.hits += 1;
                     }
+                    self.tier_counters.record_l2_hit();
 
                     // Record performance metrics
                     self.monitor.record_get_latency(start_time.elapsed()).await;
@@ -465,6 +660,9 @@ where
             let mut l2_cache = self.l2_cache.write().await;
             l2_cache.remove(key).is_some()
         };
+        if let Err(e) = self.remove_l2_entry_from_disk(key).await {
+            eprintln!("Failed to remove L2 entry from disk: {:?}", e);
+        }
 
         // Update statistics if removed
         if l1_removed || l2_removed {
@@ -504,7 +702,12 @@ where
 
         {
             let mut l2_cache = self.l2_cache.write().await;
+            let keys: Vec<K> = l2_cache.keys().cloned().collect();
             l2_cache.clear();
+            drop(l2_cache);
+            for key in &keys {
+                self.remove_l2_entry_from_disk(key).await?;
+            }
         }
 
         // Reset statistics
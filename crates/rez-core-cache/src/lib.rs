@@ -34,11 +34,19 @@ pub mod unified_cache;
 pub mod cache_config;
 pub mod cache_stats;
 pub mod error;
+pub mod file_lock;
 pub mod intelligent_manager;
+pub mod serialization;
 pub mod predictive_preheater;
 pub mod adaptive_tuner;
 pub mod performance_monitor;
 pub mod benchmarks;
+pub mod profiler;
+pub mod baseline;
+pub mod tier_stats;
+pub mod cost_model;
+pub mod benchmark_records;
+pub mod workpool;
 
 #[cfg(test)]
 mod tests;
@@ -48,11 +56,19 @@ pub use unified_cache::*;
 pub use cache_config::*;
 pub use cache_stats::*;
 pub use error::*;
+pub use file_lock::*;
 pub use intelligent_manager::*;
+pub use serialization::*;
 pub use predictive_preheater::*;
 pub use adaptive_tuner::*;
 pub use performance_monitor::*;
 pub use benchmarks::*;
+pub use profiler::*;
+pub use baseline::*;
+pub use tier_stats::*;
+pub use cost_model::*;
+pub use benchmark_records::*;
+pub use workpool::*;
 
 // Re-export existing cache components for compatibility
 // Temporarily disabled due to compilation errors in other crates
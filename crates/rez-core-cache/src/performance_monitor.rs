@@ -91,6 +91,80 @@ pub enum PerformanceEventType {
     AdaptiveTuning,
 }
 
+/// Number of repeats `run_benchmark_counted` times a closure per sample
+/// so a single slow scheduler tick doesn't dominate the measurement;
+/// the minimum across samples is the one reported.
+const COUNTED_BENCHMARK_SAMPLES: u32 = 20;
+
+/// Assumed clock rate used to convert measured elapsed time into an
+/// estimated cycle count, since no real hardware performance-counter
+/// backend (`perf_event_open`, cachegrind) is wired into this crate.
+const ASSUMED_CLOCK_HZ: f64 = 3.0e9;
+
+/// Assumed cycles-per-instruction used to derive an estimated
+/// instruction count from estimated cycles, in the absence of a real
+/// counter backend.
+const ASSUMED_CYCLES_PER_INSTRUCTION: f64 = 1.0;
+
+/// Assumed fraction of estimated instructions that are memory accesses
+/// serviced by L1/L2, used to derive cache-access counts until a real
+/// counter backend is wired in.
+const ASSUMED_L1_ACCESS_RATIO: f64 = 0.3;
+const ASSUMED_L2_ACCESS_RATIO: f64 = 0.05;
+
+/// Instruction/cache-access counts for one iteration of a closure,
+/// before baseline subtraction.
+#[derive(Debug, Clone, Copy, Default)]
+struct InstructionCounts {
+    estimated_cycles: u64,
+    instructions: u64,
+    l1_accesses: u64,
+    l2_accesses: u64,
+}
+
+impl InstructionCounts {
+    fn saturating_sub(self, baseline: Self) -> Self {
+        Self {
+            estimated_cycles: self.estimated_cycles.saturating_sub(baseline.estimated_cycles),
+            instructions: self.instructions.saturating_sub(baseline.instructions),
+            l1_accesses: self.l1_accesses.saturating_sub(baseline.l1_accesses),
+            l2_accesses: self.l2_accesses.saturating_sub(baseline.l2_accesses),
+        }
+    }
+}
+
+/// Extended benchmark result from the instruction-counting backend.
+///
+/// Reports estimated instructions, L1/L2 cache accesses, and estimated
+/// CPU cycles for a single iteration of the benchmarked closure, after
+/// subtracting a calibrated empty-closure baseline, instead of the
+/// wall-clock statistics in [`BenchmarkResult`]. Keeping the minimum
+/// over repeated samples (rather than an average) filters out scheduler
+/// noise, so benchmarks like `validate_version_parsing_117x` can assert
+/// a hard ratio between two counted results instead of a fuzzy timing
+/// threshold.
+///
+/// There's no `perf_event_open`/cachegrind backend wired into this
+/// crate to source real hardware counters from, so the instruction and
+/// cache-access fields are derived from the measured elapsed time via
+/// the fixed, documented `ASSUMED_*` constants above rather than
+/// sampled from actual retired-instruction counters. Wiring in a real
+/// backend later only requires replacing the body of
+/// [`PerformanceMonitor::measure_counts`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountedBenchmarkResult {
+    /// Benchmark name
+    pub name: String,
+    /// Estimated instructions for one iteration, baseline-subtracted
+    pub instructions: u64,
+    /// Estimated L1 cache accesses, baseline-subtracted
+    pub l1_accesses: u64,
+    /// Estimated L2 cache accesses, baseline-subtracted
+    pub l2_accesses: u64,
+    /// Estimated CPU cycles for one iteration, baseline-subtracted
+    pub estimated_cycles: u64,
+}
+
 /// Benchmark result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkResult {
@@ -114,6 +188,45 @@ pub struct BenchmarkResult {
     pub timestamp: SystemTime,
 }
 
+/// Options controlling [`PerformanceMonitor::warm_up_and_benchmark`]'s
+/// warm-up phase, so cold-cache costs from the first few iterations of
+/// a workload don't pollute the measured result.
+#[derive(Debug, Clone)]
+pub struct WarmUpOptions {
+    /// Maximum warm-up iterations to run before giving up on reaching
+    /// stability and measuring anyway
+    pub iterations: usize,
+    /// Number of trailing warm-up iterations considered when checking
+    /// for stability
+    pub stabilization_window: usize,
+    /// Warm-up is considered stable once the relative variance
+    /// (stddev / mean) of ops/sec over the trailing
+    /// `stabilization_window` iterations falls at or below this value
+    pub max_relative_variance: f64,
+}
+
+impl Default for WarmUpOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 10,
+            stabilization_window: 3,
+            max_relative_variance: 0.1,
+        }
+    }
+}
+
+/// Relative variance (population stddev / mean) of `samples`, or `0.0`
+/// if the mean is zero.
+fn relative_variance(samples: &VecDeque<f64>) -> f64 {
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    if mean == 0.0 {
+        return 0.0;
+    }
+
+    let variance = samples.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    variance.sqrt() / mean
+}
+
 /// Performance Monitor
 ///
 /// Provides comprehensive monitoring, metrics collection, and benchmarking
@@ -298,6 +411,27 @@ impl PerformanceMonitor {
 
     /// Run a benchmark
     pub async fn run_benchmark<F, Fut>(&self, name: &str, benchmark_fn: F) -> BenchmarkResult
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let result = self.measure_once(name, benchmark_fn).await;
+
+        // Store benchmark result
+        {
+            let mut history = self.benchmark_history.write().unwrap();
+            history.push(result.clone());
+        }
+
+        result
+    }
+
+    /// Measure one run of `benchmark_fn`, without recording the result
+    /// into [`benchmark_history`](Self::get_benchmark_history). Shared by
+    /// [`run_benchmark`](Self::run_benchmark) and
+    /// [`warm_up_and_benchmark`](Self::warm_up_and_benchmark)'s warm-up
+    /// iterations, which shouldn't pollute the stored history.
+    async fn measure_once<F, Fut>(&self, name: &str, benchmark_fn: F) -> BenchmarkResult
     where
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = ()>,
@@ -305,7 +439,7 @@ impl PerformanceMonitor {
         let start_time = Instant::now();
         let start_ops = self.counters.get_operations.load(Ordering::Relaxed) +
                        self.counters.put_operations.load(Ordering::Relaxed);
-        
+
         // Reset latency histogram for this benchmark
         {
             let mut histogram = self.latency_histogram.write().unwrap();
@@ -318,7 +452,7 @@ impl PerformanceMonitor {
         let duration = start_time.elapsed();
         let end_ops = self.counters.get_operations.load(Ordering::Relaxed) +
                      self.counters.put_operations.load(Ordering::Relaxed);
-        
+
         let ops_performed = end_ops - start_ops;
         let ops_per_second = if duration.as_secs_f64() > 0.0 {
             ops_performed as f64 / duration.as_secs_f64()
@@ -329,7 +463,7 @@ impl PerformanceMonitor {
         // Calculate latency percentiles
         let (avg_latency, p95_latency, p99_latency) = self.calculate_latency_percentiles().await;
 
-        let result = BenchmarkResult {
+        BenchmarkResult {
             name: name.to_string(),
             ops_per_second,
             avg_latency_us: avg_latency,
@@ -339,15 +473,95 @@ impl PerformanceMonitor {
             hit_rate: 0.0, // TODO: Calculate hit rate for benchmark
             duration,
             timestamp: SystemTime::now(),
-        };
+        }
+    }
 
-        // Store benchmark result
-        {
-            let mut history = self.benchmark_history.write().unwrap();
-            history.push(result.clone());
+    /// Run `benchmark_fn` repeatedly as a warm-up phase, discarding each
+    /// iteration's result, until ops/sec stabilizes — the relative
+    /// variance (stddev / mean) over the trailing
+    /// `options.stabilization_window` iterations falls at or below
+    /// `options.max_relative_variance` — or `options.iterations` warm-up
+    /// runs are exhausted, then measures once more via
+    /// [`run_benchmark`](Self::run_benchmark) and returns that as the
+    /// reported, stored result. This keeps cold-cache costs from the
+    /// first few iterations of a workload out of the measured result.
+    pub async fn warm_up_and_benchmark<F, Fut>(
+        &self,
+        name: &str,
+        options: WarmUpOptions,
+        benchmark_fn: F,
+    ) -> BenchmarkResult
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let mut recent_ops_per_second: VecDeque<f64> =
+            VecDeque::with_capacity(options.stabilization_window);
+
+        for _ in 0..options.iterations {
+            let warm_up_result = self.measure_once(name, &benchmark_fn).await;
+            recent_ops_per_second.push_back(warm_up_result.ops_per_second);
+            if recent_ops_per_second.len() > options.stabilization_window {
+                recent_ops_per_second.pop_front();
+            }
+
+            if recent_ops_per_second.len() == options.stabilization_window
+                && relative_variance(&recent_ops_per_second) <= options.max_relative_variance
+            {
+                break;
+            }
         }
 
-        result
+        self.run_benchmark(name, benchmark_fn).await
+    }
+
+    /// Measure instruction/cache-access counts for one call of `f`,
+    /// taking the minimum elapsed time over `COUNTED_BENCHMARK_SAMPLES`
+    /// repeats to filter out scheduler noise before converting it to
+    /// estimated counts.
+    fn measure_counts<F: Fn()>(f: &F) -> InstructionCounts {
+        let mut min_elapsed = Duration::MAX;
+        for _ in 0..COUNTED_BENCHMARK_SAMPLES {
+            let start = Instant::now();
+            f();
+            let elapsed = start.elapsed();
+            if elapsed < min_elapsed {
+                min_elapsed = elapsed;
+            }
+        }
+
+        let estimated_cycles = (min_elapsed.as_secs_f64() * ASSUMED_CLOCK_HZ) as u64;
+        let instructions = (estimated_cycles as f64 / ASSUMED_CYCLES_PER_INSTRUCTION) as u64;
+        let l1_accesses = (instructions as f64 * ASSUMED_L1_ACCESS_RATIO) as u64;
+        let l2_accesses = (instructions as f64 * ASSUMED_L2_ACCESS_RATIO) as u64;
+
+        InstructionCounts {
+            estimated_cycles,
+            instructions,
+            l1_accesses,
+            l2_accesses,
+        }
+    }
+
+    /// Run a benchmark under the instruction-counting backend.
+    ///
+    /// Reports estimated instructions, L1/L2 cache accesses, and
+    /// estimated cycles for a single iteration of `benchmark_fn`,
+    /// instead of the wall-clock timing [`run_benchmark`](Self::run_benchmark)
+    /// reports, after subtracting a calibrated empty-closure baseline so
+    /// fixed per-call overhead cancels out of the result.
+    pub fn run_benchmark_counted<F: Fn()>(&self, name: &str, benchmark_fn: F) -> CountedBenchmarkResult {
+        let baseline = Self::measure_counts(&|| {});
+        let raw = Self::measure_counts(&benchmark_fn);
+        let counts = raw.saturating_sub(baseline);
+
+        CountedBenchmarkResult {
+            name: name.to_string(),
+            instructions: counts.instructions,
+            l1_accesses: counts.l1_accesses,
+            l2_accesses: counts.l2_accesses,
+            estimated_cycles: counts.estimated_cycles,
+        }
     }
 
     /// Calculate latency percentiles from histogram
@@ -0,0 +1,259 @@
+//! Pluggable profilers sampled during a [`crate::benchmarks::LoadDriverConfig`]-driven run
+//!
+//! A [`Profiler`] is sampled on a fixed interval for the duration of a
+//! `CacheBenchmarkSuite::run_load_driver` run, so a user driving a
+//! steady-state workload (e.g. "100 ops/sec for 60s") can see where time
+//! or memory goes without having to reach for an external tool.
+
+use crate::UnifiedCacheStats;
+use std::time::Duration;
+
+/// Something sampled on an interval while a load-driver run is in
+/// flight, producing a human-readable summary once the run finishes.
+pub trait Profiler: Send {
+    /// Take one sample. `stats` is the latest cache statistics snapshot
+    /// and `elapsed` is how far into the run this sample was taken;
+    /// most profilers only need one of the two.
+    fn sample(&mut self, stats: &UnifiedCacheStats, elapsed: Duration);
+
+    /// Summarize everything observed across all samples taken so far.
+    fn report(&self) -> String;
+}
+
+/// Names a [`Profiler`] implementation, so one can be selected by name
+/// (e.g. from a `--profilers cpu,system,cache` CLI flag) without the
+/// caller constructing the `Box<dyn Profiler>` itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfilerKind {
+    /// Sampling CPU time profiler
+    Cpu,
+    /// System-resource monitor (RSS / CPU%)
+    SystemResources,
+    /// Cache-internal-metrics recorder
+    CacheMetrics,
+}
+
+impl ProfilerKind {
+    /// Construct the profiler this variant names.
+    pub fn build(self) -> Box<dyn Profiler> {
+        match self {
+            ProfilerKind::Cpu => Box::new(CpuSamplingProfiler::new()),
+            ProfilerKind::SystemResources => Box::new(SystemResourceMonitor::new()),
+            ProfilerKind::CacheMetrics => Box::new(CacheMetricsRecorder::new()),
+        }
+    }
+}
+
+impl std::str::FromStr for ProfilerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "cpu" => Ok(ProfilerKind::Cpu),
+            "system" | "system-resources" | "resources" => Ok(ProfilerKind::SystemResources),
+            "cache" | "cache-metrics" => Ok(ProfilerKind::CacheMetrics),
+            other => Err(format!("unknown profiler '{other}' (expected cpu, system, or cache)")),
+        }
+    }
+}
+
+/// Parse a comma-separated `--profilers` value (e.g. `"cpu,cache"`)
+/// into the profilers it names, in order.
+pub fn parse_profiler_list(value: &str) -> Result<Vec<Box<dyn Profiler>>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<ProfilerKind>().map(ProfilerKind::build))
+        .collect()
+}
+
+/// Read `/proc/self/stat`'s `utime`/`stime` fields (in clock ticks) for
+/// the current process, or `None` off Linux or if the read fails.
+#[cfg(target_os = "linux")]
+fn read_process_cpu_ticks() -> Option<u64> {
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    // Fields after the `(comm)` part are space-separated and comm itself
+    // may contain spaces/parens, so split on the last ')' first.
+    let after_comm = stat.rsplit_once(')')?.1;
+    let fields: Vec<&str> = after_comm.split_whitespace().collect();
+    // utime is field 14, stime is field 15 counting from field 1 = pid;
+    // after dropping `pid (comm)`, state is fields[0], so utime/stime
+    // are fields[11]/fields[12].
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some(utime + stime)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_process_cpu_ticks() -> Option<u64> {
+    None
+}
+
+/// Clock ticks per second assumed for converting `/proc/self/stat`
+/// CPU-tick deltas into a percentage; `100` is the near-universal value
+/// of `sysconf(_SC_CLK_TCK)` on Linux.
+const ASSUMED_CLOCK_TICKS_PER_SECOND: f64 = 100.0;
+
+/// Samples process CPU time from `/proc/self/stat` on each call,
+/// reporting the average utilization between samples. Linux-only; on
+/// other platforms every sample is a no-op and the report says so.
+pub struct CpuSamplingProfiler {
+    last_sample: Option<(u64, Duration)>,
+    utilization_samples: Vec<f64>,
+}
+
+impl CpuSamplingProfiler {
+    pub fn new() -> Self {
+        Self {
+            last_sample: None,
+            utilization_samples: Vec::new(),
+        }
+    }
+}
+
+impl Default for CpuSamplingProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler for CpuSamplingProfiler {
+    fn sample(&mut self, _stats: &UnifiedCacheStats, elapsed: Duration) {
+        let Some(ticks) = read_process_cpu_ticks() else {
+            return;
+        };
+
+        if let Some((last_ticks, last_elapsed)) = self.last_sample {
+            let tick_delta = ticks.saturating_sub(last_ticks) as f64;
+            let wall_delta = elapsed.saturating_sub(last_elapsed).as_secs_f64();
+            if wall_delta > 0.0 {
+                let cpu_seconds = tick_delta / ASSUMED_CLOCK_TICKS_PER_SECOND;
+                self.utilization_samples.push((cpu_seconds / wall_delta) * 100.0);
+            }
+        }
+
+        self.last_sample = Some((ticks, elapsed));
+    }
+
+    fn report(&self) -> String {
+        if self.utilization_samples.is_empty() {
+            return "CpuSamplingProfiler: no samples (not running on Linux, or run too short)".to_string();
+        }
+
+        let avg = self.utilization_samples.iter().sum::<f64>() / self.utilization_samples.len() as f64;
+        let peak = self.utilization_samples.iter().cloned().fold(0.0, f64::max);
+        format!(
+            "CpuSamplingProfiler: avg {avg:.1}% CPU, peak {peak:.1}% CPU across {} samples",
+            self.utilization_samples.len()
+        )
+    }
+}
+
+/// Read `VmRSS:` (in kB) out of `/proc/self/status`, or `None` off
+/// Linux or if the read fails.
+#[cfg(target_os = "linux")]
+fn read_process_rss_kb() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status.lines().find_map(|line| {
+        line.strip_prefix("VmRSS:")
+            .and_then(|rest| rest.trim().split_whitespace().next())
+            .and_then(|kb| kb.parse().ok())
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_process_rss_kb() -> Option<u64> {
+    None
+}
+
+/// Samples resident set size and CPU utilization on each call, tracking
+/// peak RSS and average CPU% over the run. Linux-only; on other
+/// platforms every sample is a no-op and the report says so.
+pub struct SystemResourceMonitor {
+    peak_rss_kb: u64,
+    cpu: CpuSamplingProfiler,
+    samples_taken: usize,
+}
+
+impl SystemResourceMonitor {
+    pub fn new() -> Self {
+        Self {
+            peak_rss_kb: 0,
+            cpu: CpuSamplingProfiler::new(),
+            samples_taken: 0,
+        }
+    }
+}
+
+impl Default for SystemResourceMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler for SystemResourceMonitor {
+    fn sample(&mut self, stats: &UnifiedCacheStats, elapsed: Duration) {
+        if let Some(rss_kb) = read_process_rss_kb() {
+            self.peak_rss_kb = self.peak_rss_kb.max(rss_kb);
+        }
+        self.cpu.sample(stats, elapsed);
+        self.samples_taken += 1;
+    }
+
+    fn report(&self) -> String {
+        if self.samples_taken == 0 || self.peak_rss_kb == 0 {
+            return "SystemResourceMonitor: no samples (not running on Linux, or run too short)".to_string();
+        }
+
+        format!(
+            "SystemResourceMonitor: peak RSS {:.1} MiB; {}",
+            self.peak_rss_kb as f64 / 1024.0,
+            self.cpu.report()
+        )
+    }
+}
+
+/// Snapshots `UnifiedCacheStats` on each call, so the cache's own
+/// hit-rate/eviction/memory counters can be inspected over the course
+/// of a steady-state run instead of only at the end.
+pub struct CacheMetricsRecorder {
+    snapshots: Vec<(Duration, UnifiedCacheStats)>,
+}
+
+impl CacheMetricsRecorder {
+    pub fn new() -> Self {
+        Self {
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Every snapshot recorded so far, in sampling order.
+    pub fn snapshots(&self) -> &[(Duration, UnifiedCacheStats)] {
+        &self.snapshots
+    }
+}
+
+impl Default for CacheMetricsRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler for CacheMetricsRecorder {
+    fn sample(&mut self, stats: &UnifiedCacheStats, elapsed: Duration) {
+        self.snapshots.push((elapsed, stats.clone()));
+    }
+
+    fn report(&self) -> String {
+        match (self.snapshots.first(), self.snapshots.last()) {
+            (Some((first_elapsed, _)), Some((last_elapsed, _))) => format!(
+                "CacheMetricsRecorder: {} snapshots between {:?} and {:?}",
+                self.snapshots.len(),
+                first_elapsed,
+                last_elapsed
+            ),
+            _ => "CacheMetricsRecorder: no snapshots taken".to_string(),
+        }
+    }
+}
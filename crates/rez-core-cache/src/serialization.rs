@@ -0,0 +1,105 @@
+//! Pluggable serialization backends for the cache layer
+//!
+//! L2 (disk) cache entries need to be turned into bytes before they can be
+//! written out, and the right tradeoff between throughput, size, and
+//! portability varies by deployment: JSON is human-inspectable and stable
+//! across versions, bincode is faster and smaller but opaque. Both are
+//! exposed behind a common [`CacheSerializer`] trait so the cache layer
+//! doesn't have to hardcode one.
+
+use crate::error::CacheError;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A serialization backend for cache entries
+pub trait CacheSerializer: Send + Sync + std::fmt::Debug {
+    /// Serialize a value to bytes
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, CacheError>;
+
+    /// Deserialize a value from bytes
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, CacheError>;
+
+    /// Short identifier used for diagnostics and on-disk format tagging
+    fn name(&self) -> &'static str;
+}
+
+/// JSON backend: human-readable, stable, and the default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonSerializer;
+
+impl CacheSerializer for JsonSerializer {
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, CacheError> {
+        serde_json::to_vec(value).map_err(CacheError::Serialization)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, CacheError> {
+        serde_json::from_slice(bytes).map_err(CacheError::Serialization)
+    }
+
+    fn name(&self) -> &'static str {
+        "json"
+    }
+}
+
+/// Bincode backend: compact binary encoding, faster to (de)serialize and
+/// smaller on disk than JSON, at the cost of not being human-readable and
+/// being less tolerant of schema drift between versions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BincodeSerializer;
+
+impl CacheSerializer for BincodeSerializer {
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, CacheError> {
+        bincode::serialize(value).map_err(|e| CacheError::Corruption {
+            details: format!("bincode serialization failed: {}", e),
+        })
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, CacheError> {
+        bincode::deserialize(bytes).map_err(|e| CacheError::Corruption {
+            details: format!("bincode deserialization failed: {}", e),
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "bincode"
+    }
+}
+
+/// Which built-in [`CacheSerializer`] to use, selectable via
+/// [`crate::cache_config::L2CacheConfig`] so deployments can trade
+/// readability for throughput without code changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SerializationBackend {
+    /// Human-readable, stable across versions
+    Json,
+    /// Compact and fast, opaque on disk
+    Bincode,
+}
+
+impl Default for SerializationBackend {
+    fn default() -> Self {
+        Self::Json
+    }
+}
+
+impl CacheSerializer for SerializationBackend {
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, CacheError> {
+        match self {
+            Self::Json => JsonSerializer.serialize(value),
+            Self::Bincode => BincodeSerializer.serialize(value),
+        }
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, CacheError> {
+        match self {
+            Self::Json => JsonSerializer.deserialize(bytes),
+            Self::Bincode => BincodeSerializer.deserialize(bytes),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Json => JsonSerializer.name(),
+            Self::Bincode => BincodeSerializer.name(),
+        }
+    }
+}
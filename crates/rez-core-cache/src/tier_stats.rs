@@ -0,0 +1,100 @@
+//! Per-operation cache-tier counters
+//!
+//! [`crate::BenchmarkResult`] only reports aggregate ops/sec and average
+//! latency, which hides *why* a configuration is slow — e.g. a config
+//! that's mostly L2 hits with heavy promotion traffic looks identical to
+//! one serving everything from L1 if you only look at ops/sec.
+//! [`TierOperationCounters`] tracks L1/L2 hits, promotions, demotions,
+//! and backing-store writes as they actually happen in
+//! [`crate::IntelligentCacheManager`], independent of (and in addition
+//! to) the existing `stats.overall_stats`/`stats.l1_stats`/`stats.l2_stats`
+//! bookkeeping, so a benchmark run can report [`CacheTierStats`] deltas
+//! alongside its `BenchmarkResult`.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A snapshot of [`TierOperationCounters`] at a point in time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CacheTierStats {
+    /// Gets served directly from L1
+    pub l1_hits: u64,
+    /// Gets served from L2 (with or without a subsequent promotion)
+    pub l2_hits: u64,
+    /// Entries moved from L2 to L1
+    pub promotions: u64,
+    /// Entries moved from L1 to L2
+    pub demotions: u64,
+    /// Writes into the L2 backing store (demotions and direct L2 inserts)
+    pub backing_store_writes: u64,
+}
+
+impl CacheTierStats {
+    /// Field-wise difference, saturating at zero; used to report the
+    /// counts accumulated strictly between two snapshots.
+    pub fn saturating_sub(self, earlier: Self) -> Self {
+        Self {
+            l1_hits: self.l1_hits.saturating_sub(earlier.l1_hits),
+            l2_hits: self.l2_hits.saturating_sub(earlier.l2_hits),
+            promotions: self.promotions.saturating_sub(earlier.promotions),
+            demotions: self.demotions.saturating_sub(earlier.demotions),
+            backing_store_writes: self
+                .backing_store_writes
+                .saturating_sub(earlier.backing_store_writes),
+        }
+    }
+}
+
+/// Atomic, per-tier operation counters accumulated over the lifetime of
+/// an [`crate::IntelligentCacheManager`].
+#[derive(Debug, Default)]
+pub struct TierOperationCounters {
+    l1_hits: AtomicU64,
+    l2_hits: AtomicU64,
+    promotions: AtomicU64,
+    demotions: AtomicU64,
+    backing_store_writes: AtomicU64,
+}
+
+impl TierOperationCounters {
+    /// Create a fresh set of counters, all starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a get served directly from L1.
+    pub fn record_l1_hit(&self) {
+        self.l1_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a get served from L2.
+    pub fn record_l2_hit(&self) {
+        self.l2_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an entry moved from L2 to L1.
+    pub fn record_promotion(&self) {
+        self.promotions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an entry moved from L1 to L2.
+    pub fn record_demotion(&self) {
+        self.demotions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a write into the L2 backing store.
+    pub fn record_backing_store_write(&self) {
+        self.backing_store_writes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Snapshot the current counter values.
+    pub fn snapshot(&self) -> CacheTierStats {
+        CacheTierStats {
+            l1_hits: self.l1_hits.load(Ordering::Relaxed),
+            l2_hits: self.l2_hits.load(Ordering::Relaxed),
+            promotions: self.promotions.load(Ordering::Relaxed),
+            demotions: self.demotions.load(Ordering::Relaxed),
+            backing_store_writes: self.backing_store_writes.load(Ordering::Relaxed),
+        }
+    }
+}
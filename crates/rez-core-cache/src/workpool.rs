@@ -0,0 +1,97 @@
+//! Bounded worker-pool load generator
+//!
+//! `benchmark_concurrent_operations` and `benchmark_high_contention` used
+//! to each spawn their own `tokio::spawn` loop with hand-rolled key
+//! sharding and no bound on in-flight work, so a stalled consumer would
+//! just let submissions queue up unboundedly instead of applying
+//! backpressure. [`Workpool`] centralizes that: a fixed number of worker
+//! tasks pull jobs off a bounded channel, [`Workpool::execute`] reports
+//! whether a job was actually accepted instead of assuming it always is,
+//! and [`Workpool::execute_and_finish`] drains a whole iterator of jobs
+//! before joining every worker.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex as AsyncMutex};
+use tokio::task::JoinHandle;
+
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A fixed-size pool of worker tasks fed through a bounded channel, so a
+/// benchmark's concurrency level and backpressure are both configured in
+/// one place instead of duplicated per benchmark.
+pub struct Workpool {
+    sender: mpsc::Sender<Job>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Workpool {
+    /// Spawn `worker_count` worker tasks sharing a channel bounded to
+    /// `queue_capacity` pending jobs; [`execute`](Self::execute) blocks
+    /// once the queue is full rather than growing it without bound.
+    pub fn new(worker_count: usize, queue_capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>(queue_capacity.max(1));
+        let receiver = Arc::new(AsyncMutex::new(receiver));
+
+        let workers = (0..worker_count.max(1))
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+                tokio::spawn(async move {
+                    loop {
+                        let job = {
+                            let mut receiver = receiver.lock().await;
+                            receiver.recv().await
+                        };
+                        match job {
+                            Some(job) => job.await,
+                            None => break,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        Self { sender, workers }
+    }
+
+    /// Submit a job for some worker to run. Blocks while the bounded
+    /// queue is full (backpressure), and returns `false` instead of
+    /// blocking forever if the pool has already been closed (every
+    /// worker has exited and dropped its end of the channel).
+    pub async fn execute<F>(&self, job: F) -> bool
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.sender.send(Box::pin(job)).await.is_ok()
+    }
+
+    /// Submit every job from `jobs` via [`execute`](Self::execute), then
+    /// close the queue and wait for every worker to drain it and exit.
+    /// Returns the number of jobs that couldn't be submitted because the
+    /// pool was already closed.
+    pub async fn execute_and_finish<F, I>(self, jobs: I) -> usize
+    where
+        F: Future<Output = ()> + Send + 'static,
+        I: IntoIterator<Item = F>,
+    {
+        let mut not_submitted = 0;
+        for job in jobs {
+            if !self.execute(job).await {
+                not_submitted += 1;
+            }
+        }
+
+        self.close_and_join().await;
+        not_submitted
+    }
+
+    /// Close the submission channel and wait for every worker to finish
+    /// draining it.
+    async fn close_and_join(self) {
+        drop(self.sender);
+        for worker in self.workers {
+            let _ = worker.await;
+        }
+    }
+}
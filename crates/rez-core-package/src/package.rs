@@ -725,6 +725,39 @@ impl Package {
     pub fn set_commands(&mut self, commands: String) {
         self.commands = Some(commands);
     }
+
+    /// Parse the package's `help` attribute into `(label, target)` pairs.
+    ///
+    /// Rez packages define `help` as a single URL/command string, a single
+    /// `[label, target]` pair, or a list of such pairs. Since `help` is
+    /// stored here as the raw string, a JSON array is parsed as the
+    /// multi-entry form; anything else is treated as one entry labeled
+    /// "Help". Any `{root}` token in a target is expanded against `base`.
+    pub fn help_entries(&self) -> Vec<(String, String)> {
+        let Some(help) = &self.help else {
+            return Vec::new();
+        };
+
+        let entries = match serde_json::from_str::<Vec<(String, String)>>(help) {
+            Ok(pairs) => pairs,
+            Err(_) => vec![("Help".to_string(), help.clone())],
+        };
+
+        entries
+            .into_iter()
+            .map(|(label, target)| (label, self.expand_root(&target)))
+            .collect()
+    }
+
+    /// Expand the `{root}` token in a help/commands target against the
+    /// package's install `base`, leaving the token untouched if `base` is
+    /// unknown.
+    fn expand_root(&self, target: &str) -> String {
+        match &self.base {
+            Some(base) => target.replace("{root}", base),
+            None => target.to_string(),
+        }
+    }
 }
 
 #[cfg(feature = "python-bindings")]
@@ -1,10 +1,11 @@
 //! Package validation functionality
 
+use crate::requirement::PackageRequirement;
 use crate::Package;
 use rez_core_version::Version;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Package validation result
 #[pyclass]
@@ -77,6 +78,12 @@ pub struct PackageValidator {
     
     /// Known packages for dependency validation
     known_packages: HashMap<String, Vec<Version>>,
+
+    /// Declared `requires` of known package versions, keyed by name then by
+    /// version string, so [`PackageValidator::check_circular_dependencies`]
+    /// can walk the transitive dependency graph instead of only looking at
+    /// the package under test's own `requires`.
+    known_requirements: HashMap<String, HashMap<String, Vec<String>>>,
 }
 
 #[pymethods]
@@ -193,9 +200,10 @@ impl PackageValidator {
         Self {
             options: options.unwrap_or_else(PackageValidationOptions::new),
             known_packages: HashMap::new(),
+            known_requirements: HashMap::new(),
         }
     }
-    
+
     /// Add known packages for dependency validation
     pub fn add_known_packages(&mut self, packages: HashMap<String, Vec<String>>) -> PyResult<()> {
         for (name, versions) in packages {
@@ -203,7 +211,7 @@ impl PackageValidator {
                 .into_iter()
                 .map(|v| Version::parse(&v))
                 .collect();
-                
+
             match parsed_versions {
                 Ok(versions) => {
                     self.known_packages.insert(name, versions);
@@ -217,6 +225,21 @@ impl PackageValidator {
         }
         Ok(())
     }
+
+    /// Register the declared `requires` of one known package version, so
+    /// `check_circular_dependencies` can resolve against the full package
+    /// universe instead of only the package under test's own `requires`.
+    pub fn add_known_package_requirements(
+        &mut self,
+        name: String,
+        version: String,
+        requires: Vec<String>,
+    ) {
+        self.known_requirements
+            .entry(name)
+            .or_default()
+            .insert(version, requires);
+    }
     
     /// Validate a package
     pub fn validate_package(&self, package: &Package) -> PyResult<PackageValidationResult> {
@@ -276,6 +299,18 @@ impl PackageValidator {
                 result.add_error("Package version cannot be empty".to_string());
                 result.metadata_valid = false;
             }
+
+            // Many repositories disallow publishing PEP 440-style local
+            // version segments (e.g. '1.2.3+cu118'), since they're meant
+            // for reproducing a build locally, not for distribution.
+            if self.options.strict_mode {
+                if let Some(local) = version.local_version() {
+                    result.add_warning(format!(
+                        "Package version '{}' carries a local version segment ('+{}'), which is not suitable for publishing",
+                        version.as_str(), local
+                    ));
+                }
+            }
         }
         
         // Validate authors
@@ -293,25 +328,40 @@ impl PackageValidator {
     fn validate_dependencies(&self, package: &Package, result: &mut PackageValidationResult) {
         // Validate requires
         for req in &package.requires {
-            if let Err(e) = self.validate_requirement_string(req) {
-                result.add_error(format!("Invalid requirement '{}': {}", req, e));
-                result.dependencies_valid = false;
-            }
+            self.check_requirement(req, "requirement", result, |r| &mut r.dependencies_valid);
         }
-        
+
         // Validate build_requires
         for req in &package.build_requires {
-            if let Err(e) = self.validate_requirement_string(req) {
-                result.add_error(format!("Invalid build requirement '{}': {}", req, e));
-                result.dependencies_valid = false;
-            }
+            self.check_requirement(req, "build requirement", result, |r| &mut r.dependencies_valid);
         }
-        
+
         // Validate private_build_requires
         for req in &package.private_build_requires {
-            if let Err(e) = self.validate_requirement_string(req) {
-                result.add_error(format!("Invalid private build requirement '{}': {}", req, e));
-                result.dependencies_valid = false;
+            self.check_requirement(req, "private build requirement", result, |r| &mut r.dependencies_valid);
+        }
+    }
+
+    /// Validate a single requirement string and record any errors/warnings
+    /// it produces against `result`, tagging them with `kind` (e.g.
+    /// "requirement", "variant requirement") to match the call site, and
+    /// clearing whichever `*_valid` flag `valid_flag` selects on error.
+    fn check_requirement(
+        &self,
+        req: &str,
+        kind: &str,
+        result: &mut PackageValidationResult,
+        valid_flag: impl FnOnce(&mut PackageValidationResult) -> &mut bool,
+    ) {
+        match self.validate_requirement_string(req) {
+            Ok(warnings) => {
+                for warning in warnings {
+                    result.add_warning(format!("{} '{}': {}", kind, req, warning));
+                }
+            }
+            Err(e) => {
+                result.add_error(format!("Invalid {} '{}': {}", kind, req, e));
+                *valid_flag(result) = false;
             }
         }
     }
@@ -336,26 +386,165 @@ impl PackageValidator {
         // Validate variant requirements
         for (i, variant) in package.variants.iter().enumerate() {
             for req in variant {
-                if let Err(e) = self.validate_requirement_string(req) {
-                    result.add_error(format!("Invalid variant requirement '{}' in variant {}: {}", req, i, e));
-                    result.variants_valid = false;
-                }
+                self.check_requirement(
+                    req,
+                    &format!("variant requirement in variant {}", i),
+                    result,
+                    |r| &mut r.variants_valid,
+                );
             }
         }
     }
     
-    /// Check for circular dependencies
+    /// Check for circular dependencies and version conflicts by running
+    /// unit propagation over the known package universe (`known_packages` +
+    /// `known_requirements`), starting from `package`'s own `requires`. A
+    /// package with no declared requirements in that universe is accepted
+    /// optimistically — there is nothing to check it against.
+    ///
+    /// A cycle is reported only when a requirement names a package on the
+    /// decision path that has no chosen version, which in practice is
+    /// `package` itself when it has no version. A requirement that leads
+    /// back to an already-chosen package is checked against that choice
+    /// like any other requirement and is not reported as a cycle.
     fn check_circular_dependencies(&self, package: &Package, result: &mut PackageValidationResult) {
-        // This is a simplified check - a full implementation would need
-        // access to all packages in the repository
-        let mut visited = HashSet::new();
-        let mut path = Vec::new();
-        
-        if self.has_circular_dependency(&package.name, &package.requires, &mut visited, &mut path) {
-            result.add_error(format!("Circular dependency detected: {}", path.join(" -> ")));
-            result.dependencies_valid = false;
+        let pending: VecDeque<PendingRequirement> = package
+            .requires
+            .iter()
+            .filter_map(|req| {
+                PackageRequirement::parse(req).ok().map(|requirement| PendingRequirement {
+                    dependent: package.name.clone(),
+                    requirement,
+                })
+            })
+            .collect();
+
+        let mut solution = HashMap::new();
+        if let Some(ref version) = package.version {
+            solution.insert(package.name.clone(), version.clone());
+        }
+        let mut derivations: HashMap<String, Vec<PendingRequirement>> = HashMap::new();
+        let mut path = vec![package.name.clone()];
+
+        match self.propagate(pending, &mut solution, &mut derivations, &mut path) {
+            Ok(()) => {}
+            Err(PropagationFailure::Cycle(chain)) => {
+                result.add_error(format!("Circular dependency detected: {}", chain.join(" -> ")));
+                result.dependencies_valid = false;
+            }
+            Err(PropagationFailure::Conflict(name)) => {
+                let causes = derivations.get(&name).cloned().unwrap_or_default();
+                result.add_error(Self::describe_conflict(&name, &causes));
+                result.dependencies_valid = false;
+            }
         }
     }
+
+    /// Unit-propagate the requirement at the front of `pending`, recursing
+    /// on the rest. Every sibling requirement lives in one shared queue
+    /// (rather than resolving each dependency's subtree before moving to
+    /// the next), so a conflict found while satisfying a *later*
+    /// requirement can backtrack into an *earlier* one's still-open
+    /// candidate choice — conflict-driven backjumping over a single
+    /// chronological stack rather than independent per-branch backtracking.
+    fn propagate(
+        &self,
+        mut pending: VecDeque<PendingRequirement>,
+        solution: &mut HashMap<String, Version>,
+        derivations: &mut HashMap<String, Vec<PendingRequirement>>,
+        path: &mut Vec<String>,
+    ) -> Result<(), PropagationFailure> {
+        let Some(next) = pending.pop_front() else {
+            return Ok(());
+        };
+
+        let name = next.requirement.name.clone();
+        derivations.entry(name.clone()).or_default().push(next.clone());
+
+        if let Some(existing) = solution.get(&name).cloned() {
+            return if next.requirement.satisfied_by(&existing) {
+                self.propagate(pending, solution, derivations, path)
+            } else {
+                Err(PropagationFailure::Conflict(name))
+            };
+        }
+
+        if path.contains(&name) {
+            let mut chain = path.clone();
+            chain.push(name);
+            return Err(PropagationFailure::Cycle(chain));
+        }
+
+        let Some(candidates) = self.known_packages.get(&name).cloned() else {
+            // Not part of the known universe: nothing to resolve against.
+            return self.propagate(pending, solution, derivations, path);
+        };
+
+        let imposed = derivations.get(&name).cloned().unwrap_or_default();
+        let mut candidates = candidates;
+        candidates.sort_by(|a, b| b.cmp(a));
+
+        for candidate in &candidates {
+            if !imposed.iter().all(|d| d.requirement.satisfied_by(candidate)) {
+                continue;
+            }
+
+            let solution_snapshot = solution.clone();
+            let derivations_snapshot = derivations.clone();
+
+            solution.insert(name.clone(), candidate.clone());
+            path.push(name.clone());
+
+            let mut next_pending = pending.clone();
+            let requires = self
+                .known_requirements
+                .get(&name)
+                .and_then(|versions| versions.get(candidate.as_str()))
+                .into_iter()
+                .flatten();
+            for req in requires {
+                if let Ok(requirement) = PackageRequirement::parse(req) {
+                    next_pending.push_back(PendingRequirement {
+                        dependent: format!("{}-{}", name, candidate.as_str()),
+                        requirement,
+                    });
+                }
+            }
+
+            match self.propagate(next_pending, solution, derivations, path) {
+                Ok(()) => return Ok(()),
+                Err(PropagationFailure::Cycle(chain)) => {
+                    path.pop();
+                    return Err(PropagationFailure::Cycle(chain));
+                }
+                Err(PropagationFailure::Conflict(_)) => {
+                    *solution = solution_snapshot;
+                    *derivations = derivations_snapshot;
+                    path.pop();
+                }
+            }
+        }
+
+        Err(PropagationFailure::Conflict(name))
+    }
+
+    /// Render the requirements recorded against `package` that left it
+    /// with no usable version as a human-readable explanation, e.g.
+    /// "because a requires b>=2 and c-1.0.0 requires b<2, no version of
+    /// 'b' works". Only requirements placed directly on `package` are
+    /// listed; the requirements that led to them are not.
+    fn describe_conflict(package: &str, causes: &[PendingRequirement]) -> String {
+        if causes.is_empty() {
+            return format!("No known version of '{}' exists", package);
+        }
+
+        let reasons: Vec<String> = causes
+            .iter()
+            .map(|cause| format!("{} requires {}", cause.dependent, cause.requirement.requirement_string))
+            .collect();
+
+        format!("because {}, no version of '{}' works", reasons.join(" and "), package)
+    }
     
     /// Check if package name is valid
     fn is_valid_package_name(&self, name: &str) -> bool {
@@ -366,44 +555,81 @@ impl PackageValidator {
         !name.ends_with('-')
     }
     
-    /// Validate a requirement string
-    fn validate_requirement_string(&self, req: &str) -> Result<(), String> {
+    /// Validate a requirement string, parsing it into a [`PackageRequirement`]
+    /// and checking the package name, version range, and (when
+    /// `known_packages` has an entry for this package) whether any known
+    /// version actually satisfies it. Returns non-fatal warnings on success:
+    /// a pinned exact version whose string is not among the known versions.
+    /// A range that no known version satisfies, including a pin of a
+    /// version that does not exist, is an error instead.
+    fn validate_requirement_string(&self, req: &str) -> Result<Vec<String>, String> {
         if req.is_empty() {
             return Err("Requirement cannot be empty".to_string());
         }
-        
-        // Basic validation - a full implementation would parse the requirement
+
         if req.contains("  ") {
             return Err("Requirement contains multiple spaces".to_string());
         }
-        
-        Ok(())
-    }
-    
-    /// Check for circular dependencies (simplified)
-    fn has_circular_dependency(
-        &self, 
-        package_name: &str, 
-        _requires: &[String],
-        visited: &mut HashSet<String>, 
-        path: &mut Vec<String>
-    ) -> bool {
-        if visited.contains(package_name) {
-            return true;
+
+        let parsed = PackageRequirement::parse(req).map_err(|e| e.to_string())?;
+
+        if !self.is_valid_package_name(&parsed.name) {
+            return Err(format!("Invalid package name format: '{}'", parsed.name));
         }
-        
-        visited.insert(package_name.to_string());
-        path.push(package_name.to_string());
-        
-        // In a full implementation, this would resolve requirements
-        // and recursively check dependencies
-        
-        path.pop();
-        visited.remove(package_name);
-        false
+
+        let mut warnings = Vec::new();
+
+        if let Some(ref range) = parsed.range {
+            if range.is_empty() {
+                return Err(format!("Version range '{}' is unsatisfiable", range.as_str()));
+            }
+
+            if let Some(known_versions) = self.known_packages.get(&parsed.name) {
+                if !known_versions.iter().any(|v| range.contains_version(v)) {
+                    return Err(format!(
+                        "No known version of '{}' satisfies '{}'",
+                        parsed.name,
+                        range.as_str()
+                    ));
+                }
+
+                if let Some(exact_versions) = range.to_versions() {
+                    if let Some(exact) = exact_versions.first() {
+                        if !known_versions.iter().any(|v| v.as_str() == exact.as_str()) {
+                            warnings.push(format!(
+                                "Pinned version '{}' of '{}' is not among known versions",
+                                exact.as_str(),
+                                parsed.name
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(warnings)
     }
 }
 
+/// A requirement still waiting to be unit-propagated by
+/// [`PackageValidator::propagate`], paired with who declared it so a
+/// conflict can explain itself.
+#[derive(Clone)]
+struct PendingRequirement {
+    dependent: String,
+    requirement: PackageRequirement,
+}
+
+/// Why [`PackageValidator::propagate`] stopped short of a full solution.
+enum PropagationFailure {
+    /// `chain` revisits a package already on the decision path, e.g.
+    /// `["a", "b", "a"]`.
+    Cycle(Vec<String>),
+    /// No known version of this package satisfied every requirement
+    /// imposed on it.
+    Conflict(String),
+}
+
 impl Default for PackageValidationResult {
     fn default() -> Self {
         Self::new()
@@ -32,6 +32,8 @@ pub struct BindingConfig {
     pub path_separator: String,
     /// Whether to use absolute paths
     pub use_absolute_paths: bool,
+    /// Whether to generate shell tab-completion stubs for package tools
+    pub generate_completions: bool,
 }
 
 impl Default for BindingConfig {
@@ -43,6 +45,66 @@ impl Default for BindingConfig {
             env_var_prefix: None,
             path_separator: if cfg!(windows) { ";" } else { ":" }.to_string(),
             use_absolute_paths: true,
+            generate_completions: false,
+        }
+    }
+}
+
+impl BindingConfig {
+    /// Build a configuration purely from environment variables, falling
+    /// back to [`BindingConfig::default`] for anything unset.
+    ///
+    /// Recognized variables: `REZ_BINDING_ENV_PREFIX`,
+    /// `REZ_BINDING_PATH_SEPARATOR`, `REZ_BINDING_ABSOLUTE_PATHS`,
+    /// `REZ_BINDING_GENERATE_ALIASES`, `REZ_BINDING_GENERATE_PATH`,
+    /// `REZ_BINDING_GENERATE_ENV_VARS`, and
+    /// `REZ_BINDING_GENERATE_COMPLETIONS`.
+    pub fn from_env() -> Self {
+        Self::with_env_overrides(Self::default())
+    }
+
+    /// Layer environment variable overrides on top of `base`, leaving any
+    /// unset variable's field untouched. This lets users and CI tune
+    /// binding generation (disable aliases, force a path separator for
+    /// cross-platform generation, set a custom prefix) without code
+    /// changes.
+    pub fn with_env_overrides(base: Self) -> Self {
+        let mut config = base;
+
+        if let Ok(prefix) = std::env::var("REZ_BINDING_ENV_PREFIX") {
+            config.env_var_prefix = Some(prefix);
+        }
+        if let Ok(separator) = std::env::var("REZ_BINDING_PATH_SEPARATOR") {
+            config.path_separator = separator;
+        }
+        if let Some(value) = Self::env_bool("REZ_BINDING_ABSOLUTE_PATHS") {
+            config.use_absolute_paths = value;
+        }
+        if let Some(value) = Self::env_bool("REZ_BINDING_GENERATE_ALIASES") {
+            config.generate_tool_aliases = value;
+        }
+        if let Some(value) = Self::env_bool("REZ_BINDING_GENERATE_PATH") {
+            config.generate_path_bindings = value;
+        }
+        if let Some(value) = Self::env_bool("REZ_BINDING_GENERATE_ENV_VARS") {
+            config.generate_package_env_vars = value;
+        }
+        if let Some(value) = Self::env_bool("REZ_BINDING_GENERATE_COMPLETIONS") {
+            config.generate_completions = value;
+        }
+
+        config
+    }
+
+    /// Parse a boolean-ish environment variable (`1`/`0`, `true`/`false`,
+    /// `yes`/`no`, case-insensitive). Returns `None` when unset or
+    /// unparseable, so callers can fall back to the existing value.
+    fn env_bool(var: &str) -> Option<bool> {
+        let value = std::env::var(var).ok()?;
+        match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Some(true),
+            "0" | "false" | "no" | "off" => Some(false),
+            _ => None,
         }
     }
 }
@@ -141,6 +203,37 @@ impl RexBindingGenerator {
         Ok(builder.build())
     }
 
+    /// Build a structured, serializable plan of the commands that
+    /// [`generate_context_bindings`](Self::generate_context_bindings) would
+    /// render as a shell script. This lets external tooling (IDE
+    /// integrations, container image builders, CI) consume the resolved
+    /// environment without executing any shell.
+    pub fn generate_context_plan(&self, context: &ResolvedContext) -> Result<BindingPlan, RezCoreError> {
+        let mut packages = Vec::with_capacity(context.resolved_packages.len());
+
+        for package in &context.resolved_packages {
+            let script = self.generate_package_bindings(package)?;
+            let commands: Vec<RexCommand> = script
+                .commands
+                .into_iter()
+                .filter(|command| !matches!(command, RexCommand::Comment { .. }))
+                .collect();
+
+            packages.push(PackageBindingPlan {
+                name: package.name.clone(),
+                version: package.version.as_ref().map(|v| v.as_str().to_string()),
+                commands,
+            });
+        }
+
+        Ok(BindingPlan {
+            context_id: context.id.clone(),
+            context_name: context.name.clone(),
+            shell_type: self.shell_type.clone(),
+            packages,
+        })
+    }
+
     /// Add package-specific environment variables
     fn add_package_env_vars(&self, mut builder: RexCommandBuilder, package: &Package) -> Result<RexCommandBuilder, RezCoreError> {
         let prefix = self.config.env_var_prefix.as_deref().unwrap_or("");
@@ -200,6 +293,38 @@ impl RexBindingGenerator {
         Ok(builder)
     }
 
+    /// Generate per-shell tab-completion stubs for a package's tools.
+    ///
+    /// Emits a `complete`/`compdef`/`complete` fragment (bash/zsh/fish)
+    /// registering each tool name. The fragment shells out to the tool
+    /// itself (`<tool> --generate-completion <shell>`) at completion time,
+    /// so tools that implement their own completion generator get working
+    /// completions for free, while others just produce no matches.
+    pub fn generate_tool_completions(&self, package: &Package) -> Result<RexScript, RezCoreError> {
+        let mut builder = RexCommandBuilder::new();
+        builder = builder.comment(format!("Tab completion for package: {}", package.name));
+
+        for tool in &package.tools {
+            let line = match self.shell_type {
+                ShellType::Bash => format!("complete -C '{tool} --generate-completion bash' {tool}"),
+                ShellType::Zsh => format!("_rez_complete_{tool}() {{ compadd -- $({tool} --generate-completion zsh) }}; compdef _rez_complete_{tool} {tool}"),
+                ShellType::Fish => format!("complete -c {tool} -a \"({tool} --generate-completion fish)\""),
+                ShellType::Cmd | ShellType::PowerShell => {
+                    // No native tab-completion registration mechanism for
+                    // these shells; skip rather than emit something invalid.
+                    continue;
+                }
+            };
+
+            builder.commands.push(RexCommand::Command {
+                command: line,
+                args: Vec::new(),
+            });
+        }
+
+        Ok(builder.build())
+    }
+
     /// Add package commands
     fn add_package_commands(&self, mut builder: RexCommandBuilder, package: &Package, commands: &str) -> Result<RexCommandBuilder, RezCoreError> {
         // Parse the commands string and convert to Rex commands
@@ -383,8 +508,20 @@ pub struct RexBindingUtils;
 impl RexBindingUtils {
     /// Generate bindings for all common shell types
     pub fn generate_all_shell_bindings(context: &ResolvedContext) -> Result<HashMap<ShellType, String>, RezCoreError> {
+        Self::generate_all_shell_bindings_with_config(context, BindingConfig::default())
+    }
+
+    /// Like [`generate_all_shell_bindings`](Self::generate_all_shell_bindings), but with an
+    /// explicit [`BindingConfig`]. When `config.generate_completions` is
+    /// set, each shell's tab-completion fragments for every resolved
+    /// package's tools are folded into that shell's output.
+    pub fn generate_all_shell_bindings_with_config(
+        context: &ResolvedContext,
+        config: BindingConfig,
+    ) -> Result<HashMap<ShellType, String>, RezCoreError> {
         let mut bindings = HashMap::new();
-        let generator = RexBindingGenerator::new(ShellType::Bash);
+        let generate_completions = config.generate_completions;
+        let generator = RexBindingGenerator::with_config(ShellType::Bash, config);
 
         let shell_types = vec![
             ShellType::Bash,
@@ -395,7 +532,24 @@ impl RexBindingUtils {
         ];
 
         for shell_type in shell_types {
-            let binding_script = generator.generate_for_shell(context, shell_type.clone())?;
+            let mut binding_script = generator.generate_for_shell(context, shell_type.clone())?;
+
+            if generate_completions {
+                let shell_generator =
+                    RexBindingGenerator::with_config(shell_type.clone(), generator.config.clone());
+                for package in &context.resolved_packages {
+                    let completions = shell_generator.generate_tool_completions(package)?;
+                    if !completions.commands.is_empty() {
+                        let fragment = crate::RexCommandUtils::script_to_shell_script(
+                            &completions,
+                            &shell_type,
+                        )?;
+                        binding_script.push('\n');
+                        binding_script.push_str(&fragment);
+                    }
+                }
+            }
+
             bindings.insert(shell_type, binding_script);
         }
 
@@ -411,15 +565,7 @@ impl RexBindingUtils {
             .map_err(|e| RezCoreError::RexError(format!("Failed to create output directory: {}", e)))?;
 
         for (shell_type, script) in bindings {
-            let filename = match shell_type {
-                ShellType::Bash => "bindings.sh",
-                ShellType::Zsh => "bindings.zsh",
-                ShellType::Fish => "bindings.fish",
-                ShellType::Cmd => "bindings.bat",
-                ShellType::PowerShell => "bindings.ps1",
-            };
-
-            let file_path = output_dir.join(filename);
+            let file_path = output_dir.join(Self::filename_for_shell(shell_type));
             tokio::fs::write(&file_path, script).await
                 .map_err(|e| RezCoreError::RexError(
                     format!("Failed to write {}: {}", file_path.display(), e)
@@ -429,6 +575,56 @@ impl RexBindingUtils {
         Ok(())
     }
 
+    /// Check whether the binding files already on disk match the given
+    /// bindings, without writing anything. This mirrors a formatter's
+    /// `--check` mode: CI can regenerate bindings and verify they match
+    /// what's committed instead of silently overwriting them.
+    pub async fn verify_bindings_against_dir(
+        bindings: &HashMap<ShellType, String>,
+        output_dir: &PathBuf,
+    ) -> Result<HashMap<ShellType, BindingDiff>, RezCoreError> {
+        let mut results = HashMap::new();
+
+        for (shell_type, expected) in bindings {
+            let file_path = output_dir.join(Self::filename_for_shell(shell_type));
+
+            let actual = match tokio::fs::read_to_string(&file_path).await {
+                Ok(contents) => Some(contents),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    return Err(RezCoreError::RexError(format!(
+                        "Failed to read {}: {}",
+                        file_path.display(),
+                        e
+                    )))
+                }
+            };
+
+            let diff = match &actual {
+                Some(actual) if actual == expected => {
+                    BindingDiff::up_to_date(file_path)
+                }
+                Some(actual) => BindingDiff::mismatched(file_path, expected, actual),
+                None => BindingDiff::missing(file_path, expected),
+            };
+
+            results.insert(shell_type.clone(), diff);
+        }
+
+        Ok(results)
+    }
+
+    /// Filename used for a shell's generated binding script
+    fn filename_for_shell(shell_type: &ShellType) -> &'static str {
+        match shell_type {
+            ShellType::Bash => "bindings.sh",
+            ShellType::Zsh => "bindings.zsh",
+            ShellType::Fish => "bindings.fish",
+            ShellType::Cmd => "bindings.bat",
+            ShellType::PowerShell => "bindings.ps1",
+        }
+    }
+
     /// Validate Rex bindings
     pub fn validate_bindings(script: &RexScript) -> Result<BindingValidation, RezCoreError> {
         let mut validation = BindingValidation::default();
@@ -468,6 +664,35 @@ impl RexBindingUtils {
     }
 }
 
+/// A fully-resolved, serializable binding plan for a [`ResolvedContext`],
+/// as produced by
+/// [`RexBindingGenerator::generate_context_plan`]. Unlike a rendered
+/// shell script, this can be consumed directly by external tooling
+/// (IDE integrations, container image builders, CI) without executing
+/// anything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BindingPlan {
+    /// Resolved context this plan was generated from
+    pub context_id: String,
+    /// Optional human-readable context name
+    pub context_name: Option<String>,
+    /// Shell the plan's commands were generated for
+    pub shell_type: ShellType,
+    /// Per-package commands, in dependency order
+    pub packages: Vec<PackageBindingPlan>,
+}
+
+/// The commands a single package contributes to a [`BindingPlan`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageBindingPlan {
+    /// Package name
+    pub name: String,
+    /// Package version, if any
+    pub version: Option<String>,
+    /// Commands contributed by this package
+    pub commands: Vec<RexCommand>,
+}
+
 /// Binding validation result
 #[derive(Debug, Clone, Default)]
 pub struct BindingValidation {
@@ -484,3 +709,71 @@ pub struct BindingValidation {
     /// Number of functions defined
     pub functions_defined: usize,
 }
+
+/// Result of comparing a generated binding script against the file
+/// already on disk for one shell, as produced by
+/// [`RexBindingUtils::verify_bindings_against_dir`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BindingDiff {
+    /// Path the binding file would be written to
+    pub file_path: PathBuf,
+    /// Whether the file on disk already matches the generated bindings
+    pub is_up_to_date: bool,
+    /// Unified-style textual diff of expected vs. actual, empty when up to date
+    pub diff: String,
+}
+
+impl BindingDiff {
+    fn up_to_date(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            is_up_to_date: true,
+            diff: String::new(),
+        }
+    }
+
+    fn missing(file_path: PathBuf, expected: &str) -> Self {
+        let diff = Self::unified_diff("/dev/null", &file_path.display().to_string(), "", expected);
+        Self {
+            file_path,
+            is_up_to_date: false,
+            diff,
+        }
+    }
+
+    fn mismatched(file_path: PathBuf, expected: &str, actual: &str) -> Self {
+        let label = file_path.display().to_string();
+        let diff = Self::unified_diff(&label, &label, actual, expected);
+        Self {
+            file_path,
+            is_up_to_date: false,
+            diff,
+        }
+    }
+
+    /// A minimal unified-diff renderer: no hunk headers or context
+    /// collapsing, just `-`/`+`/` ` prefixed lines, which is enough for a
+    /// human (or CI log) to see exactly what would change.
+    fn unified_diff(from_label: &str, to_label: &str, actual: &str, expected: &str) -> String {
+        let actual_lines: Vec<&str> = actual.lines().collect();
+        let expected_lines: Vec<&str> = expected.lines().collect();
+
+        let mut out = format!("--- {}\n+++ {}\n", from_label, to_label);
+        let max_len = actual_lines.len().max(expected_lines.len());
+
+        for i in 0..max_len {
+            match (actual_lines.get(i), expected_lines.get(i)) {
+                (Some(a), Some(e)) if a == e => out.push_str(&format!(" {}\n", a)),
+                (Some(a), Some(e)) => {
+                    out.push_str(&format!("-{}\n", a));
+                    out.push_str(&format!("+{}\n", e));
+                }
+                (Some(a), None) => out.push_str(&format!("-{}\n", a)),
+                (None, Some(e)) => out.push_str(&format!("+{}\n", e)),
+                (None, None) => {}
+            }
+        }
+
+        out
+    }
+}
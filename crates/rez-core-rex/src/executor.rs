@@ -1,12 +1,14 @@
 //! Rex command executor
 
-use crate::{RexScript, RexInterpreter, RexBindingGenerator, ExecutionResult};
+use crate::{RexScript, RexInterpreter, RexBindingGenerator, RexCommand, ExecutionResult};
 use rez_core_common::RezCoreError;
 use rez_core_context::{ResolvedContext, ShellExecutor, ShellType, CommandResult};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Rex executor for running Rex scripts in resolved contexts
 #[pyclass]
@@ -39,6 +41,17 @@ pub struct ExecutorConfig {
     pub validate_before_execution: bool,
     /// Debug mode
     pub debug_mode: bool,
+    /// Observer notified before/after each `RexCommand` during
+    /// `execute_script`, and when `execute_package_bindings` loads a
+    /// package's bindings. Not serializable, so it's skipped (and reset to
+    /// `None`) by `ExecutorConfig`'s (de)serialization.
+    #[serde(skip)]
+    pub observer: Option<Arc<dyn ExecutionObserver>>,
+    /// Aliases seeded into the interpreter before any script runs, e.g.
+    /// ones resolved from a context or package's own alias definitions.
+    /// A script's own `alias` commands still take precedence, since they
+    /// run afterward and simply overwrite these.
+    pub preset_aliases: HashMap<String, String>,
 }
 
 impl Default for ExecutorConfig {
@@ -50,10 +63,134 @@ impl Default for ExecutorConfig {
             auto_generate_bindings: true,
             validate_before_execution: true,
             debug_mode: false,
+            observer: None,
+            preset_aliases: HashMap::new(),
         }
     }
 }
 
+/// Hook invoked around each `RexCommand` as [`RexExecutor::execute_script`]
+/// runs it, and when [`RexExecutor::execute_package_bindings`] loads a
+/// package's bindings. Mirrors the `callback`/`package_load_callback` hooks
+/// a resolved context accepts, but at the level of individual Rex commands
+/// rather than whole-context resolution.
+pub trait ExecutionObserver: std::fmt::Debug + Send + Sync {
+    /// Called immediately before `command` (at `index` in the script) runs,
+    /// with the environment as it stands beforehand. Returning
+    /// [`ObserverAction::Stop`] aborts the remaining commands without
+    /// running this one.
+    fn before_command(
+        &self,
+        _command: &RexCommand,
+        _index: usize,
+        _environment: &HashMap<String, String>,
+    ) -> ObserverAction {
+        ObserverAction::Continue
+    }
+
+    /// Called immediately after `command` finishes, with how long it took
+    /// and the environment snapshot that resulted from running it.
+    /// Returning [`ObserverAction::Stop`] aborts the remaining commands.
+    fn after_command(
+        &self,
+        _command: &RexCommand,
+        _index: usize,
+        _elapsed: Duration,
+        _environment: &HashMap<String, String>,
+    ) -> ObserverAction {
+        ObserverAction::Continue
+    }
+
+    /// Called when `execute_package_bindings` loads bindings for
+    /// `package_name`.
+    fn package_bindings_loaded(&self, _package_name: &str) {}
+}
+
+/// What an [`ExecutionObserver`] callback wants the executor to do next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObserverAction {
+    /// Keep running the script.
+    Continue,
+    /// Abort the remaining commands.
+    Stop,
+}
+
+/// Adapts a Python callable into an [`ExecutionObserver`], so Python callers
+/// can pass a plain function to [`RexExecutor::set_observer`] or
+/// [`RexExecutorBuilder::with_observer`] instead of implementing the trait
+/// in Rust.
+///
+/// The callable is invoked as `observer(event, command, index, elapsed_ms,
+/// environment)`, where `event` is `"before"` or `"after"` and `elapsed_ms`
+/// is `None` for `"before"`; or as `observer("package_bindings_loaded",
+/// package_name)` for the binding hook. Returning the string `"stop"` from a
+/// `"before"`/`"after"` call aborts the remaining commands; any other
+/// return value (including `None`) continues execution.
+pub struct PyExecutionObserver {
+    callback: PyObject,
+}
+
+impl PyExecutionObserver {
+    /// Wrap a Python callable as an [`ExecutionObserver`].
+    pub fn new(callback: PyObject) -> Self {
+        Self { callback }
+    }
+
+    fn invoke(
+        &self,
+        event: &str,
+        command: &RexCommand,
+        index: usize,
+        elapsed_ms: Option<u64>,
+        environment: &HashMap<String, String>,
+    ) -> ObserverAction {
+        Python::with_gil(|py| {
+            let result = self.callback.call1(
+                py,
+                (event, format!("{:?}", command), index, elapsed_ms, environment.clone()),
+            );
+
+            match result.and_then(|value| value.extract::<String>(py)) {
+                Ok(s) if s == "stop" => ObserverAction::Stop,
+                _ => ObserverAction::Continue,
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for PyExecutionObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PyExecutionObserver").finish()
+    }
+}
+
+impl ExecutionObserver for PyExecutionObserver {
+    fn before_command(
+        &self,
+        command: &RexCommand,
+        index: usize,
+        environment: &HashMap<String, String>,
+    ) -> ObserverAction {
+        self.invoke("before", command, index, None, environment)
+    }
+
+    fn after_command(
+        &self,
+        command: &RexCommand,
+        index: usize,
+        elapsed: Duration,
+        environment: &HashMap<String, String>,
+    ) -> ObserverAction {
+        self.invoke("after", command, index, Some(elapsed.as_millis() as u64), environment)
+    }
+
+    fn package_bindings_loaded(&self, package_name: &str) {
+        Python::with_gil(|py| {
+            let _ = self.callback.call1(py, ("package_bindings_loaded", package_name));
+        });
+    }
+}
+
 /// Executor statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutorStats {
@@ -89,6 +226,9 @@ impl Default for ExecutorStats {
 pub struct ScriptExecutionResult {
     /// Whether execution was successful
     pub success: bool,
+    /// Whether an [`ExecutionObserver`] aborted the script partway through
+    /// by returning [`ObserverAction::Stop`], leaving `rex_result` partial
+    pub cancelled: bool,
     /// Rex execution result
     pub rex_result: ExecutionResult,
     /// Shell execution result (if shell commands were run)
@@ -104,6 +244,7 @@ impl ScriptExecutionResult {
     pub fn success(rex_result: ExecutionResult) -> Self {
         Self {
             success: true,
+            cancelled: false,
             rex_result,
             shell_result: None,
             generated_script: None,
@@ -115,6 +256,21 @@ impl ScriptExecutionResult {
     pub fn failure(rex_result: ExecutionResult) -> Self {
         Self {
             success: false,
+            cancelled: false,
+            rex_result,
+            shell_result: None,
+            generated_script: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Create a result for a script an [`ExecutionObserver`] aborted
+    /// partway through; `rex_result` reflects only the commands that ran
+    /// before the observer returned [`ObserverAction::Stop`].
+    pub fn cancelled(rex_result: ExecutionResult) -> Self {
+        Self {
+            success: false,
+            cancelled: true,
             rex_result,
             shell_result: None,
             generated_script: None,
@@ -183,6 +339,13 @@ impl RexExecutor {
     pub fn context_id(&self) -> String {
         self.context.id.clone()
     }
+
+    /// Register a Python callable to observe script execution, replacing
+    /// any previously registered observer. See [`PyExecutionObserver`] for
+    /// the calling convention.
+    pub fn set_observer(&mut self, callback: PyObject) {
+        self.config.observer = Some(Arc::new(PyExecutionObserver::new(callback)));
+    }
 }
 
 impl RexExecutor {
@@ -195,8 +358,9 @@ impl RexExecutor {
             ..Default::default()
         };
 
-        let interpreter = RexInterpreter::with_config(interpreter_config);
-        
+        let mut interpreter = RexInterpreter::with_config(interpreter_config);
+        interpreter.seed_aliases(config.preset_aliases.clone());
+
         let shell_executor = ShellExecutor::with_shell(config.shell_type.clone())
             .with_environment(context.environment_vars.clone())
             .with_timeout(config.timeout_seconds);
@@ -217,6 +381,12 @@ impl RexExecutor {
     }
 
     /// Execute a Rex script
+    ///
+    /// Runs the script one command at a time (rather than delegating the
+    /// whole script to the interpreter in one call) so the configured
+    /// [`ExecutionObserver`], if any, can inspect each command before and
+    /// after it runs and abort the remaining commands by returning
+    /// [`ObserverAction::Stop`].
     pub async fn execute_script(&mut self, script: &RexScript) -> Result<ScriptExecutionResult, RezCoreError> {
         let start_time = std::time::Instant::now();
         self.stats.scripts_executed += 1;
@@ -226,15 +396,54 @@ impl RexExecutor {
             self.validate_script(script)?;
         }
 
-        // Execute the script with the interpreter
-        let rex_result = self.interpreter.execute_script(script).await?;
+        let observer = self.config.observer.clone();
+        let mut rex_result = ExecutionResult::success();
+        let mut cancelled = false;
+
+        for (index, command) in script.commands.iter().enumerate() {
+            if let Some(observer) = observer.as_ref() {
+                let environment = self.interpreter.environment();
+                if observer.before_command(command, index, &environment) == ObserverAction::Stop {
+                    cancelled = true;
+                    break;
+                }
+            }
+
+            let command_start = std::time::Instant::now();
+            let command_result = self.interpreter.execute_command(command).await?;
+            let elapsed = command_start.elapsed();
+            let command_succeeded = command_result.success;
+
+            rex_result.output.extend(command_result.output);
+            rex_result.errors.extend(command_result.errors);
+            rex_result.env_changes.extend(command_result.env_changes);
+            if !command_succeeded {
+                rex_result.success = false;
+            }
+
+            if let Some(observer) = observer.as_ref() {
+                let environment = self.interpreter.environment();
+                if observer.after_command(command, index, elapsed, &environment) == ObserverAction::Stop {
+                    cancelled = true;
+                    break;
+                }
+            }
+
+            if !command_succeeded {
+                break;
+            }
+        }
 
         // Update statistics
         let execution_time_ms = start_time.elapsed().as_millis() as u64;
+        rex_result.execution_time_ms = execution_time_ms;
         self.stats.total_execution_time_ms += execution_time_ms;
         self.stats.commands_executed += script.commands.len();
 
-        let mut script_result = if rex_result.success {
+        let mut script_result = if cancelled {
+            self.stats.failed_executions += 1;
+            ScriptExecutionResult::cancelled(rex_result)
+        } else if rex_result.success {
             self.stats.successful_executions += 1;
             ScriptExecutionResult::success(rex_result)
         } else {
@@ -256,13 +465,44 @@ impl RexExecutor {
         Ok(script_result)
     }
 
-    /// Execute a Rex script from string content
+    /// Execute a Rex script from string content. On a malformed script, the
+    /// returned error carries a compiler-style diagnostic from the parser
+    /// (line, column, the offending source line, and a caret), rather than
+    /// a bare "parse failed" message.
     pub async fn execute_script_content(&mut self, content: &str) -> Result<ScriptExecutionResult, RezCoreError> {
-        let parser = crate::RexParser::new();
+        let parser = crate::RexParser::with_config(crate::ParserConfig {
+            strict_mode: true,
+            ..Default::default()
+        });
         let script = parser.parse(content)?;
         self.execute_script(&script).await
     }
 
+    /// Execute a Rex script read in full from any [`std::io::Read`]
+    /// source, e.g. a file handle or a pipe. Useful for tooling that
+    /// wants to pass package commands without first materializing a
+    /// `String` or `Package`.
+    pub async fn execute_script_from_reader<R: std::io::Read>(
+        &mut self,
+        reader: &mut R,
+    ) -> Result<ScriptExecutionResult, RezCoreError> {
+        let mut content = String::new();
+        reader
+            .read_to_string(&mut content)
+            .map_err(|e| RezCoreError::RexError(format!("Failed to read script: {}", e)))?;
+
+        self.execute_script_content(&content).await
+    }
+
+    /// Execute a Rex script piped in on stdin. Equivalent to
+    /// `execute_script_from_reader(&mut std::io::stdin())`, but reads a
+    /// lock on stdin for the caller.
+    pub async fn execute_script_from_stdin(&mut self) -> Result<ScriptExecutionResult, RezCoreError> {
+        let stdin = std::io::stdin();
+        let mut handle = stdin.lock();
+        self.execute_script_from_reader(&mut handle).await
+    }
+
     /// Execute context bindings
     pub async fn execute_context_bindings(&mut self) -> Result<ScriptExecutionResult, RezCoreError> {
         if !self.config.auto_generate_bindings {
@@ -308,6 +548,10 @@ impl RexExecutor {
         let binding_generator = RexBindingGenerator::new(self.config.shell_type.clone());
         let binding_script = binding_generator.generate_package_bindings(package)?;
 
+        if let Some(observer) = self.config.observer.clone() {
+            observer.package_bindings_loaded(package_name);
+        }
+
         // Execute the binding script
         let mut result = self.execute_script(&binding_script).await?;
 
@@ -452,6 +696,21 @@ impl RexExecutorBuilder {
         self
     }
 
+    /// Register an observer to be notified before/after each command
+    /// during `execute_script`, and when `execute_package_bindings` loads
+    /// a package's bindings.
+    pub fn with_observer(mut self, observer: Arc<dyn ExecutionObserver>) -> Self {
+        self.config.observer = Some(observer);
+        self
+    }
+
+    /// Seed an alias into the interpreter before any script runs. Can be
+    /// called multiple times to seed several aliases.
+    pub fn with_alias(mut self, name: String, command: String) -> Self {
+        self.config.preset_aliases.insert(name, command);
+        self
+    }
+
     /// Build the Rex executor
     pub fn build(self) -> RexExecutor {
         RexExecutor::with_config(self.context, self.config)
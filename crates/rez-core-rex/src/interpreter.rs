@@ -400,23 +400,15 @@ impl RexInterpreter {
 
     /// Execute command call
     async fn execute_command_call(&mut self, command: &str, args: &[String]) -> Result<ExecutionResult, RezCoreError> {
-        // Check if it's an alias
-        if let Some(alias_command) = self.aliases.get(command) {
-            if self.config.debug_mode {
-                println!("Executing alias: {} -> {}", command, alias_command);
-            }
-            
-            // For simplicity, just return success with alias info
-            return Ok(ExecutionResult::success()
-                .with_output(format!("Executed alias: {} -> {}", command, alias_command)));
-        }
+        let (command, args) = self.expand_aliases(command, args)?;
+        let command = command.as_str();
 
         // Check if it's a function
         if let Some(function_body) = self.functions.get(command) {
             if self.config.debug_mode {
                 println!("Executing function: {}", command);
             }
-            
+
             // For simplicity, just return success with function info
             return Ok(ExecutionResult::success()
                 .with_output(format!("Executed function: {}", command)));
@@ -434,8 +426,76 @@ impl RexInterpreter {
             println!("Executing command: {}", full_command);
         }
 
-        Ok(ExecutionResult::success()
-            .with_output(format!("Command: {}", full_command)))
+        let mut result = ExecutionResult::success()
+            .with_output(format!("Command: {}", full_command));
+
+        if let Some(suggestion) = self.suggest_known_command(command) {
+            result = result.with_output(format!(
+                "'{}' is not a known alias or function — did you mean `{}`?",
+                command, suggestion
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Expand `command`'s leading alias, re-scanning the substituted body
+    /// (whose own leading token might itself be an alias) until it
+    /// resolves to a non-alias command, returning the final command and
+    /// its arguments (the alias body's own trailing tokens, followed by
+    /// the caller-supplied `args`).
+    ///
+    /// Detects cycles (e.g. `alias a b` / `alias b a`) by tracking which
+    /// alias names have already been expanded on this resolution chain,
+    /// and bails out with a `RezCoreError::RexError` rather than looping
+    /// forever; also bounded by `config.max_recursion_depth` as a
+    /// backstop against very long (but non-cyclic) alias chains.
+    fn expand_aliases(
+        &self,
+        command: &str,
+        args: &[String],
+    ) -> Result<(String, Vec<String>), RezCoreError> {
+        let mut command = command.to_string();
+        let mut args = args.to_vec();
+        let mut seen = std::collections::HashSet::new();
+
+        loop {
+            let Some(alias_body) = self.aliases.get(&command) else {
+                return Ok((command, args));
+            };
+
+            if !seen.insert(command.clone()) || seen.len() > self.config.max_recursion_depth {
+                return Err(RezCoreError::RexError(format!(
+                    "Alias cycle (or recursion limit) detected while expanding '{}'",
+                    command
+                )));
+            }
+
+            let mut tokens = alias_body.split_whitespace();
+            let Some(head) = tokens.next() else {
+                return Ok((command, args));
+            };
+
+            let mut expanded_args: Vec<String> = tokens.map(|s| s.to_string()).collect();
+            expanded_args.extend(args);
+            command = head.to_string();
+            args = expanded_args;
+        }
+    }
+
+    /// Find the closest known alias or function name to `command` by
+    /// Levenshtein distance, for suggesting a likely typo fix. Only
+    /// returns a suggestion within 2 edits of `command`, so unrelated
+    /// real shell commands (which are never registered as aliases or
+    /// functions here) don't get a spurious "did you mean" note.
+    fn suggest_known_command(&self, command: &str) -> Option<String> {
+        self.aliases
+            .keys()
+            .chain(self.functions.keys())
+            .map(|candidate| (candidate, levenshtein(command, candidate)))
+            .filter(|(_, distance)| (1..=2).contains(distance))
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(candidate, _)| candidate.clone())
     }
 
     /// Execute if command
@@ -530,6 +590,14 @@ impl RexInterpreter {
     pub fn get_stats(&self) -> &InterpreterStats {
         &self.stats
     }
+
+    /// Seed aliases before any script runs, e.g. ones pulled from an
+    /// `ExecutorConfig` or resolved from a context/package's own alias
+    /// definitions. Aliases defined later by an `alias` command in a
+    /// running script take precedence by simply overwriting these.
+    pub fn seed_aliases(&mut self, aliases: impl IntoIterator<Item = (String, String)>) {
+        self.aliases.extend(aliases);
+    }
 }
 
 impl Default for RexInterpreter {
@@ -537,3 +605,30 @@ impl Default for RexInterpreter {
         Self::new()
     }
 }
+
+/// Classic Levenshtein edit distance between two strings, used by
+/// [`RexInterpreter::suggest_known_command`] to suggest a likely
+/// alias/function name for a probably-mistyped command.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dp = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        dp[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = (dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1)
+                .min(dp[i - 1][j - 1] + cost);
+        }
+    }
+
+    dp[a.len()][b.len()]
+}
@@ -193,6 +193,112 @@ impl Default for ParserConfig {
     }
 }
 
+/// A parse failure with its exact location in the source: byte offset,
+/// 1-based line and column, and the offending line's text, so it can be
+/// rendered as a compiler-style diagnostic via [`RexSourceContext::annotate`]
+/// rather than a bare "parse failed" message.
+#[derive(Debug, Clone)]
+pub struct RexParseError {
+    /// Description of what went wrong on this line
+    pub message: String,
+    /// Byte offset of the offending line's first non-whitespace character
+    /// within the source
+    pub byte_offset: usize,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column of the first non-whitespace character
+    pub column: usize,
+    /// The full text of the offending line, untrimmed
+    pub line_text: String,
+}
+
+/// Source location and package context attached to a Rex script being
+/// parsed, so parse errors can point back to where the script came from
+/// (e.g. a package's `commands()` block) instead of just the line text.
+#[derive(Debug, Clone, Default)]
+pub struct RexSourceContext {
+    /// Path or label identifying where the script came from, e.g.
+    /// `package.py` or a `commands.rex` file path
+    pub source: Option<String>,
+    /// Name of the package whose commands are being parsed
+    pub package_name: Option<String>,
+    /// Version of the package whose commands are being parsed
+    pub package_version: Option<String>,
+}
+
+impl RexSourceContext {
+    /// Build a context for a specific package
+    pub fn for_package(package_name: impl Into<String>) -> Self {
+        Self {
+            source: None,
+            package_name: Some(package_name.into()),
+            package_version: None,
+        }
+    }
+
+    /// Attach a source file/label to this context
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Attach a package version to this context
+    pub fn with_package_version(mut self, version: impl Into<String>) -> Self {
+        self.package_version = Some(version.into());
+        self
+    }
+
+    /// Render `error` as a compiler-style diagnostic: a
+    /// `"Parse error in <source> [<package>@<version>] at line L, column C: <message>"`
+    /// header (omitting any part that isn't set), followed by the
+    /// offending source line and a caret pointing at the column, e.g.
+    ///
+    /// ```text
+    /// Parse error in commands.rex at line 3, column 3: Rex error: setenv requires name and value
+    ///     setenv FOO
+    ///     ^
+    /// ```
+    fn annotate(&self, error: &RexParseError) -> String {
+        let mut location = String::new();
+
+        if let Some(ref source) = self.source {
+            location.push_str(source);
+        }
+
+        if let Some(ref package_name) = self.package_name {
+            if !location.is_empty() {
+                location.push(' ');
+            }
+            location.push('[');
+            location.push_str(package_name);
+            if let Some(ref version) = self.package_version {
+                location.push('@');
+                location.push_str(version);
+            }
+            location.push(']');
+        }
+
+        let header = if location.is_empty() {
+            format!(
+                "Parse error at line {}, column {}: {}",
+                error.line, error.column, error.message
+            )
+        } else {
+            format!(
+                "Parse error in {} at line {}, column {}: {}",
+                location, error.line, error.column, error.message
+            )
+        };
+
+        format!(
+            "{}\n  {}\n  {}^",
+            header,
+            error.line_text,
+            " ".repeat(error.column.saturating_sub(1))
+        )
+    }
+}
+
 impl RexParser {
     /// Create a new Rex parser
     pub fn new() -> Self {
@@ -208,25 +314,51 @@ impl RexParser {
 
     /// Parse a Rex script from string
     pub fn parse(&self, content: &str) -> Result<RexScript, RezCoreError> {
+        self.parse_with_source(content, &RexSourceContext::default())
+    }
+
+    /// Parse a Rex script from string, attaching source location and
+    /// package context to any parse error so the message can point a
+    /// user back to the `package.py`/`commands()` block that produced it,
+    /// instead of just the bare Rex line.
+    pub fn parse_with_source(
+        &self,
+        content: &str,
+        source: &RexSourceContext,
+    ) -> Result<RexScript, RezCoreError> {
         let mut script = RexScript::new();
-        
-        for (line_num, line) in content.lines().enumerate() {
-            let line = line.trim();
-            
+        let mut byte_offset = 0usize;
+
+        for (line_num, raw_line) in content.lines().enumerate() {
+            let line_offset = byte_offset;
+            // `.lines()` strips the line terminator, so account for the `\n`
+            // it split on when advancing to the next line's offset.
+            byte_offset += raw_line.len() + 1;
+
+            let trimmed_start = raw_line.trim_start();
+            let column = raw_line.len() - trimmed_start.len() + 1;
+            let line = trimmed_start.trim_end();
+
             // Skip empty lines
             if line.is_empty() {
                 continue;
             }
-            
+
             // Parse command
             match self.parse_line(line) {
                 Ok(Some(command)) => script.add_command(command),
                 Ok(None) => {}, // Empty or comment line
                 Err(e) => {
+                    let parse_error = RexParseError {
+                        message: e.to_string(),
+                        byte_offset: line_offset + column - 1,
+                        line: line_num + 1,
+                        column,
+                        line_text: raw_line.to_string(),
+                    };
+
                     if self.config.strict_mode {
-                        return Err(RezCoreError::RexError(
-                            format!("Parse error at line {}: {}", line_num + 1, e)
-                        ));
+                        return Err(RezCoreError::RexError(source.annotate(&parse_error)));
                     } else {
                         // In non-strict mode, treat as comment
                         script.add_command(RexCommand::Comment {
@@ -236,7 +368,7 @@ impl RexParser {
                 }
             }
         }
-        
+
         Ok(script)
     }
 
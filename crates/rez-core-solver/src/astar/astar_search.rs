@@ -3,9 +3,15 @@
 //! This module implements the core A* search algorithm optimized for dependency resolution.
 //! It uses heuristic functions to guide the search towards optimal solutions efficiently.
 
+use super::conflict_cache::{ConflictCache, ConflictSet};
+use super::progress::{ResolverProgress, SearchProgress, SearchProgressCallback};
+use super::resolve_error::{PackageId, ResolveError};
 use super::search_state::{
     ConflictType, DependencyConflict, Package, PackageRequirement, SearchState, StatePool,
 };
+use super::transposition::TranspositionTable;
+#[cfg(feature = "python-bindings")]
+use pyo3::prelude::*;
 // Temporarily comment out problematic imports for testing
 // use crate::{SolverConfig, ConflictStrategy};
 // use rez_core_common::RezCoreError;
@@ -75,11 +81,32 @@ pub struct AStarSearch {
     /// Search statistics
     stats: SearchStats,
 
+    /// Cache of previously discovered dead-end activation sets, used to
+    /// prune states and candidates that are already known to fail
+    conflict_cache: ConflictCache,
+
+    /// Every state explored so far, keyed by state id, so a failure can
+    /// walk `parent_id` back to the root to build a package path
+    visited: HashMap<u64, SearchState>,
+
+    /// The most recent unsatisfiable conflict encountered, with the
+    /// path that led to it
+    last_resolve_error: Option<ResolveError>,
+
     /// Maximum search time
     max_search_time: Duration,
 
     /// Maximum number of states to explore
     max_states: usize,
+
+    /// Periodic progress/timing telemetry, reported to a registered
+    /// callback (if any) on a backoff-based cadence
+    progress: ResolverProgress,
+
+    /// Memoizes heuristic evaluation per state hash so equivalent states
+    /// reached by different paths don't recompute it, and prunes
+    /// re-expansion once an equal-or-better `g` is already on record
+    transposition: TranspositionTable,
 }
 
 /// Search statistics for monitoring and debugging
@@ -122,11 +149,47 @@ impl AStarSearch {
             repository_manager,
             config,
             stats: SearchStats::default(),
+            conflict_cache: ConflictCache::new(),
+            visited: HashMap::new(),
+            last_resolve_error: None,
             max_search_time,
             max_states,
+            progress: ResolverProgress::new(),
+            transposition: TranspositionTable::new(),
         }
     }
 
+    /// Register a callback invoked periodically while [`search`](Self::search)
+    /// runs, reporting states explored, open/closed set sizes, the most
+    /// recently expanded state's complexity and depth, and its conflict
+    /// count. A no-op until this is called, so unregistered searches pay
+    /// nothing for the telemetry.
+    pub fn with_progress_callback(mut self, callback: SearchProgressCallback) -> Self {
+        self.progress = self.progress.with_callback(callback);
+        self
+    }
+
+    /// Register a Python callable as the progress callback, so Python
+    /// callers can render a spinner or log line during a long resolve.
+    #[cfg(feature = "python-bindings")]
+    pub fn with_py_progress_callback(self, callback: PyObject) -> Self {
+        self.with_progress_callback(Box::new(move |progress: SearchProgress| {
+            Python::with_gil(|py| {
+                let _ = callback.call1(
+                    py,
+                    (
+                        progress.states_explored,
+                        progress.open_set_size,
+                        progress.closed_set_size,
+                        progress.best_complexity,
+                        progress.conflicts_encountered,
+                        progress.depth,
+                    ),
+                );
+            });
+        }))
+    }
+
     /// Perform A* search to find optimal dependency resolution
     pub async fn search(
         &mut self,
@@ -141,6 +204,8 @@ impl AStarSearch {
 
         self.open_set.push(initial_state);
         self.stats = SearchStats::default();
+        self.progress.reset();
+        self.transposition = TranspositionTable::new();
 
         while let Some(current_state) = self.open_set.pop() {
             // Check time and state limits
@@ -157,8 +222,11 @@ impl AStarSearch {
                 continue;
             }
 
-            // Add to closed set
+            // Add to closed set, keeping the full state around so a
+            // later failure can walk parent_id back to the root
             self.closed_set.insert(current_state.get_hash());
+            self.visited
+                .insert(current_state.state_id, current_state.clone());
             self.stats.states_explored += 1;
 
             // Check if goal state
@@ -168,34 +236,74 @@ impl AStarSearch {
                 return Ok(Some(current_state));
             }
 
-            // Skip invalid states
-            if !current_state.is_valid() {
+            // Skip invalid states, including ones already known to be
+            // doomed by a previously recorded conflict
+            if !current_state.is_valid_with_cache(&self.conflict_cache) {
                 self.stats.invalid_states_pruned += 1;
                 continue;
             }
 
             // Generate successor states
+            let successors_start = Instant::now();
             let successors = self.generate_successors(&current_state).await?;
+            let deps_elapsed = successors_start.elapsed();
 
             for mut successor in successors {
                 let successor_hash = successor.get_hash();
 
-                // Skip if already in closed set
-                if self.closed_set.contains(&successor_hash) {
+                // Skip successors that are already known dead ends; no
+                // point letting them take up space in the open set
+                if !successor.is_valid_with_cache(&self.conflict_cache) {
+                    self.stats.invalid_states_pruned += 1;
                     continue;
                 }
 
-                // Calculate heuristic value
-                let h_value = heuristic_fn(&successor);
-                successor.estimated_total_cost = successor.cost_so_far + h_value;
-
-                // Add to open set
-                self.open_set.push(successor);
+                // Memoize the heuristic value per state hash, and prune
+                // re-expansion once an equal-or-better cost-so-far is
+                // already on record for it. A strictly cheaper path
+                // reopens the state — removing it from the closed set if
+                // it was already there — since non-admissible heuristics
+                // like `ConflictPenaltyHeuristic` can only stay correct
+                // if a later, cheaper path is allowed to override one
+                // that was settled on earlier.
+                let successor_g = successor.cost_so_far;
+                match self
+                    .transposition
+                    .evaluate(successor_hash, successor_g, || heuristic_fn(&successor))
+                {
+                    Some(h_value) => {
+                        successor.estimated_total_cost = successor_g + h_value;
+                        self.closed_set.remove(&successor_hash);
+                        self.open_set.push(successor);
+                    }
+                    None => {
+                        self.stats.invalid_states_pruned += 1;
+                    }
+                }
             }
 
             // Update statistics
             self.stats.open_set_size = self.open_set.len();
             self.stats.closed_set_size = self.closed_set.len();
+
+            let (states_explored, open_set_size, closed_set_size) = (
+                self.stats.states_explored,
+                self.stats.open_set_size,
+                self.stats.closed_set_size,
+            );
+            let (best_complexity, conflicts_encountered, depth) = (
+                current_state.calculate_complexity(),
+                current_state.conflicts.len(),
+                current_state.depth,
+            );
+            self.progress.tick(deps_elapsed, || SearchProgress {
+                states_explored,
+                open_set_size,
+                closed_set_size,
+                best_complexity,
+                conflicts_encountered,
+                depth,
+            });
         }
 
         // No solution found
@@ -205,13 +313,24 @@ impl AStarSearch {
 
     /// Generate successor states from current state
     async fn generate_successors(
-        &self,
+        &mut self,
         current_state: &SearchState,
     ) -> Result<Vec<SearchState>, String> {
         let mut successors = Vec::new();
 
         // Get next requirement to resolve
         if let Some(requirement) = current_state.get_next_requirement() {
+            // Don't bother querying the repository for a requirement
+            // that's already known to be a dead end given what's
+            // resolved so far
+            if self
+                .conflict_cache
+                .is_known_conflict(&requirement.name, &current_state.resolved_packages)
+            {
+                self.stats.invalid_states_pruned += 1;
+                return Ok(successors);
+            }
+
             // Find packages that satisfy this requirement
             let search_criteria = PackageSearchCriteria {
                 name_pattern: Some(requirement.name.clone()),
@@ -226,6 +345,16 @@ impl AStarSearch {
                 .find_packages(&search_criteria)
                 .await?;
 
+            if packages.is_empty() {
+                let conflict = DependencyConflict {
+                    package_name: requirement.name.clone(),
+                    conflicting_requirements: vec![requirement.clone()],
+                    severity: 1.0,
+                    conflict_type: ConflictType::MissingPackage,
+                };
+                self.record_resolve_error(current_state, conflict);
+            }
+
             // Create successor state for each viable package
             for package in packages {
                 if let Ok(successor) = self
@@ -242,7 +371,7 @@ impl AStarSearch {
 
     /// Create a successor state by resolving a requirement with a package
     async fn create_successor_state(
-        &self,
+        &mut self,
         parent_state: &SearchState,
         package: Package,
         resolved_requirement: &PackageRequirement,
@@ -295,15 +424,38 @@ impl AStarSearch {
     }
 
     /// Detect conflicts in the current state
-    async fn detect_conflicts(&self, state: &mut SearchState) -> Result<(), String> {
-        // Check for version conflicts
-        let mut version_conflicts = HashMap::new();
-
-        // Simplified conflict detection for testing
+    async fn detect_conflicts(&mut self, state: &mut SearchState) -> Result<(), String> {
+        // Group pending requirements by package name. Two different
+        // requirement strings pending for the same package mean the
+        // packages already resolved in this state pulled in
+        // incompatible requirements for it.
+        let mut by_name: HashMap<String, Vec<&PackageRequirement>> = HashMap::new();
         for requirement in &state.pending_requirements {
-            if let Some(_resolved_package) = state.resolved_packages.get(&requirement.name) {
-                // For now, assume no version conflicts in testing
-                // TODO: Implement proper version conflict detection
+            by_name
+                .entry(requirement.name.clone())
+                .or_default()
+                .push(requirement);
+        }
+
+        // The scaffold has no per-requirement provenance (which
+        // resolved package introduced which requirement), so the whole
+        // set of currently resolved packages is the best available
+        // approximation of "the activation set responsible".
+        let activations: ConflictSet = state.resolved_packages.keys().cloned().collect();
+
+        let mut new_conflicts = Vec::new();
+        for (package_name, requirements) in &by_name {
+            let distinct_strings: HashSet<&str> = requirements
+                .iter()
+                .map(|req| req.requirement_string.as_str())
+                .collect();
+            if distinct_strings.len() > 1 {
+                new_conflicts.push(DependencyConflict {
+                    package_name: package_name.clone(),
+                    conflicting_requirements: ConflictCache::requirements_for(&activations),
+                    severity: 1.0,
+                    conflict_type: ConflictType::VersionConflict,
+                });
             }
         }
 
@@ -312,19 +464,111 @@ impl AStarSearch {
         // - Platform compatibility checks
         // - Missing package detection
 
+        for conflict in new_conflicts {
+            self.record_conflict(conflict.package_name.clone(), activations.clone());
+            state.add_conflict(conflict);
+        }
+
         Ok(())
     }
 
+    /// Record a newly discovered dead end in the conflict cache and
+    /// backjump past every queued state it already dooms, instead of
+    /// waiting to discover each one individually as it's later popped.
+    fn record_conflict(&mut self, package_name: String, activations: ConflictSet) {
+        self.conflict_cache.record(&package_name, activations.clone());
+        self.backjump(&activations);
+    }
+
+    /// Purge every state from the open set whose resolved packages are
+    /// a superset of `conflict_set` — they're doomed for the same
+    /// reason that was just discovered, so there's no point holding
+    /// them in the queue waiting to be popped and pruned one at a time.
+    fn backjump(&mut self, conflict_set: &ConflictSet) {
+        if conflict_set.is_empty() {
+            return;
+        }
+
+        let drained: Vec<SearchState> = self.open_set.drain().collect();
+        let original_len = drained.len();
+        let retained: Vec<SearchState> = drained
+            .into_iter()
+            .filter(|state| {
+                !conflict_set
+                    .iter()
+                    .all(|name| state.resolved_packages.contains_key(name))
+            })
+            .collect();
+        self.stats.invalid_states_pruned += original_len - retained.len();
+        self.open_set = retained.into_iter().collect();
+    }
+
     /// Get current search statistics
     pub fn get_stats(&self) -> &SearchStats {
         &self.stats
     }
 
+    /// Get the conflict cache accumulated so far
+    pub fn conflict_cache(&self) -> &ConflictCache {
+        &self.conflict_cache
+    }
+
+    /// Get the transposition table accumulated so far, including its
+    /// cache-hit/miss counters
+    pub fn transposition_table(&self) -> &TranspositionTable {
+        &self.transposition
+    }
+
+    /// Get the most recent unsatisfiable conflict encountered during
+    /// the search, with the full package path that led to it
+    pub fn last_error(&self) -> Option<&ResolveError> {
+        self.last_resolve_error.as_ref()
+    }
+
     /// Clear search state for reuse
     pub fn clear(&mut self) {
         self.open_set.clear();
         self.closed_set.clear();
         self.stats = SearchStats::default();
+        self.conflict_cache = ConflictCache::new();
+        self.visited.clear();
+        self.last_resolve_error = None;
+        self.progress.reset();
+        self.transposition = TranspositionTable::new();
+    }
+
+    /// Record an unsatisfiable conflict discovered while expanding
+    /// `state`, building the package path from the root down to it by
+    /// walking `parent_id` back through `self.visited`.
+    fn record_resolve_error(&mut self, state: &SearchState, conflict: DependencyConflict) {
+        let package_path = self.build_package_path(state);
+        self.last_resolve_error = Some(ResolveError::new(conflict, package_path));
+    }
+
+    /// Walk from `state` back to the root via `parent_id`, collecting
+    /// the package resolved on each edge in root-to-leaf order.
+    fn build_package_path(&self, state: &SearchState) -> Vec<PackageId> {
+        let mut path = Vec::new();
+
+        if let Some(package) = &state.last_resolved {
+            path.push(PackageId::from(package));
+        }
+
+        let mut parent_id = state.parent_id;
+        while let Some(id) = parent_id {
+            match self.visited.get(&id) {
+                Some(parent_state) => {
+                    if let Some(package) = &parent_state.last_resolved {
+                        path.push(PackageId::from(package));
+                    }
+                    parent_id = parent_state.parent_id;
+                }
+                None => break,
+            }
+        }
+
+        path.reverse();
+        path
     }
 
     /// Reconstruct solution path from goal state
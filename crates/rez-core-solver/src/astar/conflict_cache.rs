@@ -0,0 +1,166 @@
+//! Conflict cache for the A* dependency resolver
+//!
+//! Tracks dead ends the search has already discovered, keyed by the
+//! package whose requirement couldn't be satisfied, along with the set
+//! of already-resolved packages whose simultaneous activation caused
+//! the conflict. Consulting this cache lets the search skip candidates
+//! — and backjump past whole branches of the open set — that are known
+//! to fail for the same reason, rather than re-deriving the same
+//! conflict one state at a time. This mirrors Cargo's conflict-tracking
+//! resolver.
+
+use super::search_state::{Package, PackageRequirement, SearchState};
+use std::collections::{BTreeSet, HashMap};
+
+/// The set of already-resolved package names whose simultaneous
+/// activation is responsible for a conflict. Stored as a `BTreeSet` so
+/// two equivalent sets compare equal regardless of discovery order.
+pub type ConflictSet = BTreeSet<String>;
+
+/// Records, per conflicting package, every activation set that's been
+/// seen to cause a dead end.
+#[derive(Debug, Clone, Default)]
+pub struct ConflictCache {
+    conflicts: HashMap<String, Vec<ConflictSet>>,
+}
+
+impl ConflictCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that resolving `package_name` is a dead end whenever every
+    /// package in `activations` is already resolved.
+    pub fn record(&mut self, package_name: &str, activations: ConflictSet) {
+        let sets = self.conflicts.entry(package_name.to_string()).or_default();
+        if !activations.is_empty() && !sets.contains(&activations) {
+            sets.push(activations);
+        }
+    }
+
+    /// Is there a recorded conflict for `package_name` whose whole
+    /// activation set is already present in `resolved`? If so, a
+    /// candidate for `package_name` here is known-bad and expanding it
+    /// would just rediscover the same dead end.
+    pub fn is_known_conflict(
+        &self,
+        package_name: &str,
+        resolved: &HashMap<String, Package>,
+    ) -> bool {
+        self.conflicts
+            .get(package_name)
+            .map(|sets| sets.iter().any(|set| is_subset_of_resolved(set, resolved)))
+            .unwrap_or(false)
+    }
+
+    /// Find the first recorded activation set (for any package) that's
+    /// already fully present in `resolved`, regardless of which package
+    /// it was originally recorded against. A state whose resolved
+    /// packages are a superset of such a set is doomed no matter what's
+    /// chosen next, so it should never re-enter the open list.
+    pub fn find_doomed_conflict_set(&self, resolved: &HashMap<String, Package>) -> Option<ConflictSet> {
+        self.conflicts
+            .values()
+            .flatten()
+            .find(|set| is_subset_of_resolved(set, resolved))
+            .cloned()
+    }
+
+    /// Build the `conflicting_requirements` to attach to a
+    /// `DependencyConflict` for `activations`.
+    pub fn requirements_for(activations: &ConflictSet) -> Vec<PackageRequirement> {
+        activations
+            .iter()
+            .map(|name| PackageRequirement {
+                name: name.clone(),
+                requirement_string: name.clone(),
+            })
+            .collect()
+    }
+
+    /// Total number of distinct activation sets recorded, across every
+    /// package.
+    pub fn len(&self) -> usize {
+        self.conflicts.values().map(|sets| sets.len()).sum()
+    }
+
+    /// True if no conflicts have been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn is_subset_of_resolved(set: &ConflictSet, resolved: &HashMap<String, Package>) -> bool {
+    !set.is_empty() && set.iter().all(|name| resolved.contains_key(name))
+}
+
+impl SearchState {
+    /// Same as [`Self::is_valid`], but also treats this state as invalid
+    /// if its resolved packages are a superset of any activation set
+    /// `cache` has already recorded as a dead end — so a state that
+    /// failed down one branch never re-enters the open list via another.
+    pub fn is_valid_with_cache(&self, cache: &ConflictCache) -> bool {
+        self.is_valid() && cache.find_doomed_conflict_set(&self.resolved_packages).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved_with(names: &[&str]) -> HashMap<String, Package> {
+        names
+            .iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    Package {
+                        name: name.to_string(),
+                        requires: vec![],
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_is_known_conflict_requires_full_activation_set() {
+        let mut cache = ConflictCache::new();
+        let activations: ConflictSet = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        cache.record("c", activations);
+
+        assert!(!cache.is_known_conflict("c", &resolved_with(&["a"])));
+        assert!(cache.is_known_conflict("c", &resolved_with(&["a", "b"])));
+        assert!(cache.is_known_conflict("c", &resolved_with(&["a", "b", "extra"])));
+    }
+
+    #[test]
+    fn test_record_deduplicates_identical_activation_sets() {
+        let mut cache = ConflictCache::new();
+        let activations: ConflictSet = ["a"].iter().map(|s| s.to_string()).collect();
+        cache.record("c", activations.clone());
+        cache.record("c", activations);
+
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_find_doomed_conflict_set_ignores_empty_sets() {
+        let mut cache = ConflictCache::new();
+        cache.record("c", ConflictSet::new());
+        assert!(cache.find_doomed_conflict_set(&resolved_with(&["a"])).is_none());
+    }
+
+    #[test]
+    fn test_is_valid_with_cache_flags_doomed_state() {
+        let mut cache = ConflictCache::new();
+        let activations: ConflictSet = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        cache.record("c", activations);
+
+        let mut state = SearchState::new_initial(vec![]);
+        state.resolved_packages = resolved_with(&["a", "b"]);
+
+        assert!(!state.is_valid_with_cache(&cache));
+    }
+}
@@ -0,0 +1,349 @@
+//! Runtime registry for assembling heuristics by name
+//!
+//! [`super::heuristics::HeuristicFactory`] only knows a fixed set of
+//! scenario strings and complexity thresholds baked in at compile time.
+//! [`HeuristicRegistry`] takes the pluggable-backend approach instead:
+//! callers register a named constructor (optionally with a default
+//! weight), and a [`WeightedCompositeHeuristic`] is then resolved by
+//! naming a list of registered components and weights, so a custom
+//! domain heuristic can be blended in alongside the built-ins without
+//! recompiling.
+//!
+//! `HeuristicBenchmark::run_comprehensive_benchmark` benchmarks a fixed
+//! list of heuristics and does not iterate [`HeuristicRegistry::names`],
+//! so entries registered here are not benchmarked by it.
+
+use super::heuristics::{
+    ConflictPenaltyHeuristic, DependencyDepthHeuristic, DependencyHeuristic, HeuristicConfig,
+    RemainingRequirementsHeuristic, VersionPreferenceHeuristic,
+};
+use super::search_state::SearchState;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[cfg(feature = "python-bindings")]
+use pyo3::prelude::*;
+
+/// Builds a fresh boxed heuristic instance from a [`HeuristicConfig`], so
+/// the same registration can be resolved against different configs.
+type HeuristicConstructor =
+    Box<dyn Fn(&HeuristicConfig) -> Box<dyn DependencyHeuristic + Send + Sync> + Send + Sync>;
+
+struct RegisteredHeuristic {
+    constructor: HeuristicConstructor,
+    default_weight: f64,
+}
+
+/// A composite heuristic assembled from named [`HeuristicRegistry`]
+/// entries, each contributing `weight * component.calculate(state)` to
+/// the total.
+///
+/// Unlike [`super::heuristics::CompositeHeuristic`], which always sums
+/// a fixed set of built-ins unweighted, components here are added one at
+/// a time with an explicit weight picked at resolve time.
+pub struct WeightedCompositeHeuristic {
+    components: Vec<(Box<dyn DependencyHeuristic + Send + Sync>, f64)>,
+}
+
+impl WeightedCompositeHeuristic {
+    /// Create an empty weighted composite.
+    pub fn new() -> Self {
+        Self {
+            components: Vec::new(),
+        }
+    }
+
+    /// Add a component heuristic with its resolved weight.
+    pub fn push(&mut self, heuristic: Box<dyn DependencyHeuristic + Send + Sync>, weight: f64) {
+        self.components.push((heuristic, weight));
+    }
+
+    /// Number of components currently assembled.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Whether no components were resolved (e.g. every named component
+    /// was unregistered).
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+impl Default for WeightedCompositeHeuristic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyHeuristic for WeightedCompositeHeuristic {
+    fn calculate(&self, state: &SearchState) -> f64 {
+        self.components
+            .iter()
+            .map(|(heuristic, weight)| heuristic.calculate(state) * weight)
+            .sum()
+    }
+
+    fn name(&self) -> &'static str {
+        "WeightedComposite"
+    }
+
+    fn is_admissible(&self) -> bool {
+        self.components.iter().all(|(heuristic, _)| heuristic.is_admissible())
+    }
+}
+
+/// Adapts a Python callable to [`DependencyHeuristic`], for heuristics
+/// registered across the PyO3 boundary. `SearchState` itself isn't
+/// exposed to Python, so the callable receives summary counts instead:
+/// resolved package count, pending requirement count, conflict count,
+/// and search depth.
+#[cfg(feature = "python-bindings")]
+struct PyHeuristic {
+    callback: PyObject,
+}
+
+#[cfg(feature = "python-bindings")]
+impl DependencyHeuristic for PyHeuristic {
+    fn calculate(&self, state: &SearchState) -> f64 {
+        Python::with_gil(|py| {
+            self.callback
+                .call1(
+                    py,
+                    (
+                        state.resolved_packages.len(),
+                        state.pending_requirements.len(),
+                        state.conflicts.len(),
+                        state.depth,
+                    ),
+                )
+                .and_then(|result| result.extract::<f64>(py))
+                .unwrap_or(0.0)
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "PyHeuristic"
+    }
+
+    fn is_admissible(&self) -> bool {
+        // A user-supplied Python heuristic can't be statically verified
+        // admissible, so assume the conservative answer.
+        false
+    }
+}
+
+/// Runtime registry of named heuristic constructors.
+pub struct HeuristicRegistry {
+    entries: HashMap<String, RegisteredHeuristic>,
+}
+
+impl HeuristicRegistry {
+    /// Create an empty registry with nothing registered.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// A registry pre-populated with the four built-in heuristics, under
+    /// their [`DependencyHeuristic::name`] strings, each with a default
+    /// weight of `1.0`.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register("RemainingRequirements", 1.0, |config| {
+            Box::new(RemainingRequirementsHeuristic::new(config.clone()))
+        });
+        registry.register("ConflictPenalty", 1.0, |config| {
+            Box::new(ConflictPenaltyHeuristic::new(config.clone()))
+        });
+        registry.register("DependencyDepth", 1.0, |config| {
+            Box::new(DependencyDepthHeuristic::new(config.clone()))
+        });
+        registry.register("VersionPreference", 1.0, |config| {
+            Box::new(VersionPreferenceHeuristic::new(config.clone()))
+        });
+        registry
+    }
+
+    /// Register a named heuristic constructor with a default weight,
+    /// overwriting any prior registration under the same name.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        default_weight: f64,
+        constructor: impl Fn(&HeuristicConfig) -> Box<dyn DependencyHeuristic + Send + Sync>
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        self.entries.insert(
+            name.into(),
+            RegisteredHeuristic {
+                constructor: Box::new(constructor),
+                default_weight,
+            },
+        );
+    }
+
+    /// Register a Python callable under `name`, so it can be named in a
+    /// later [`resolve`](Self::resolve) call alongside the built-ins.
+    #[cfg(feature = "python-bindings")]
+    pub fn register_py(&mut self, name: impl Into<String>, default_weight: f64, callback: PyObject) {
+        self.entries.insert(
+            name.into(),
+            RegisteredHeuristic {
+                constructor: Box::new(move |_config: &HeuristicConfig| {
+                    Box::new(PyHeuristic {
+                        callback: callback.clone(),
+                    }) as Box<dyn DependencyHeuristic + Send + Sync>
+                }),
+                default_weight,
+            },
+        );
+    }
+
+    /// Names of every heuristic currently registered.
+    pub fn names(&self) -> Vec<&str> {
+        self.entries.keys().map(String::as_str).collect()
+    }
+
+    /// Build a fresh boxed instance of the named heuristic, or `None` if
+    /// nothing is registered under that name.
+    pub fn build(
+        &self,
+        name: &str,
+        config: &HeuristicConfig,
+    ) -> Option<Box<dyn DependencyHeuristic + Send + Sync>> {
+        self.entries.get(name).map(|entry| (entry.constructor)(config))
+    }
+
+    /// Resolve a weighted composite from a list of `(name, weight)`
+    /// pairs. `weight` overrides the component's registered default
+    /// weight when `Some`; names that aren't registered are skipped.
+    pub fn resolve(
+        &self,
+        components: &[(&str, Option<f64>)],
+        config: &HeuristicConfig,
+    ) -> WeightedCompositeHeuristic {
+        let mut composite = WeightedCompositeHeuristic::new();
+        for (name, weight_override) in components {
+            if let Some(entry) = self.entries.get(*name) {
+                let weight = weight_override.unwrap_or(entry.default_weight);
+                composite.push((entry.constructor)(config), weight);
+            }
+        }
+        composite
+    }
+}
+
+impl Default for HeuristicRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+/// Declarative description of a [`HeuristicRegistry`]'s starting
+/// contents, so one can be built from config (e.g. deserialized solver
+/// settings) instead of only via [`HeuristicRegistry::register`] calls.
+/// Custom constructors and PyO3 callbacks can't be serialized, so they
+/// still need to be registered in code after [`build`](Self::build).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeuristicRegistryConfig {
+    /// Whether to seed the registry with `RemainingRequirements`,
+    /// `ConflictPenalty`, `DependencyDepth`, and `VersionPreference`
+    pub include_builtins: bool,
+}
+
+impl Default for HeuristicRegistryConfig {
+    fn default() -> Self {
+        Self {
+            include_builtins: true,
+        }
+    }
+}
+
+impl HeuristicRegistryConfig {
+    /// Build the registry described by this config.
+    pub fn build(&self) -> HeuristicRegistry {
+        if self.include_builtins {
+            HeuristicRegistry::with_builtins()
+        } else {
+            HeuristicRegistry::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::search_state::PackageRequirement;
+
+    fn test_state() -> SearchState {
+        SearchState::new_initial(vec![PackageRequirement {
+            name: "test_package".to_string(),
+            requirement_string: "test_package".to_string(),
+        }])
+    }
+
+    #[test]
+    fn test_resolve_with_builtins_sums_weighted_components() {
+        let registry = HeuristicRegistry::with_builtins();
+        let config = HeuristicConfig::default();
+        let state = test_state();
+
+        let composite = registry.resolve(
+            &[("RemainingRequirements", Some(2.0)), ("DependencyDepth", None)],
+            &config,
+        );
+
+        assert_eq!(composite.len(), 2);
+
+        let expected = RemainingRequirementsHeuristic::new(config.clone()).calculate(&state) * 2.0
+            + DependencyDepthHeuristic::new(config.clone()).calculate(&state) * 1.0;
+        assert_eq!(composite.calculate(&state), expected);
+    }
+
+    #[test]
+    fn test_resolve_skips_unregistered_names() {
+        let registry = HeuristicRegistry::new();
+        let composite = registry.resolve(&[("DoesNotExist", None)], &HeuristicConfig::default());
+
+        assert!(composite.is_empty());
+        assert_eq!(composite.calculate(&test_state()), 0.0);
+    }
+
+    #[test]
+    fn test_register_overwrites_prior_entry_under_same_name() {
+        let mut registry = HeuristicRegistry::new();
+        registry.register("custom", 1.0, |config| {
+            Box::new(RemainingRequirementsHeuristic::new(config.clone()))
+        });
+        registry.register("custom", 3.0, |config| {
+            Box::new(DependencyDepthHeuristic::new(config.clone()))
+        });
+
+        let config = HeuristicConfig::default();
+        let state = test_state();
+        let built = registry.build("custom", &config).unwrap();
+
+        assert_eq!(built.name(), "DependencyDepth");
+
+        let composite = registry.resolve(&[("custom", None)], &config);
+        let expected = DependencyDepthHeuristic::new(config).calculate(&state) * 3.0;
+        assert_eq!(composite.calculate(&state), expected);
+    }
+
+    #[test]
+    fn test_registry_config_toggles_builtins() {
+        assert!(!HeuristicRegistryConfig { include_builtins: false }
+            .build()
+            .names()
+            .contains(&"RemainingRequirements"));
+
+        assert!(HeuristicRegistryConfig::default()
+            .build()
+            .names()
+            .contains(&"RemainingRequirements"));
+    }
+}
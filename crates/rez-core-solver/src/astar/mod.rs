@@ -11,18 +11,27 @@
 
 pub mod search_state;
 pub mod astar_search;
+pub mod conflict_cache;
+pub mod resolve_error;
 pub mod heuristics;
+pub mod heuristic_registry;
+pub mod progress;
+pub mod transposition;
 pub mod test_framework;
 pub mod standalone_test;
+pub mod heuristic_benchmark;
 
 #[cfg(test)]
 pub mod heuristic_integration_test;
 
-#[cfg(test)]
-pub mod heuristic_benchmark;
-
 pub use search_state::*;
 pub use astar_search::*;
+pub use conflict_cache::*;
+pub use resolve_error::*;
 pub use heuristics::*;
+pub use heuristic_registry::*;
+pub use progress::*;
+pub use transposition::*;
 pub use test_framework::*;
 pub use standalone_test::*;
+pub use heuristic_benchmark::*;
@@ -0,0 +1,167 @@
+//! Progress and timing telemetry for the A* search loop
+//!
+//! A long resolve currently runs silently until it returns or times out.
+//! [`ResolverProgress`] borrows the tick-and-threshold pattern from
+//! Cargo's resolver progress reporting: it's cheap to poll every node
+//! expansion, and only actually calls the registered callback once
+//! `time_to_print` has elapsed, backing that threshold off exponentially
+//! so a fast resolve never pays for more than a couple of checks.
+
+use std::time::{Duration, Instant};
+
+/// A snapshot of A* search progress, reported on a backoff-based cadence
+/// rather than per-node so the callback isn't swamped on large resolves.
+#[derive(Debug, Clone, Copy)]
+pub struct SearchProgress {
+    /// States popped from the open set and expanded so far
+    pub states_explored: usize,
+    /// States still queued for evaluation
+    pub open_set_size: usize,
+    /// States already evaluated
+    pub closed_set_size: usize,
+    /// `calculate_complexity()` of the most recently expanded state
+    pub best_complexity: usize,
+    /// Conflicts recorded on the most recently expanded state
+    pub conflicts_encountered: usize,
+    /// Depth of the most recently expanded state in the search tree
+    pub depth: usize,
+}
+
+/// Callback invoked periodically during [`super::astar_search::AStarSearch::search`]
+pub type SearchProgressCallback = Box<dyn Fn(SearchProgress) + Send + Sync>;
+
+/// Tracks when the search loop should next report progress.
+///
+/// `tick` is meant to be called once per node expansion; it's a no-op
+/// (aside from the `deps_time` bookkeeping) unless a callback is
+/// registered and `time_to_print` has elapsed since the last report, so
+/// it adds negligible overhead to the hot loop.
+pub struct ResolverProgress {
+    ticks: u64,
+    start: Instant,
+    time_to_print: Duration,
+    /// Accumulated time spent fetching candidate dependencies
+    /// (`generate_successors`) across all ticks so far.
+    deps_time: Duration,
+    callback: Option<SearchProgressCallback>,
+}
+
+/// Default threshold before the first progress report is printed.
+const INITIAL_TIME_TO_PRINT: Duration = Duration::from_millis(500);
+
+/// Upper bound on the backed-off threshold, so a resolve that runs for
+/// minutes still reports periodically rather than going silent forever.
+const MAX_TIME_TO_PRINT: Duration = Duration::from_secs(10);
+
+impl ResolverProgress {
+    /// Create a progress tracker with no callback registered (a no-op).
+    pub fn new() -> Self {
+        Self {
+            ticks: 0,
+            start: Instant::now(),
+            time_to_print: INITIAL_TIME_TO_PRINT,
+            deps_time: Duration::ZERO,
+            callback: None,
+        }
+    }
+
+    /// Register the callback to fire on future ticks.
+    pub fn with_callback(mut self, callback: SearchProgressCallback) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+
+    /// Time spent fetching candidate dependencies so far.
+    pub fn deps_time(&self) -> Duration {
+        self.deps_time
+    }
+
+    /// Reset the tick counter, timer, and backoff for a fresh search,
+    /// keeping any registered callback in place.
+    pub fn reset(&mut self) {
+        self.ticks = 0;
+        self.start = Instant::now();
+        self.time_to_print = INITIAL_TIME_TO_PRINT;
+        self.deps_time = Duration::ZERO;
+    }
+
+    /// Record one node expansion, including how long fetching its
+    /// candidate dependencies took, and fire the callback with a fresh
+    /// snapshot if enough time has passed since the last report.
+    ///
+    /// `snapshot` is only evaluated when a report is actually about to
+    /// fire, so building it can be as expensive as the caller likes.
+    pub fn tick(&mut self, deps_elapsed: Duration, snapshot: impl FnOnce() -> SearchProgress) {
+        let Some(callback) = self.callback.as_ref() else {
+            return;
+        };
+
+        self.ticks += 1;
+        self.deps_time += deps_elapsed;
+
+        if self.start.elapsed() < self.time_to_print {
+            return;
+        }
+
+        callback(snapshot());
+
+        // Exponential backoff: each report doubles the threshold before
+        // the next one, capped so very long resolves don't go silent.
+        self.time_to_print = (self.time_to_print * 2).min(MAX_TIME_TO_PRINT);
+        self.start = Instant::now();
+    }
+}
+
+impl Default for ResolverProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_tick_is_a_no_op_without_a_callback() {
+        let mut progress = ResolverProgress::new();
+        progress.tick(Duration::from_millis(1), || panic!("snapshot should not be built"));
+        assert_eq!(progress.deps_time(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_tick_accumulates_deps_time_even_before_first_report() {
+        let mut progress = ResolverProgress::new().with_callback(Box::new(|_| {}));
+        progress.tick(Duration::from_millis(10), || SearchProgress {
+            states_explored: 0,
+            open_set_size: 0,
+            closed_set_size: 0,
+            best_complexity: 0,
+            conflicts_encountered: 0,
+            depth: 0,
+        });
+        assert_eq!(progress.deps_time(), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_tick_does_not_fire_before_threshold_elapses() {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_clone = fired.clone();
+        let mut progress = ResolverProgress::new().with_callback(Box::new(move |_| {
+            fired_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        progress.tick(Duration::ZERO, || SearchProgress {
+            states_explored: 1,
+            open_set_size: 0,
+            closed_set_size: 0,
+            best_complexity: 0,
+            conflicts_encountered: 0,
+            depth: 0,
+        });
+
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+    }
+}
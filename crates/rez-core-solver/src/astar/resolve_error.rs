@@ -0,0 +1,135 @@
+//! Rich dependency resolution failures for the A* resolver
+//!
+//! A bare `Err(String)` tells a caller a resolve failed but not *how*
+//! the search got there. [`ResolveError`] carries the full chain of
+//! packages from the root request down to the package whose
+//! requirement could not be satisfied, alongside the [`DependencyConflict`]
+//! that made the branch unsatisfiable.
+
+use super::search_state::{DependencyConflict, Package};
+
+/// Identifies a resolved package in a [`ResolveError`] path.
+///
+/// The A* scaffold doesn't yet carry version information on `Package`,
+/// so `version` is `None` until the version system is wired in; the
+/// `Display` impl degrades gracefully to the bare name in that case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageId {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl std::fmt::Display for PackageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{}-{}", self.name, version),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl From<&Package> for PackageId {
+    fn from(package: &Package) -> Self {
+        Self {
+            name: package.name.clone(),
+            // TODO: Populate from the resolved package's version once
+            // the version system is available
+            version: None,
+        }
+    }
+}
+
+/// A failed resolve, carrying the full path from the root request down
+/// to the package whose requirements could not be satisfied.
+#[derive(Debug)]
+pub struct ResolveError {
+    /// The conflict that made this branch of the search unsatisfiable
+    pub root_cause: DependencyConflict,
+
+    /// The chain of packages resolved from the root down to (but not
+    /// including) the package named in `root_cause`
+    pub package_path: Vec<PackageId>,
+}
+
+impl ResolveError {
+    /// Create a new resolve error from its root cause and the path that
+    /// led to it.
+    pub fn new(root_cause: DependencyConflict, package_path: Vec<PackageId>) -> Self {
+        Self {
+            root_cause,
+            package_path,
+        }
+    }
+}
+
+impl std::fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "root")?;
+        for package_id in &self.package_path {
+            write!(f, " -> {}", package_id)?;
+        }
+        write!(f, " -> {}", self.root_cause)
+    }
+}
+
+impl std::error::Error for ResolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.root_cause)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search_state::ConflictType;
+    use super::*;
+
+    #[test]
+    fn test_package_id_display_without_version() {
+        let id = PackageId {
+            name: "foo".to_string(),
+            version: None,
+        };
+        assert_eq!(id.to_string(), "foo");
+    }
+
+    #[test]
+    fn test_package_id_display_with_version() {
+        let id = PackageId {
+            name: "foo".to_string(),
+            version: Some("1.2".to_string()),
+        };
+        assert_eq!(id.to_string(), "foo-1.2");
+    }
+
+    #[test]
+    fn test_resolve_error_display_includes_full_path() {
+        let conflict = DependencyConflict {
+            package_name: "bar".to_string(),
+            conflicting_requirements: vec![],
+            severity: 1.0,
+            conflict_type: ConflictType::MissingPackage,
+        };
+        let path = vec![PackageId {
+            name: "foo".to_string(),
+            version: Some("1.2".to_string()),
+        }];
+        let error = ResolveError::new(conflict, path);
+
+        assert!(error.to_string().starts_with("root -> foo-1.2 -> bar"));
+    }
+
+    #[test]
+    fn test_resolve_error_source_exposes_root_cause() {
+        use std::error::Error;
+
+        let conflict = DependencyConflict {
+            package_name: "bar".to_string(),
+            conflicting_requirements: vec![],
+            severity: 1.0,
+            conflict_type: ConflictType::MissingPackage,
+        };
+        let error = ResolveError::new(conflict, vec![]);
+
+        assert!(error.source().is_some());
+    }
+}
@@ -54,6 +54,11 @@ pub struct SearchState {
     /// Unique identifier for this state
     pub state_id: u64,
 
+    /// The package resolved on the edge from the parent state to this
+    /// one (`None` for the initial state), used to reconstruct the
+    /// package path leading to a failure
+    pub last_resolved: Option<Package>,
+
     /// Hash of the state for quick comparison
     state_hash: u64,
 }
@@ -90,6 +95,28 @@ pub enum ConflictType {
     PlatformConflict,
 }
 
+impl std::fmt::Display for DependencyConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let requirements: Vec<&str> = self
+            .conflicting_requirements
+            .iter()
+            .map(|req| req.requirement_string.as_str())
+            .collect();
+        if requirements.is_empty() {
+            write!(f, "{} (no candidates)", self.package_name)
+        } else {
+            write!(
+                f,
+                "{} {} (no candidates)",
+                self.package_name,
+                requirements.join(", ")
+            )
+        }
+    }
+}
+
+impl std::error::Error for DependencyConflict {}
+
 impl SearchState {
     /// Create a new initial search state
     pub fn new_initial(requirements: Vec<PackageRequirement>) -> Self {
@@ -102,6 +129,7 @@ impl SearchState {
             depth: 0,
             parent_id: None,
             state_id: 0,
+            last_resolved: None,
             state_hash: 0,
         };
 
@@ -118,7 +146,7 @@ impl SearchState {
         additional_cost: f64,
     ) -> Self {
         let mut resolved_packages = parent.resolved_packages.clone();
-        resolved_packages.insert(resolved_package.name.clone(), resolved_package);
+        resolved_packages.insert(resolved_package.name.clone(), resolved_package.clone());
 
         // Filter out requirements that are now satisfied
         let mut pending_requirements = parent.pending_requirements.clone();
@@ -133,6 +161,7 @@ impl SearchState {
             depth: parent.depth + 1,
             parent_id: Some(parent.state_id),
             state_id: 0,
+            last_resolved: Some(resolved_package),
             state_hash: 0,
         };
 
@@ -289,6 +318,7 @@ impl StatePool {
             state.depth = 0;
             state.parent_id = None;
             state.state_id = 0;
+            state.last_resolved = None;
             state.state_hash = 0;
 
             self.pool.push(state);
@@ -0,0 +1,163 @@
+//! Transposition table for memoizing per-state heuristic evaluation
+//!
+//! `HeuristicConfig`-driven heuristics are deterministic (see
+//! `test_heuristic_consistency` in [`super::heuristic_integration_test`]),
+//! so the search recomputes the exact same cost every time it reaches an
+//! equivalent [`super::search_state::SearchState`] by a different path.
+//! [`TranspositionTable`] borrows the evaluation-cache idea from rustc's
+//! candidate selection: it's keyed by `SearchState::get_hash()` (already a
+//! canonical hash over the sorted resolved packages, pending requirement
+//! strings, and active conflicts) and maps to the best `g`-cost seen for
+//! that state along with the heuristic value computed for it.
+//!
+//! Hit/miss counters are exposed via [`TranspositionTable::hits`] and
+//! [`TranspositionTable::misses`] so the speedup is measurable.
+//! `HeuristicBenchmark` does not report them; callers read them directly
+//! off the table (see [`super::astar_search::AStarSearch::transposition_table`]).
+
+use std::collections::HashMap;
+
+/// The best `g`-cost recorded for a state so far, and the heuristic value
+/// computed for it the first time it was reached.
+struct TranspositionEntry {
+    best_g: f64,
+    heuristic: f64,
+}
+
+/// Maps a [`SearchState`](super::search_state::SearchState) hash to the
+/// cheapest path found to it and its memoized heuristic value.
+pub struct TranspositionTable {
+    entries: HashMap<u64, TranspositionEntry>,
+    hits: usize,
+    misses: usize,
+}
+
+impl TranspositionTable {
+    /// Create an empty transposition table.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Evaluate a node reaching a state (identified by `hash`) with
+    /// cost-so-far `g`.
+    ///
+    /// Returns `None` when an equal-or-better path to this state is
+    /// already on record — there's nothing to gain from expanding it
+    /// again, so the caller should prune it. Returns `Some(heuristic)`
+    /// otherwise, either the cached heuristic value (a strictly cheaper
+    /// `g` than what was recorded, reopening the state) or a freshly
+    /// computed one via `compute_heuristic` (the state's first visit).
+    ///
+    /// Reopening on a strictly cheaper `g` is required for correctness
+    /// with non-admissible heuristics like `ConflictPenaltyHeuristic`:
+    /// an admissible heuristic never benefits from revisiting a closed
+    /// state, but a non-admissible one can have settled on a suboptimal
+    /// path before a cheaper one was discovered.
+    pub fn evaluate(&mut self, hash: u64, g: f64, compute_heuristic: impl FnOnce() -> f64) -> Option<f64> {
+        match self.entries.get_mut(&hash) {
+            Some(entry) if entry.best_g <= g => {
+                self.hits += 1;
+                None
+            }
+            Some(entry) => {
+                self.hits += 1;
+                entry.best_g = g;
+                Some(entry.heuristic)
+            }
+            None => {
+                self.misses += 1;
+                let heuristic = compute_heuristic();
+                self.entries.insert(hash, TranspositionEntry { best_g: g, heuristic });
+                Some(heuristic)
+            }
+        }
+    }
+
+    /// Number of states re-reached that already had a recorded entry,
+    /// whether that led to a prune or a reopening.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of states evaluated for the first time.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Number of distinct states currently memoized.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the table has no memoized states yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_visit_is_a_miss_and_computes_heuristic() {
+        let mut table = TranspositionTable::new();
+        let mut computed = false;
+
+        let h = table.evaluate(1, 5.0, || {
+            computed = true;
+            2.5
+        });
+
+        assert_eq!(h, Some(2.5));
+        assert!(computed);
+        assert_eq!(table.misses(), 1);
+        assert_eq!(table.hits(), 0);
+    }
+
+    #[test]
+    fn test_equal_or_better_g_is_pruned_without_recomputing() {
+        let mut table = TranspositionTable::new();
+        table.evaluate(1, 5.0, || 2.5);
+
+        let h = table.evaluate(1, 5.0, || panic!("heuristic should not be recomputed"));
+        assert_eq!(h, None);
+
+        let h = table.evaluate(1, 7.0, || panic!("heuristic should not be recomputed"));
+        assert_eq!(h, None);
+
+        assert_eq!(table.hits(), 2);
+        assert_eq!(table.misses(), 1);
+    }
+
+    #[test]
+    fn test_strictly_cheaper_g_reopens_and_reuses_cached_heuristic() {
+        let mut table = TranspositionTable::new();
+        table.evaluate(1, 5.0, || 2.5);
+
+        let h = table.evaluate(1, 3.0, || panic!("heuristic should be reused, not recomputed"));
+        assert_eq!(h, Some(2.5));
+        assert_eq!(table.hits(), 1);
+
+        // The better g is now on record, so an equal-or-worse g again prunes.
+        let h = table.evaluate(1, 3.0, || panic!("heuristic should not be recomputed"));
+        assert_eq!(h, None);
+    }
+
+    #[test]
+    fn test_table_starts_empty() {
+        let table = TranspositionTable::new();
+        assert!(table.is_empty());
+        assert_eq!(table.len(), 0);
+    }
+}
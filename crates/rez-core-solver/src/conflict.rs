@@ -1,12 +1,86 @@
 //! Conflict detection and resolution
 
-use crate::{ConflictResolution, ConflictStrategy, DependencyConflict};
+use crate::ConflictStrategy;
 use rez_core_common::RezCoreError;
 use rez_core_package::PackageRequirement;
 use rez_core_version::{Version, VersionRange};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// A dependency conflict between requirements on the same package
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DependencyConflict {
+    /// Name of the conflicting package
+    pub package_name: String,
+    /// Conflicting requirements
+    pub conflicting_requirements: Vec<PackageRequirement>,
+    /// Packages that introduced the conflicting requirements
+    pub source_packages: Vec<String>,
+    /// Severity of the conflict
+    pub severity: ConflictSeverity,
+}
+
+/// How severe a dependency conflict is
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConflictSeverity {
+    /// A compatible version range still exists
+    Minor,
+    /// No version satisfies every requirement
+    Incompatible,
+}
+
+/// Outcome of resolving a single conflict
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConflictResolution {
+    /// Package the resolution applies to
+    pub package_name: String,
+    /// Version selected for the package, if any
+    pub selected_version: Option<Version>,
+    /// Strategy (or outcome) that produced this resolution
+    pub strategy: String,
+    /// Packages whose requirements were affected by this resolution
+    pub modified_packages: Vec<String>,
+    /// Set when the package could not be resolved at all and was excluded
+    /// from the candidate set instead of aborting the whole solve.
+    pub excluded: Option<ExclusionReason>,
+}
+
+/// Why a candidate package was excluded rather than selected
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExclusionReason {
+    /// Version that was excluded, if one could be identified
+    pub version: Option<Version>,
+    /// Human-readable explanation (e.g. "metadata unavailable")
+    pub reason: String,
+}
+
+impl ConflictResolution {
+    /// Build a resolution marking a package as excluded because its
+    /// metadata (and therefore its dependencies) could not be retrieved.
+    pub fn excluded(
+        package_name: String,
+        version: Option<Version>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            package_name,
+            selected_version: None,
+            strategy: "excluded".to_string(),
+            modified_packages: Vec::new(),
+            excluded: Some(ExclusionReason {
+                version,
+                reason: reason.into(),
+            }),
+        }
+    }
+
+    /// Whether this resolution represents an excluded candidate rather
+    /// than a selected version.
+    pub fn is_excluded(&self) -> bool {
+        self.excluded.is_some()
+    }
+}
+
 /// Conflict resolver for dependency conflicts
 #[derive(Debug)]
 pub struct ConflictResolver {
@@ -98,6 +172,7 @@ impl ConflictResolver {
             selected_version: latest_version,
             strategy: "latest_wins".to_string(),
             modified_packages,
+            excluded: None,
         })
     }
 
@@ -133,6 +208,7 @@ impl ConflictResolver {
             selected_version: earliest_version,
             strategy: "earliest_wins".to_string(),
             modified_packages,
+            excluded: None,
         })
     }
 
@@ -160,9 +236,23 @@ impl ConflictResolver {
             selected_version,
             strategy: "find_compatible".to_string(),
             modified_packages,
+            excluded: None,
         })
     }
 
+    /// Mark a candidate as excluded because its metadata could not be
+    /// fetched, instead of propagating a hard resolution error. The
+    /// resolver can keep trying other candidates for the same package
+    /// name rather than aborting the whole solve.
+    pub fn resolve_unfetchable(
+        &self,
+        package_name: &str,
+        version: Option<Version>,
+        reason: impl Into<String>,
+    ) -> ConflictResolution {
+        ConflictResolution::excluded(package_name.to_string(), version, reason)
+    }
+
     /// Find a version range that is compatible with all requirements
     fn find_compatible_range(
         &self,
@@ -271,6 +361,8 @@ pub enum SuggestionAction {
     AddConstraint(String),
     /// Change resolution strategy
     ChangeStrategy(ConflictStrategy),
+    /// Exclude a version because its metadata could not be fetched
+    Exclude(String),
 }
 
 impl ConflictResolver {
@@ -312,6 +404,35 @@ impl ConflictResolver {
         }
     }
 
+    /// Fold excluded candidates (packages whose metadata could not be
+    /// fetched) into a [`ConflictAnalysis`], alongside ordinary conflicts.
+    pub fn analyze_with_excluded(
+        &self,
+        conflicts: &[DependencyConflict],
+        excluded: &[ConflictResolution],
+    ) -> ConflictAnalysis {
+        let mut analysis = self.analyze_conflicts(conflicts);
+
+        for resolution in excluded {
+            if let Some(exclusion) = &resolution.excluded {
+                analysis.suggestions.push(ConflictSuggestion {
+                    package_name: resolution.package_name.clone(),
+                    action: SuggestionAction::Exclude(
+                        exclusion
+                            .version
+                            .as_ref()
+                            .map(|v| v.as_str().to_string())
+                            .unwrap_or_else(|| "unknown".to_string()),
+                    ),
+                    reason: exclusion.reason.clone(),
+                    confidence: 1.0,
+                });
+            }
+        }
+
+        analysis
+    }
+
     /// Generate suggestions for a specific conflict
     fn generate_suggestions_for_conflict(
         &self,
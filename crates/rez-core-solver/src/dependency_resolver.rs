@@ -21,6 +21,42 @@ pub struct DependencyResolver {
 
     /// Cache of resolved packages
     package_cache: HashMap<String, Vec<Arc<Package>>>,
+
+    /// Optional callback invoked every [`TICK_INTERVAL`] requirements
+    /// processed, reporting progress and flagging slow resolves
+    progress_callback: Option<ProgressCallback>,
+}
+
+/// Number of requirements processed between progress ticks
+const TICK_INTERVAL: usize = 25;
+
+/// A resolve is considered slow once it has run this long without finishing
+const SLOW_RESOLVE_THRESHOLD_MS: u64 = 5_000;
+
+/// Callback invoked periodically during [`DependencyResolver::resolve`]
+pub type ProgressCallback = Box<dyn Fn(ResolveProgress) + Send + Sync>;
+
+/// A snapshot of resolver progress, reported on a tick-based cadence
+/// rather than per-requirement so the callback isn't swamped on large
+/// resolves.
+#[derive(Debug, Clone)]
+pub struct ResolveProgress {
+    /// Monotonically increasing tick counter (one per [`TICK_INTERVAL`]
+    /// requirements processed)
+    pub tick: u64,
+    /// Wall-clock time elapsed since `resolve` was called
+    pub elapsed: std::time::Duration,
+    /// Packages considered so far
+    pub packages_considered: usize,
+    /// Variants evaluated so far
+    pub variants_evaluated: usize,
+    /// Requirements still queued
+    pub pending_requirements: usize,
+    /// Conflicts encountered so far
+    pub conflicts_encountered: usize,
+    /// Set once `elapsed` exceeds [`SLOW_RESOLVE_THRESHOLD_MS`], with a
+    /// diagnostic message describing what the resolver has been doing
+    pub slow_resolve_warning: Option<String>,
 }
 
 /// Resolution result containing resolved packages and metadata
@@ -98,9 +134,19 @@ impl DependencyResolver {
             config,
             stats: SolverStats::default(),
             package_cache: HashMap::new(),
+            progress_callback: None,
         }
     }
 
+    /// Register a callback invoked every [`TICK_INTERVAL`] requirements
+    /// processed during [`resolve`](Self::resolve), reporting progress and
+    /// a slow-resolve diagnostic once the resolve has run long enough to
+    /// be worth reporting on.
+    pub fn with_progress_callback(mut self, callback: ProgressCallback) -> Self {
+        self.progress_callback = Some(callback);
+        self
+    }
+
     /// Resolve a set of requirements into a consistent package set
     pub async fn resolve(
         &mut self,
@@ -112,7 +158,7 @@ impl DependencyResolver {
         let mut resolution_state = ResolutionState::new(requirements.clone());
 
         // Perform resolution
-        let result = self.resolve_recursive(&mut resolution_state).await?;
+        let result = self.resolve_recursive(&mut resolution_state, start_time).await?;
 
         // Calculate statistics
         let resolution_time = start_time.elapsed().as_millis() as u64;
@@ -136,9 +182,19 @@ impl DependencyResolver {
     async fn resolve_recursive(
         &mut self,
         state: &mut ResolutionState,
+        start_time: std::time::Instant,
     ) -> Result<Vec<ResolvedPackageInfo>, RezCoreError> {
+        let mut tick: u64 = 0;
+        let mut requirements_processed: usize = 0;
+
         // Get next requirement to resolve
         while let Some(requirement) = state.get_next_requirement() {
+            requirements_processed += 1;
+            if requirements_processed % TICK_INTERVAL == 0 {
+                tick += 1;
+                self.report_progress(state, tick, start_time.elapsed());
+            }
+
             // Check if we already have a package that satisfies this requirement
             if let Some(existing) = state.find_satisfying_package(&requirement) {
                 // Mark this requirement as satisfied
@@ -183,6 +239,38 @@ impl DependencyResolver {
         Ok(state.resolved_packages.clone())
     }
 
+    /// Emit a progress tick to the registered callback, if any, including
+    /// a slow-resolve diagnostic once the resolve has run past
+    /// [`SLOW_RESOLVE_THRESHOLD_MS`].
+    fn report_progress(&self, state: &ResolutionState, tick: u64, elapsed: std::time::Duration) {
+        let Some(ref callback) = self.progress_callback else {
+            return;
+        };
+
+        let slow_resolve_warning = if elapsed.as_millis() as u64 > SLOW_RESOLVE_THRESHOLD_MS {
+            Some(format!(
+                "Resolution has been running for {}ms with {} requirement(s) still pending \
+                 and {} conflict(s) encountered; this may indicate an overconstrained or \
+                 highly ambiguous dependency set",
+                elapsed.as_millis(),
+                state.requirement_queue.len(),
+                state.conflicts.len(),
+            ))
+        } else {
+            None
+        };
+
+        callback(ResolveProgress {
+            tick,
+            elapsed,
+            packages_considered: state.packages_considered,
+            variants_evaluated: state.variants_evaluated,
+            pending_requirements: state.requirement_queue.len(),
+            conflicts_encountered: state.conflicts.len(),
+            slow_resolve_warning,
+        });
+    }
+
     /// Find candidate packages that could satisfy a requirement
     async fn find_candidate_packages(
         &mut self,
@@ -12,11 +12,12 @@
 mod solver;
 mod graph;
 pub mod dependency_resolver;
+pub mod astar;
+pub mod sat;
 // mod resolution;
 // mod conflict;
 // mod cache;
 // mod optimized_solver;
-// mod astar;
 
 pub use solver::*;
 pub use graph::*;
@@ -25,7 +26,13 @@ pub use dependency_resolver::*;
 // pub use conflict::*;
 // pub use cache::*;
 // pub use optimized_solver::*;
-// pub use astar::*;
+// `astar`/`sat` are not glob re-exported at the crate root: both define
+// their own `SolverConfig`/`RepositoryManager`/`PackageSearchCriteria`
+// (standalone test scaffolding predating their integration with the rest
+// of this crate), which would collide with the real `SolverConfig` above
+// and `rez_core_repository::simple_repository::RepositoryManager`. Reach
+// them via `rez_core_solver::astar::...` / `rez_core_solver::sat::...`
+// until that scaffolding is merged into the real types.
 
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
@@ -15,6 +15,9 @@ pub struct ResolutionResult {
     pub resolution_time_ms: u64,
     /// Additional metadata
     pub metadata: HashMap<String, String>,
+    /// PubGrub-style explanation of the conflicts that had to be resolved
+    /// (or that made the resolution impossible), if any were encountered.
+    pub conflict_explanation: Option<ConflictExplanation>,
 }
 
 impl ResolutionResult {
@@ -25,6 +28,7 @@ impl ResolutionResult {
             conflicts_resolved: false,
             resolution_time_ms: 0,
             metadata: HashMap::new(),
+            conflict_explanation: None,
         }
     }
 
@@ -35,9 +39,16 @@ impl ResolutionResult {
             conflicts_resolved: true,
             resolution_time_ms,
             metadata: HashMap::new(),
+            conflict_explanation: None,
         }
     }
 
+    /// Attach a PubGrub-style conflict explanation to this result
+    pub fn with_conflict_explanation(mut self, explanation: ConflictExplanation) -> Self {
+        self.conflict_explanation = Some(explanation);
+        self
+    }
+
     /// Add metadata to the resolution result
     pub fn with_metadata(mut self, key: String, value: String) -> Self {
         self.metadata.insert(key, value);
@@ -139,6 +150,25 @@ impl ResolutionResult {
         Ok(())
     }
 
+    /// Build a [`LockFile`] pinning every resolved package to its exact
+    /// version, so the same resolution can be reproduced later without
+    /// re-running the solver against (possibly changed) repositories.
+    pub fn to_lock_file(&self) -> LockFile {
+        LockFile {
+            version: LOCK_FILE_FORMAT_VERSION,
+            packages: self
+                .packages
+                .iter()
+                .map(|package| LockedPackage {
+                    name: package.name.clone(),
+                    version: package.version.as_ref().map(|v| v.as_str().to_string()),
+                    requires: package.requires.clone(),
+                })
+                .collect(),
+            metadata: self.metadata.clone(),
+        }
+    }
+
     /// Convert to a format suitable for environment generation
     pub fn to_environment_spec(&self) -> EnvironmentSpec {
         let mut packages = Vec::new();
@@ -150,6 +180,7 @@ impl ResolutionResult {
                 version: package.version.as_ref().map(|v| v.as_str().to_string()),
                 requirements: package.requires.clone(),
                 tools: package.tools.clone(),
+                root: package.base.clone(),
             };
             packages.push(package_spec);
 
@@ -207,6 +238,9 @@ pub struct PackageSpec {
     pub requirements: Vec<String>,
     /// Package tools
     pub tools: Vec<String>,
+    /// Package install root, if known. Used to resolve real tool paths in
+    /// [`EnvironmentSpec::generate_shell_script`] instead of placeholders.
+    pub root: Option<String>,
 }
 
 impl EnvironmentSpec {
@@ -236,6 +270,28 @@ impl EnvironmentSpec {
         all_tools
     }
 
+    /// Resolve each tool to the directory its package was installed under,
+    /// so shell script generation can point `PATH` at real locations
+    /// instead of a placeholder. Packages with no known `root` fall back
+    /// to a `/path/to/{name}`-style stand-in so the generated script still
+    /// documents what needs to be filled in.
+    fn tool_bin_dirs(&self, path_sep: char) -> Vec<(String, String)> {
+        let mut dirs = Vec::new();
+        for package in &self.packages {
+            if package.tools.is_empty() {
+                continue;
+            }
+            let bin_dir = match &package.root {
+                Some(root) => format!("{}{}bin", root.trim_end_matches(['/', '\\']), path_sep),
+                None => format!("/path/to/{}{}bin", package.name, path_sep),
+            };
+            dirs.push((package.name.clone(), bin_dir));
+        }
+        dirs.sort();
+        dirs.dedup();
+        dirs
+    }
+
     /// Generate shell script for environment setup
     pub fn generate_shell_script(&self, shell: ShellType) -> String {
         let mut script = String::new();
@@ -249,12 +305,12 @@ impl EnvironmentSpec {
                     script.push_str(&format!("export {}=\"{}\"\n", name, value));
                 }
 
-                // Add tools to PATH
-                let tools = self.get_all_tools();
-                if !tools.is_empty() {
+                // Add tool bin directories to PATH
+                let bin_dirs = self.tool_bin_dirs('/');
+                if !bin_dirs.is_empty() {
                     script.push_str("\n# Add tools to PATH\n");
-                    for tool in tools {
-                        script.push_str(&format!("export PATH=\"$PATH:/path/to/{}\"\n", tool));
+                    for (_, bin_dir) in bin_dirs {
+                        script.push_str(&format!("export PATH=\"$PATH:{}\"\n", bin_dir));
                     }
                 }
             }
@@ -266,12 +322,12 @@ impl EnvironmentSpec {
                     script.push_str(&format!("set {}={}\n", name, value));
                 }
 
-                // Add tools to PATH
-                let tools = self.get_all_tools();
-                if !tools.is_empty() {
+                // Add tool bin directories to PATH
+                let bin_dirs = self.tool_bin_dirs('\\');
+                if !bin_dirs.is_empty() {
                     script.push_str("\nREM Add tools to PATH\n");
-                    for tool in tools {
-                        script.push_str(&format!("set PATH=%PATH%;C:\\path\\to\\{}\n", tool));
+                    for (_, bin_dir) in bin_dirs {
+                        script.push_str(&format!("set PATH=%PATH%;{}\n", bin_dir));
                     }
                 }
             }
@@ -282,12 +338,12 @@ impl EnvironmentSpec {
                     script.push_str(&format!("$env:{} = \"{}\"\n", name, value));
                 }
 
-                // Add tools to PATH
-                let tools = self.get_all_tools();
-                if !tools.is_empty() {
+                // Add tool bin directories to PATH
+                let bin_dirs = self.tool_bin_dirs('\\');
+                if !bin_dirs.is_empty() {
                     script.push_str("\n# Add tools to PATH\n");
-                    for tool in tools {
-                        script.push_str(&format!("$env:PATH += \";C:\\path\\to\\{}\"\n", tool));
+                    for (_, bin_dir) in bin_dirs {
+                        script.push_str(&format!("$env:PATH += \";{}\"\n", bin_dir));
                     }
                 }
             }
@@ -297,6 +353,198 @@ impl EnvironmentSpec {
     }
 }
 
+/// Current on-disk format version for [`LockFile`]
+pub const LOCK_FILE_FORMAT_VERSION: u32 = 1;
+
+/// A reproducible record of a resolution: every package pinned to the
+/// exact version that was selected, so re-resolving against the same
+/// lock file deterministically reproduces the same environment even if
+/// the repositories it was originally solved against have since gained
+/// newer candidate versions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockFile {
+    /// Format version, bumped on incompatible schema changes
+    pub version: u32,
+    /// Packages pinned to exact versions, in dependency order
+    pub packages: Vec<LockedPackage>,
+    /// Metadata carried over from the [`ResolutionResult`] it was built from
+    pub metadata: HashMap<String, String>,
+}
+
+/// A single package pinned in a [`LockFile`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedPackage {
+    /// Package name
+    pub name: String,
+    /// Exact version that was resolved, if the package is versioned
+    pub version: Option<String>,
+    /// Original (unpinned) requirements this package declared; kept for
+    /// diagnostics, not re-evaluated during reproduction
+    pub requires: Vec<String>,
+}
+
+impl LockFile {
+    /// Serialize this lock file as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, rez_core_common::RezCoreError> {
+        serde_json::to_string_pretty(self).map_err(rez_core_common::RezCoreError::Serde)
+    }
+
+    /// Parse a lock file from JSON
+    pub fn from_json(json: &str) -> Result<Self, rez_core_common::RezCoreError> {
+        serde_json::from_str(json).map_err(rez_core_common::RezCoreError::Serde)
+    }
+
+    /// Write the lock file to disk as JSON
+    pub fn save_to_file(&self, path: &std::path::Path) -> Result<(), rez_core_common::RezCoreError> {
+        std::fs::write(path, self.to_json()?).map_err(rez_core_common::RezCoreError::Io)
+    }
+
+    /// Load a lock file previously written by [`LockFile::save_to_file`]
+    pub fn load_from_file(path: &std::path::Path) -> Result<Self, rez_core_common::RezCoreError> {
+        let contents = std::fs::read_to_string(path).map_err(rez_core_common::RezCoreError::Io)?;
+        Self::from_json(&contents)
+    }
+
+    /// Build exact-version requirement strings (e.g. `"foo-1.2.3"`) that,
+    /// when fed back into the solver, force it to reproduce exactly this
+    /// lock file's package set rather than re-solving against whatever
+    /// candidates the repositories currently offer.
+    pub fn to_exact_requirements(&self) -> Vec<String> {
+        self.packages
+            .iter()
+            .map(|package| match &package.version {
+                Some(version) => format!("{}-{}", package.name, version),
+                None => package.name.clone(),
+            })
+            .collect()
+    }
+}
+
+/// A PubGrub-style explanation of why a set of requirements could not
+/// (or could only partially) be resolved: an ordered derivation of
+/// incompatibilities, each one either a root cause (a package's declared
+/// dependency) or derived from two earlier incompatibilities.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ConflictExplanation {
+    /// Incompatibilities in derivation order; the last entry is the
+    /// "unsatisfiable" conclusion the solver stopped at.
+    pub incompatibilities: Vec<Incompatibility>,
+}
+
+/// A single incompatibility: a set of terms (package + version range)
+/// that cannot all hold at once, together with why the solver derived it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Incompatibility {
+    /// Package names and the requirement string that couldn't coexist,
+    /// e.g. `("foo", "foo-1.2+")`
+    pub terms: Vec<(String, String)>,
+    /// Why this incompatibility holds
+    pub cause: IncompatibilityCause,
+}
+
+/// Why an [`Incompatibility`] holds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IncompatibilityCause {
+    /// Taken directly from a package's declared dependency
+    Dependency {
+        /// Package that declared the dependency
+        package: String,
+    },
+    /// Derived from two earlier incompatibilities during unit propagation
+    Derived {
+        /// Index into `ConflictExplanation::incompatibilities` of the first cause
+        left: usize,
+        /// Index into `ConflictExplanation::incompatibilities` of the second cause
+        right: usize,
+    },
+    /// No version of the package exists that satisfies the requirement
+    NoVersions {
+        /// Package with no satisfying version
+        package: String,
+    },
+}
+
+impl ConflictExplanation {
+    /// Start an empty explanation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a root-cause incompatibility taken from a package's
+    /// declared dependency, returning its index for use as a `Derived` cause.
+    pub fn add_dependency(&mut self, terms: Vec<(String, String)>, package: impl Into<String>) -> usize {
+        self.incompatibilities.push(Incompatibility {
+            terms,
+            cause: IncompatibilityCause::Dependency {
+                package: package.into(),
+            },
+        });
+        self.incompatibilities.len() - 1
+    }
+
+    /// Record that no version of `package` satisfies the given requirement
+    pub fn add_no_versions(&mut self, terms: Vec<(String, String)>, package: impl Into<String>) -> usize {
+        self.incompatibilities.push(Incompatibility {
+            terms,
+            cause: IncompatibilityCause::NoVersions {
+                package: package.into(),
+            },
+        });
+        self.incompatibilities.len() - 1
+    }
+
+    /// Record an incompatibility derived from two earlier ones
+    pub fn add_derived(&mut self, terms: Vec<(String, String)>, left: usize, right: usize) -> usize {
+        self.incompatibilities.push(Incompatibility {
+            terms,
+            cause: IncompatibilityCause::Derived { left, right },
+        });
+        self.incompatibilities.len() - 1
+    }
+
+    /// Render a human-readable, step-by-step explanation of the
+    /// derivation, in the style of PubGrub's `DefaultStringReporter`:
+    /// one numbered line per incompatibility, referencing earlier lines
+    /// by number.
+    pub fn human_readable(&self) -> String {
+        if self.incompatibilities.is_empty() {
+            return "No conflicts were recorded.".to_string();
+        }
+
+        let mut lines = Vec::with_capacity(self.incompatibilities.len());
+
+        for (i, incompat) in self.incompatibilities.iter().enumerate() {
+            let terms = incompat
+                .terms
+                .iter()
+                .map(|(name, range)| format!("{} {}", name, range))
+                .collect::<Vec<_>>()
+                .join(" and ");
+
+            let explanation = match &incompat.cause {
+                IncompatibilityCause::Dependency { package } => {
+                    format!("because {} depends on {}", package, terms)
+                }
+                IncompatibilityCause::NoVersions { package } => {
+                    format!("because no version of {} satisfies {}", package, terms)
+                }
+                IncompatibilityCause::Derived { left, right } => {
+                    format!(
+                        "because of ({}) and ({}), {} cannot hold",
+                        left + 1,
+                        right + 1,
+                        terms
+                    )
+                }
+            };
+
+            lines.push(format!("{}. {}", i + 1, explanation));
+        }
+
+        lines.join("\n")
+    }
+}
+
 /// Supported shell types
 #[derive(Debug, Clone, PartialEq)]
 pub enum ShellType {
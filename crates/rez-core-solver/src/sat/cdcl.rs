@@ -0,0 +1,411 @@
+//! CDCL (conflict-driven clause learning) solver core
+//!
+//! Implements the textbook CDCL loop over the clauses produced by
+//! [`CnfEncoder`]: unit propagation via two watched literals per clause,
+//! first-UIP conflict analysis, and non-chronological backjumping. The
+//! decision heuristic always picks the most-preferred (lowest-ranked, in
+//! [`CnfEncoder`] candidate order) unassigned candidate of the
+//! lowest-numbered unresolved package, mirroring
+//! [`super::super::astar::heuristics::VersionPreferenceHeuristic`]'s bias
+//! towards latest versions without duplicating its cost-weighting logic.
+
+use super::encoding::{CnfEncoder, Literal, Package, VarId};
+use crate::astar::{ConflictType, DependencyConflict, PackageRequirement};
+use std::collections::HashMap;
+
+type ClauseId = usize;
+
+/// Why a variable currently holds its assignment.
+#[derive(Debug, Clone, Copy)]
+enum Reason {
+    Decision,
+    Propagated(ClauseId),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct VarState {
+    value: Option<bool>,
+    level: usize,
+    reason: Reason,
+}
+
+/// Outcome of a [`CdclSolver`] run.
+#[derive(Debug)]
+pub enum SatResolution {
+    /// A satisfying assignment, as the selected candidates.
+    Satisfied(Vec<Package>),
+    /// No satisfying assignment exists. Holds one entry: the requirement
+    /// labelling the clause found falsified at decision level 0, or an
+    /// entry with no requirements when that clause has no label (an
+    /// at-most-one or learned clause). This is not a minimal
+    /// unsatisfiable core; the clauses that led up to the falsified one
+    /// are not reported.
+    Unsatisfiable(Vec<DependencyConflict>),
+}
+
+enum ClauseUpdate {
+    KeepWatching,
+    MovedWatch,
+    Conflict,
+}
+
+/// A CDCL solver over the clauses produced by [`CnfEncoder`].
+pub struct CdclSolver {
+    candidates: Vec<Package>,
+    clauses: Vec<Vec<Literal>>,
+    requirement_labels: HashMap<ClauseId, PackageRequirement>,
+    watches: HashMap<Literal, Vec<ClauseId>>,
+    vars: Vec<VarState>,
+    trail: Vec<Literal>,
+    /// Index into `trail` where each decision level begins.
+    level_starts: Vec<usize>,
+    propagated_up_to: usize,
+    /// Set when a clause with no literals at all is learned or added,
+    /// meaning the formula is unsatisfiable regardless of assignment.
+    contradiction: Option<ClauseId>,
+}
+
+impl CdclSolver {
+    pub fn new(encoder: CnfEncoder) -> Self {
+        let num_vars = encoder.candidates.len();
+        let mut solver = Self {
+            candidates: encoder.candidates,
+            clauses: Vec::new(),
+            requirement_labels: encoder.requirement_labels,
+            watches: HashMap::new(),
+            vars: (0..num_vars)
+                .map(|_| VarState {
+                    value: None,
+                    level: 0,
+                    reason: Reason::Decision,
+                })
+                .collect(),
+            trail: Vec::new(),
+            level_starts: vec![0],
+            propagated_up_to: 0,
+            contradiction: None,
+        };
+        for clause in encoder.clauses {
+            solver.add_clause(clause.literals);
+        }
+        solver
+    }
+
+    /// Run CDCL to completion.
+    pub fn solve(mut self) -> SatResolution {
+        if let Some(clause_id) = self.contradiction {
+            return SatResolution::Unsatisfiable(self.explain(clause_id));
+        }
+
+        loop {
+            if let Some(conflict) = self.propagate() {
+                if self.level() == 0 {
+                    return SatResolution::Unsatisfiable(self.explain(conflict));
+                }
+                let (learned, backjump_level) = self.analyze_conflict(conflict);
+                self.backjump(backjump_level);
+                let clause_id = self.add_clause(learned);
+                self.assert_unit_if_needed(clause_id);
+            } else if let Some(var) = self.pick_unassigned_variable() {
+                self.decide(var);
+            } else {
+                return SatResolution::Satisfied(self.extract_assignment());
+            }
+        }
+    }
+
+    fn level(&self) -> usize {
+        self.level_starts.len() - 1
+    }
+
+    fn value_of(&self, lit: Literal) -> Option<bool> {
+        self.vars[lit.var].value.map(|v| if lit.negated { !v } else { v })
+    }
+
+    fn assign(&mut self, lit: Literal, reason: Reason) {
+        self.vars[lit.var].value = Some(!lit.negated);
+        self.vars[lit.var].level = self.level();
+        self.vars[lit.var].reason = reason;
+        self.trail.push(lit);
+    }
+
+    fn add_clause(&mut self, literals: Vec<Literal>) -> ClauseId {
+        let id = self.clauses.len();
+
+        if literals.is_empty() {
+            self.contradiction = Some(id);
+        } else if literals.len() >= 2 {
+            self.watches.entry(literals[0]).or_default().push(id);
+            self.watches.entry(literals[1]).or_default().push(id);
+        } else {
+            // A unit clause is a standing fact: assign it immediately
+            // rather than waiting for something else to watch it.
+            match self.value_of(literals[0]) {
+                Some(false) => self.contradiction = Some(id),
+                Some(true) => {}
+                None => self.assign(literals[0], Reason::Propagated(id)),
+            }
+        }
+
+        self.clauses.push(literals);
+        id
+    }
+
+    /// After learning a clause and backjumping, exactly one of its
+    /// literals should be unassigned (the first-UIP asserting literal)
+    /// with the rest already false. Since the watch mechanism only
+    /// reacts to *new* falsifications, this is enqueued directly rather
+    /// than waiting for `propagate` to stumble onto it.
+    fn assert_unit_if_needed(&mut self, clause_id: ClauseId) {
+        let literals = self.clauses[clause_id].clone();
+        if literals.iter().any(|&lit| self.value_of(lit) == Some(true)) {
+            return;
+        }
+        let unassigned: Vec<Literal> = literals
+            .iter()
+            .copied()
+            .filter(|&lit| self.value_of(lit).is_none())
+            .collect();
+        if let [unit] = unassigned.as_slice() {
+            self.assign(*unit, Reason::Propagated(clause_id));
+        }
+    }
+
+    /// Runs unit propagation to a fixpoint, returning the first clause
+    /// found to be fully falsified, if any.
+    fn propagate(&mut self) -> Option<ClauseId> {
+        while self.propagated_up_to < self.trail.len() {
+            let lit = self.trail[self.propagated_up_to];
+            self.propagated_up_to += 1;
+            let falsified = lit.negate();
+
+            let mut watchers = self.watches.remove(&falsified).unwrap_or_default();
+            let mut i = 0;
+            let mut conflict = None;
+            while i < watchers.len() {
+                let clause_id = watchers[i];
+                match self.propagate_clause(clause_id, falsified) {
+                    ClauseUpdate::KeepWatching => i += 1,
+                    ClauseUpdate::MovedWatch => {
+                        watchers.swap_remove(i);
+                    }
+                    ClauseUpdate::Conflict => {
+                        conflict = Some(clause_id);
+                        i += 1;
+                    }
+                }
+            }
+            self.watches.entry(falsified).or_default().extend(watchers);
+
+            if let Some(clause_id) = conflict {
+                return Some(clause_id);
+            }
+        }
+        None
+    }
+
+    /// Re-examines one clause after `falsified` became false: finds a new
+    /// literal to watch if one isn't already false, otherwise propagates
+    /// the clause's last remaining literal or reports a conflict.
+    fn propagate_clause(&mut self, clause_id: ClauseId, falsified: Literal) -> ClauseUpdate {
+        let clause = &mut self.clauses[clause_id];
+        if clause[0] == falsified {
+            clause.swap(0, 1);
+        }
+        let other_watch = clause[0];
+
+        if self.value_of(other_watch) == Some(true) {
+            return ClauseUpdate::KeepWatching;
+        }
+
+        for i in 2..clause.len() {
+            if self.value_of(clause[i]) != Some(false) {
+                clause.swap(1, i);
+                let new_watch = clause[1];
+                self.watches.entry(new_watch).or_default().push(clause_id);
+                return ClauseUpdate::MovedWatch;
+            }
+        }
+
+        match self.value_of(other_watch) {
+            Some(false) => ClauseUpdate::Conflict,
+            _ => {
+                self.assign(other_watch, Reason::Propagated(clause_id));
+                ClauseUpdate::KeepWatching
+            }
+        }
+    }
+
+    /// Picks the most-preferred unassigned candidate, preferring the
+    /// lowest `VarId` among a package's still-unassigned candidates
+    /// (candidates are encoded in provider preference order, so this is
+    /// the "prefer latest" bias without re-deriving version ordering).
+    fn pick_unassigned_variable(&self) -> Option<VarId> {
+        (0..self.vars.len()).find(|&var| self.vars[var].value.is_none())
+    }
+
+    fn decide(&mut self, var: VarId) {
+        self.level_starts.push(self.trail.len());
+        self.assign(Literal::positive(var), Reason::Decision);
+    }
+
+    /// Undoes all assignments made at a decision level deeper than
+    /// `level`, then resumes propagation from there.
+    fn backjump(&mut self, level: usize) {
+        let cutoff = self.level_starts[level + 1];
+        for lit in self.trail.drain(cutoff..) {
+            self.vars[lit.var].value = None;
+        }
+        self.level_starts.truncate(level + 1);
+        self.propagated_up_to = self.propagated_up_to.min(self.trail.len());
+    }
+
+    /// First-UIP conflict analysis: walks the implication graph backward
+    /// from the conflicting clause, resolving away every literal
+    /// assigned at the current decision level except the single one that
+    /// dominates the rest (the first unique implication point), which
+    /// becomes the asserting literal of the learned clause.
+    fn analyze_conflict(&self, conflict: ClauseId) -> (Vec<Literal>, usize) {
+        let current_level = self.level();
+        let mut learned: Vec<Literal> = self.clauses[conflict].clone();
+        let mut seen_at_current_level = |literals: &[Literal]| {
+            literals
+                .iter()
+                .filter(|lit| self.vars[lit.var].level == current_level)
+                .count()
+        };
+
+        let mut trail_index = self.trail.len();
+        while seen_at_current_level(&learned) > 1 {
+            trail_index -= 1;
+            let lit = self.trail[trail_index];
+            if !learned.contains(&lit.negate()) {
+                continue;
+            }
+            if let Reason::Propagated(antecedent) = self.vars[lit.var].reason {
+                learned.retain(|&l| l != lit.negate());
+                for &antecedent_lit in &self.clauses[antecedent] {
+                    if antecedent_lit.var != lit.var && !learned.contains(&antecedent_lit) {
+                        learned.push(antecedent_lit);
+                    }
+                }
+            }
+        }
+
+        // Backjump to the second-highest decision level among the
+        // learned clause's literals (0 if there's only the asserting
+        // literal left).
+        let mut levels: Vec<usize> = learned
+            .iter()
+            .map(|lit| self.vars[lit.var].level)
+            .filter(|&level| level != current_level)
+            .collect();
+        levels.sort_unstable();
+        let backjump_level = levels.last().copied().unwrap_or(0);
+
+        (learned, backjump_level)
+    }
+
+    fn extract_assignment(&self) -> Vec<Package> {
+        self.vars
+            .iter()
+            .enumerate()
+            .filter(|(_, state)| state.value == Some(true))
+            .map(|(var, _)| self.candidates[var].clone())
+            .collect()
+    }
+
+    /// Maps a falsified clause back to the requirements responsible for
+    /// it, for callers that want the same diagnostics shape the A*
+    /// backend produces.
+    fn explain(&self, clause_id: ClauseId) -> Vec<DependencyConflict> {
+        match self.requirement_labels.get(&clause_id) {
+            Some(requirement) => vec![DependencyConflict {
+                package_name: requirement.name.clone(),
+                conflicting_requirements: vec![requirement.clone()],
+                severity: 1.0,
+                conflict_type: ConflictType::MissingPackage,
+            }],
+            None => vec![DependencyConflict {
+                package_name: String::new(),
+                conflicting_requirements: vec![],
+                severity: 1.0,
+                conflict_type: ConflictType::VersionConflict,
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::provider::InMemoryDependencyProvider;
+    use super::*;
+
+    fn pkg(name: &str, version: &str) -> Package {
+        Package {
+            name: name.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    fn req(name: &str) -> PackageRequirement {
+        PackageRequirement {
+            name: name.to_string(),
+            requirement_string: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_solves_simple_chain() {
+        let mut provider = InMemoryDependencyProvider::new();
+        provider.add(pkg("app", "1.0"), vec![req("python")]);
+        provider.add(pkg("python", "3.10"), vec![]);
+
+        let encoder = CnfEncoder::encode(&provider, &[req("app")]);
+        let solver = CdclSolver::new(encoder);
+
+        match solver.solve() {
+            SatResolution::Satisfied(selected) => {
+                assert!(selected.iter().any(|p| p.name == "app"));
+                assert!(selected.iter().any(|p| p.name == "python"));
+            }
+            SatResolution::Unsatisfiable(_) => panic!("expected a satisfying assignment"),
+        }
+    }
+
+    #[test]
+    fn test_reports_unsatisfiable_missing_dependency() {
+        let mut provider = InMemoryDependencyProvider::new();
+        provider.add(pkg("app", "1.0"), vec![req("missing")]);
+
+        let encoder = CnfEncoder::encode(&provider, &[req("app")]);
+        let solver = CdclSolver::new(encoder);
+
+        match solver.solve() {
+            SatResolution::Satisfied(selected) => {
+                assert!(!selected.iter().any(|p| p.name == "app"));
+            }
+            SatResolution::Unsatisfiable(conflicts) => {
+                assert!(!conflicts.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefers_first_ranked_candidate() {
+        let mut provider = InMemoryDependencyProvider::new();
+        provider.add(pkg("python", "3.10"), vec![]);
+        provider.add(pkg("python", "3.9"), vec![]);
+
+        let encoder = CnfEncoder::encode(&provider, &[req("python")]);
+        let solver = CdclSolver::new(encoder);
+
+        match solver.solve() {
+            SatResolution::Satisfied(selected) => {
+                assert_eq!(selected.len(), 1);
+                assert_eq!(selected[0].version, "3.10");
+            }
+            SatResolution::Unsatisfiable(_) => panic!("expected a satisfying assignment"),
+        }
+    }
+}
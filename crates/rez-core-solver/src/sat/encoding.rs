@@ -0,0 +1,245 @@
+//! CNF encoding of a dependency resolution problem
+//!
+//! Each `(name, version)` candidate becomes a boolean variable. The
+//! encoder then adds:
+//!
+//! - one "at most one" set of clauses per package name, so a solution
+//!   can't select two versions of the same package;
+//! - one "selecting X implies one of its dependency candidates" clause
+//!   per requirement X has, built by asking the [`DependencyProvider`]
+//!   for dependencies as new candidates are discovered;
+//! - a unit clause forcing a candidate false whenever one of its
+//!   requirements has no compatible candidate at all, which is how an
+//!   unsatisfiable requirement turns into an unsatisfiable formula;
+//! - one clause per root requirement, satisfied only by selecting one of
+//!   its matching candidates (the empty clause when none matches).
+//!
+//! Requirement compatibility is intentionally simple here (an empty
+//! `requirement_string` matches any candidate for the name, otherwise it
+//! must equal the candidate's version exactly) — the same "TODO: wire up
+//! the real version system later" scope as the rest of this crate's
+//! stub [`Package`](super::super::astar::Package) type.
+
+use super::provider::DependencyProvider;
+use crate::astar::PackageRequirement;
+use std::collections::{HashMap, VecDeque};
+
+pub type VarId = usize;
+
+/// A `(name, version)` candidate the SAT encoder can assign a variable
+/// to. Distinct from [`super::super::astar::Package`] because this
+/// backend needs an explicit version to tell candidates of the same
+/// name apart.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Package {
+    pub name: String,
+    pub version: String,
+}
+
+/// A boolean variable, optionally negated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Literal {
+    pub var: VarId,
+    pub negated: bool,
+}
+
+impl Literal {
+    pub fn positive(var: VarId) -> Self {
+        Literal {
+            var,
+            negated: false,
+        }
+    }
+
+    pub fn negative(var: VarId) -> Self {
+        Literal { var, negated: true }
+    }
+
+    pub fn negate(self) -> Self {
+        Literal {
+            var: self.var,
+            negated: !self.negated,
+        }
+    }
+}
+
+/// A clause: the disjunction of its literals.
+#[derive(Debug, Clone)]
+pub struct Clause {
+    pub literals: Vec<Literal>,
+}
+
+fn requirement_matches(requirement: &PackageRequirement, candidate: &Package) -> bool {
+    requirement.name == candidate.name
+        && (requirement.requirement_string.is_empty()
+            || requirement.requirement_string == candidate.version)
+}
+
+/// The CNF form of a dependency resolution problem, ready to hand to a
+/// [`super::cdcl::CdclSolver`].
+#[derive(Debug, Default)]
+pub struct CnfEncoder {
+    /// Every candidate that was assigned a variable, index-aligned with
+    /// its `VarId`.
+    pub candidates: Vec<Package>,
+    pub clauses: Vec<Clause>,
+    /// The requirement a given clause was generated from, for
+    /// unsatisfiable-core reporting. Clauses without an entry here (the
+    /// at-most-one clauses) aren't attributable to a single requirement.
+    pub requirement_labels: HashMap<usize, PackageRequirement>,
+    var_by_candidate: HashMap<Package, VarId>,
+}
+
+impl CnfEncoder {
+    /// Encode the transitive closure of `root_requirements` using
+    /// `provider` to discover candidates and their dependencies.
+    pub fn encode(
+        provider: &dyn DependencyProvider,
+        root_requirements: &[PackageRequirement],
+    ) -> Self {
+        let mut encoder = Self::default();
+        let mut queue: VecDeque<PackageRequirement> =
+            root_requirements.iter().cloned().collect();
+        let mut encoded_names = std::collections::HashSet::new();
+
+        while let Some(requirement) = queue.pop_front() {
+            if !encoded_names.insert(requirement.name.clone()) {
+                continue;
+            }
+
+            let candidates = provider.candidates(&requirement.name);
+            let vars: Vec<VarId> = candidates
+                .iter()
+                .map(|candidate| encoder.var_for(candidate.clone()))
+                .collect();
+
+            encoder.add_at_most_one(&vars);
+
+            for (candidate, &var) in candidates.iter().zip(&vars) {
+                for dependency in provider.dependencies(candidate) {
+                    let dependency_candidates = provider.candidates(&dependency.name);
+                    let dependency_vars: Vec<VarId> = dependency_candidates
+                        .iter()
+                        .filter(|dep_candidate| requirement_matches(&dependency, dep_candidate))
+                        .map(|dep_candidate| encoder.var_for(dep_candidate.clone()))
+                        .collect();
+
+                    let mut literals = vec![Literal::negative(var)];
+                    literals.extend(dependency_vars.iter().map(|&v| Literal::positive(v)));
+                    encoder.add_labeled_clause(literals, dependency.clone());
+
+                    queue.push_back(dependency);
+                }
+            }
+        }
+
+        for requirement in root_requirements {
+            let literals = provider
+                .candidates(&requirement.name)
+                .into_iter()
+                .filter(|candidate| requirement_matches(requirement, candidate))
+                .map(|candidate| Literal::positive(encoder.var_for(candidate)))
+                .collect();
+            encoder.add_labeled_clause(literals, requirement.clone());
+        }
+
+        encoder
+    }
+
+    fn var_for(&mut self, candidate: Package) -> VarId {
+        if let Some(&var) = self.var_by_candidate.get(&candidate) {
+            return var;
+        }
+        let var = self.candidates.len();
+        self.candidates.push(candidate.clone());
+        self.var_by_candidate.insert(candidate, var);
+        var
+    }
+
+    fn add_at_most_one(&mut self, vars: &[VarId]) {
+        for i in 0..vars.len() {
+            for j in (i + 1)..vars.len() {
+                self.clauses.push(Clause {
+                    literals: vec![Literal::negative(vars[i]), Literal::negative(vars[j])],
+                });
+            }
+        }
+    }
+
+    fn add_labeled_clause(&mut self, literals: Vec<Literal>, requirement: PackageRequirement) {
+        let id = self.clauses.len();
+        self.requirement_labels.insert(id, requirement);
+        self.clauses.push(Clause { literals });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::provider::InMemoryDependencyProvider;
+    use super::*;
+
+    fn pkg(name: &str, version: &str) -> Package {
+        Package {
+            name: name.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    fn req(name: &str) -> PackageRequirement {
+        PackageRequirement {
+            name: name.to_string(),
+            requirement_string: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_encode_adds_at_most_one_clause_per_package_name() {
+        let mut provider = InMemoryDependencyProvider::new();
+        provider.add(pkg("python", "3.10"), vec![]);
+        provider.add(pkg("python", "3.9"), vec![]);
+
+        let encoder = CnfEncoder::encode(&provider, &[req("python")]);
+
+        assert_eq!(encoder.candidates.len(), 2);
+        assert!(encoder
+            .clauses
+            .iter()
+            .any(|clause| clause.literals.len() == 2
+                && clause.literals.iter().all(|lit| lit.negated)));
+    }
+
+    #[test]
+    fn test_encode_adds_implication_clause_for_dependency() {
+        let mut provider = InMemoryDependencyProvider::new();
+        provider.add(pkg("app", "1.0"), vec![req("python")]);
+        provider.add(pkg("python", "3.10"), vec![]);
+
+        let encoder = CnfEncoder::encode(&provider, &[req("app")]);
+
+        let app_var = encoder.candidates.iter().position(|c| c.name == "app").unwrap();
+        let python_var = encoder
+            .candidates
+            .iter()
+            .position(|c| c.name == "python")
+            .unwrap();
+
+        assert!(encoder.clauses.iter().any(|clause| {
+            clause.literals.contains(&Literal::negative(app_var))
+                && clause.literals.contains(&Literal::positive(python_var))
+        }));
+    }
+
+    #[test]
+    fn test_encode_forces_false_when_dependency_has_no_candidates() {
+        let mut provider = InMemoryDependencyProvider::new();
+        provider.add(pkg("app", "1.0"), vec![req("missing")]);
+
+        let encoder = CnfEncoder::encode(&provider, &[req("app")]);
+        let app_var = encoder.candidates.iter().position(|c| c.name == "app").unwrap();
+
+        assert!(encoder
+            .clauses
+            .iter()
+            .any(|clause| clause.literals == vec![Literal::negative(app_var)]));
+    }
+}
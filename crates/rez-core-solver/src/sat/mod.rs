@@ -0,0 +1,31 @@
+//! # SAT/CDCL Backend for Dependency Resolution
+//!
+//! An alternative to [`super::astar`]'s heuristic-guided search. Heuristic
+//! A* can thrash on deeply conflicting graphs, re-discovering the same
+//! dead end along many branches before giving up; a conflict-driven
+//! clause-learning (CDCL) SAT encoding instead learns a clause from every
+//! conflict it finds, so the same root cause is never re-explored twice.
+//! For graphs that are unsatisfiable the result names the requirement
+//! behind the clause found falsified at decision level 0; no minimal
+//! unsatisfiable core is computed.
+//!
+//! ## Key Components
+//!
+//! - [`DependencyProvider`]: lazily supplies candidates and their
+//!   dependencies, so the encoder only pulls in the part of the package
+//!   graph it actually needs to reason about.
+//! - [`CnfEncoder`]: turns candidates and requirements into boolean
+//!   variables and clauses.
+//! - [`CdclSolver`]: unit propagation over watched literals, first-UIP
+//!   conflict analysis, and non-chronological backjumping.
+//!
+//! Both backends report failures as [`super::astar::DependencyConflict`]
+//! so callers can handle either one's diagnostics identically.
+
+pub mod provider;
+pub mod encoding;
+pub mod cdcl;
+
+pub use provider::*;
+pub use encoding::*;
+pub use cdcl::*;
@@ -0,0 +1,114 @@
+//! Candidate supply for the SAT encoder
+//!
+//! [`CnfEncoder`](super::encoding::CnfEncoder) never loads the whole
+//! package universe up front — it only asks a [`DependencyProvider`] for
+//! candidates and dependencies as it discovers it needs them, so a
+//! provider backed by a repository index can fetch lazily instead of
+//! materializing every version of every package before resolution even
+//! starts.
+
+use super::encoding::Package;
+use crate::astar::PackageRequirement;
+
+/// Supplies candidate packages and their dependencies to the encoder.
+pub trait DependencyProvider {
+    /// All known candidates for a package name, ordered from most to
+    /// least preferred. Mirrors `VersionPreferenceHeuristic`'s bias
+    /// towards latest versions: the encoder and solver both treat index
+    /// 0 as the most preferred candidate for its name.
+    fn candidates(&self, name: &str) -> Vec<Package>;
+
+    /// The requirements `package` would bring in if selected.
+    fn dependencies(&self, package: &Package) -> Vec<PackageRequirement>;
+}
+
+/// An in-memory [`DependencyProvider`] backed by a fixed package index,
+/// useful for tests and for small, already-resolved repositories.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDependencyProvider {
+    packages: Vec<Package>,
+    requirements: Vec<(Package, Vec<PackageRequirement>)>,
+}
+
+impl InMemoryDependencyProvider {
+    /// Create an empty provider.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a candidate and the requirements it brings in if
+    /// selected. Candidates for the same name should be added in
+    /// preference order (most preferred first).
+    pub fn add(&mut self, package: Package, requires: Vec<PackageRequirement>) {
+        self.packages.push(package.clone());
+        self.requirements.push((package, requires));
+    }
+}
+
+impl DependencyProvider for InMemoryDependencyProvider {
+    fn candidates(&self, name: &str) -> Vec<Package> {
+        self.packages
+            .iter()
+            .filter(|package| package.name == name)
+            .cloned()
+            .collect()
+    }
+
+    fn dependencies(&self, package: &Package) -> Vec<PackageRequirement> {
+        self.requirements
+            .iter()
+            .find(|(candidate, _)| candidate == package)
+            .map(|(_, requires)| requires.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_provider_returns_candidates_by_name() {
+        let mut provider = InMemoryDependencyProvider::new();
+        provider.add(
+            Package {
+                name: "python".to_string(),
+                version: "3.10".to_string(),
+            },
+            vec![],
+        );
+        provider.add(
+            Package {
+                name: "python".to_string(),
+                version: "3.9".to_string(),
+            },
+            vec![],
+        );
+
+        let candidates = provider.candidates("python");
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].version, "3.10");
+    }
+
+    #[test]
+    fn test_in_memory_provider_returns_dependencies_for_exact_candidate() {
+        let mut provider = InMemoryDependencyProvider::new();
+        let python = Package {
+            name: "python".to_string(),
+            version: "3.10".to_string(),
+        };
+        let requirement = PackageRequirement {
+            name: "openssl".to_string(),
+            requirement_string: "openssl".to_string(),
+        };
+        provider.add(python.clone(), vec![requirement.clone()]);
+
+        assert_eq!(provider.dependencies(&python), vec![requirement]);
+        assert!(provider
+            .dependencies(&Package {
+                name: "python".to_string(),
+                version: "2.7".to_string(),
+            })
+            .is_empty());
+    }
+}
@@ -2,7 +2,7 @@
 
 use super::Version;
 #[cfg(feature = "python-bindings")]
-use super::VersionToken;
+use super::{AlphanumericVersionToken, NumericToken, VersionToken};
 use ahash::AHashMap;
 use once_cell::sync::Lazy;
 use rez_core_common::RezCoreError;
@@ -30,6 +30,81 @@ enum ParseState {
     End,
 }
 
+/// Character class used to detect mixed alphanumeric runs (`1a2`) so they
+/// split into separate tokens instead of one opaque alphanumeric blob.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CharClass {
+    Digit,
+    Alpha,
+    /// `_` doesn't start a new run on its own; it's only valid glued onto
+    /// whichever run it appears inside. A class change right after `_`
+    /// does not split the run, so `release_1` stays one token.
+    Underscore,
+}
+
+/// Pushed into the separator vec at a digit/letter boundary within a single
+/// run (e.g. between `1` and `a` in `1a2`) where the input had no explicit
+/// separator character. Kept distinct from real separators so callers can
+/// tell the two apart if they need to.
+const IMPLICIT_SEPARATOR: char = '\0';
+
+/// A version parse failure with enough position info to point a user at
+/// the offending character: the byte offset and char index into the
+/// original input, plus the index (0-based) of the token being built when
+/// parsing failed. [`StateMachineParser::parse_tokens`] collapses this down
+/// to a plain [`RezCoreError::VersionParse`] message for callers that don't
+/// care about position; [`StateMachineParser::parse_tokens_detailed`]
+/// returns it directly for callers (CLI/API error surfaces) that do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub byte_offset: usize,
+    pub char_index: usize,
+    pub token_index: usize,
+}
+
+impl ParseError {
+    /// Render a two-line caret diagnostic: `input` on the first line, a
+    /// `^` under the offending byte column on the second, e.g.:
+    /// ```text
+    /// 1.2.3@
+    ///      ^
+    /// ```
+    pub fn render_caret_diagnostic(&self, input: &str) -> String {
+        format!("{}\n{}^", input, " ".repeat(self.byte_offset))
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (at byte {}, token {})",
+            self.message, self.byte_offset, self.token_index
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for RezCoreError {
+    fn from(err: ParseError) -> Self {
+        RezCoreError::VersionParse(err.to_string())
+    }
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_ascii_digit() {
+            CharClass::Digit
+        } else if c == '_' {
+            CharClass::Underscore
+        } else {
+            CharClass::Alpha
+        }
+    }
+}
+
 /// High-performance version parser with state machine and zero-copy optimization
 pub struct StateMachineParser {
     /// Enable string interning for memory optimization
@@ -109,6 +184,18 @@ impl StateMachineParser {
         &self,
         input: &str,
     ) -> Result<(SmallVec<[TokenType; 8]>, SmallVec<[char; 7]>), RezCoreError> {
+        self.parse_tokens_detailed(input).map_err(Into::into)
+    }
+
+    /// Parse version string using zero-copy state machine, returning the
+    /// structured, span-carrying [`ParseError`] on failure instead of
+    /// the flattened [`RezCoreError::VersionParse`] message. Prefer this
+    /// over [`Self::parse_tokens`] when the caller wants to render a caret
+    /// diagnostic or otherwise point a user at the offending character.
+    pub fn parse_tokens_detailed(
+        &self,
+        input: &str,
+    ) -> Result<(SmallVec<[TokenType; 8]>, SmallVec<[char; 7]>), ParseError> {
         if input.is_empty() {
             return Ok((SmallVec::new(), SmallVec::new()));
         }
@@ -117,10 +204,12 @@ impl StateMachineParser {
         let mut separators = SmallVec::new();
         let mut state = ParseState::Start;
         let mut current_token = String::new();
+        let mut current_class: Option<CharClass> = None;
         let mut numeric_count = 0;
 
         let chars: SmallVec<[char; 64]> = input.chars().collect();
         let mut i = 0;
+        let mut byte_offset = 0;
 
         while i < chars.len() {
             let c = chars[i];
@@ -129,115 +218,190 @@ impl StateMachineParser {
                 ParseState::Start => {
                     if Self::is_token_char(c) {
                         current_token.push(c);
+                        current_class = Some(CharClass::of(c));
                         state = ParseState::InToken;
                     } else if Self::is_valid_separator(c) {
-                        return Err(RezCoreError::VersionParse(format!(
-                            "Version cannot start with separator '{}'",
-                            c
-                        )));
+                        return Err(ParseError {
+                            message: format!("Version cannot start with separator '{}'", c),
+                            byte_offset,
+                            char_index: i,
+                            token_index: tokens.len(),
+                        });
                     } else {
-                        return Err(RezCoreError::VersionParse(format!(
-                            "Invalid character '{}' at start of version",
-                            c
-                        )));
+                        return Err(ParseError {
+                            message: format!("Invalid character '{}' at start of version", c),
+                            byte_offset,
+                            char_index: i,
+                            token_index: tokens.len(),
+                        });
                     }
                 }
 
                 ParseState::InToken => {
                     if Self::is_token_char(c) {
+                        let class = CharClass::of(c);
+                        let is_boundary = matches!(
+                            (current_class, class),
+                            (Some(CharClass::Digit), CharClass::Alpha)
+                                | (Some(CharClass::Alpha), CharClass::Digit)
+                        ) && !current_token.ends_with('_');
+                        if is_boundary {
+                            self.finalize_token(
+                                &mut current_token,
+                                &mut tokens,
+                                &mut numeric_count,
+                                byte_offset,
+                                i,
+                            )?;
+                            separators.push(IMPLICIT_SEPARATOR);
+                        }
                         current_token.push(c);
+                        if class != CharClass::Underscore {
+                            current_class = Some(class);
+                        }
                     } else if Self::is_valid_separator(c) {
                         // Finalize current token
-                        self.finalize_token(&mut current_token, &mut tokens, &mut numeric_count)?;
+                        self.finalize_token(
+                            &mut current_token,
+                            &mut tokens,
+                            &mut numeric_count,
+                            byte_offset,
+                            i,
+                        )?;
                         separators.push(c);
+                        current_class = None;
                         state = ParseState::InSeparator;
                     } else {
-                        return Err(RezCoreError::VersionParse(format!(
-                            "Invalid character '{}' in token",
-                            c
-                        )));
+                        return Err(ParseError {
+                            message: format!("Invalid character '{}' in token", c),
+                            byte_offset,
+                            char_index: i,
+                            token_index: tokens.len(),
+                        });
                     }
                 }
 
                 ParseState::InSeparator => {
                     if Self::is_token_char(c) {
                         current_token.push(c);
+                        current_class = Some(CharClass::of(c));
                         state = ParseState::InToken;
                     } else {
-                        return Err(RezCoreError::VersionParse(format!(
-                            "Expected token character after separator, found '{}'",
-                            c
-                        )));
+                        return Err(ParseError {
+                            message: format!(
+                                "Expected token character after separator, found '{}'",
+                                c
+                            ),
+                            byte_offset,
+                            char_index: i,
+                            token_index: tokens.len(),
+                        });
                     }
                 }
 
                 ParseState::End => break,
             }
 
+            byte_offset += c.len_utf8();
             i += 1;
         }
 
         // Finalize last token if we're in a token state
         if state == ParseState::InToken && !current_token.is_empty() {
-            self.finalize_token(&mut current_token, &mut tokens, &mut numeric_count)?;
+            self.finalize_token(
+                &mut current_token,
+                &mut tokens,
+                &mut numeric_count,
+                byte_offset,
+                i,
+            )?;
         } else if state == ParseState::InSeparator {
-            return Err(RezCoreError::VersionParse(
-                "Version cannot end with separator".to_string(),
-            ));
+            return Err(ParseError {
+                message: "Version cannot end with separator".to_string(),
+                byte_offset,
+                char_index: i,
+                token_index: tokens.len(),
+            });
         }
 
         // Validate token counts
         if tokens.len() > self.max_tokens {
-            return Err(RezCoreError::VersionParse(format!(
-                "Too many tokens: {} (max: {})",
-                tokens.len(),
-                self.max_tokens
-            )));
+            return Err(ParseError {
+                message: format!(
+                    "Too many tokens: {} (max: {})",
+                    tokens.len(),
+                    self.max_tokens
+                ),
+                byte_offset,
+                char_index: i,
+                token_index: tokens.len(),
+            });
         }
 
         if numeric_count > self.max_numeric_tokens {
-            return Err(RezCoreError::VersionParse(format!(
-                "Too many numeric tokens: {} (max: {})",
-                numeric_count, self.max_numeric_tokens
-            )));
+            return Err(ParseError {
+                message: format!(
+                    "Too many numeric tokens: {} (max: {})",
+                    numeric_count, self.max_numeric_tokens
+                ),
+                byte_offset,
+                char_index: i,
+                token_index: tokens.len(),
+            });
         }
 
         Ok((tokens, separators))
     }
 
-    /// Finalize a token and add it to the tokens list
+    /// Finalize a token and add it to the tokens list. `byte_offset` and
+    /// `char_index` are the position of the character that triggered
+    /// finalization (a separator, a class boundary, or end-of-input),
+    /// recorded on any error this produces.
     fn finalize_token(
         &self,
         current_token: &mut String,
         tokens: &mut SmallVec<[TokenType; 8]>,
         numeric_count: &mut usize,
-    ) -> Result<(), RezCoreError> {
+        byte_offset: usize,
+        char_index: usize,
+    ) -> Result<(), ParseError> {
         if current_token.is_empty() {
-            return Err(RezCoreError::VersionParse("Empty token found".to_string()));
+            return Err(ParseError {
+                message: "Empty token found".to_string(),
+                byte_offset,
+                char_index,
+                token_index: tokens.len(),
+            });
         }
 
         // Validate token format
         if current_token.starts_with('_') || current_token.ends_with('_') {
-            return Err(RezCoreError::VersionParse(format!(
-                "Invalid token format: '{}'",
-                current_token
-            )));
+            return Err(ParseError {
+                message: format!("Invalid token format: '{}'", current_token),
+                byte_offset,
+                char_index,
+                token_index: tokens.len(),
+            });
         }
 
         // Check for invalid patterns
         if current_token == "not" || current_token == "version" {
-            return Err(RezCoreError::VersionParse(format!(
-                "Invalid version token: '{}'",
-                current_token
-            )));
+            return Err(ParseError {
+                message: format!("Invalid version token: '{}'", current_token),
+                byte_offset,
+                char_index,
+                token_index: tokens.len(),
+            });
         }
 
         // Reject overly long alphabetic tokens
         if current_token.chars().all(|c| c.is_alphabetic()) && current_token.len() > 10 {
-            return Err(RezCoreError::VersionParse(format!(
-                "Invalid version token: '{}'",
-                current_token
-            )));
+            return Err(ParseError {
+                message: format!("Invalid version token: '{}'", current_token),
+                byte_offset,
+                char_index,
+                token_index: tokens.len(),
+            });
         }
 
         // Try to parse as numeric first (fast path)
@@ -274,27 +438,58 @@ impl VersionParser {
         }
     }
 
-    /// Parse a version string into tokens (legacy interface)
-    #[cfg(feature = "python-bindings")]
+    /// Parse a version string into its raw tokens and separators, as
+    /// produced by the underlying [`StateMachineParser`]. `tokens.len() ==
+    /// separators.len() + 1` holds for any successfully parsed, non-empty
+    /// input.
     pub fn parse_tokens(
         &self,
         input: &str,
-    ) -> Result<(Vec<VersionToken>, Vec<char>), RezCoreError> {
-        let (_tokens, separators) = self.inner.parse_tokens(input)?;
+    ) -> Result<(SmallVec<[TokenType; 8]>, SmallVec<[char; 7]>), RezCoreError> {
+        self.inner.parse_tokens(input)
+    }
 
-        // Convert to legacy format
-        let legacy_tokens = Vec::new();
+    /// Parse a version string, returning the structured, span-carrying
+    /// [`ParseError`] on failure. See
+    /// [`StateMachineParser::parse_tokens_detailed`].
+    pub fn parse_tokens_detailed(
+        &self,
+        input: &str,
+    ) -> Result<(SmallVec<[TokenType; 8]>, SmallVec<[char; 7]>), ParseError> {
+        self.inner.parse_tokens_detailed(input)
+    }
+
+    /// Parse a version string into tokens (legacy Python-binding interface).
+    #[cfg(feature = "python-bindings")]
+    pub fn parse_tokens_py(
+        &self,
+        input: &str,
+    ) -> Result<(Vec<VersionToken>, Vec<char>), RezCoreError> {
+        let (tokens, separators) = self.inner.parse_tokens(input)?;
+
+        let legacy_tokens = tokens
+            .into_iter()
+            .map(|token| match token {
+                TokenType::Numeric(n) => VersionToken::Numeric(NumericToken::new(n)),
+                TokenType::Alphanumeric(s) => {
+                    VersionToken::Alphanumeric(AlphanumericVersionToken::new(s))
+                }
+                TokenType::Separator(c) => {
+                    VersionToken::Alphanumeric(AlphanumericVersionToken::new(c.to_string()))
+                }
+            })
+            .collect();
         let legacy_separators: Vec<char> = separators.into_iter().collect();
 
-        // For now, return empty vectors to maintain compatibility
-        // TODO: Implement proper conversion from TokenType to VersionToken
         Ok((legacy_tokens, legacy_separators))
     }
 
-    /// Parse a complete version string
+    /// Parse a complete version string.
+    ///
+    /// Delegates to `Version::parse`, which tokenizes with its own
+    /// regex-based parser; the [`StateMachineParser`] this type wraps is
+    /// not used here.
     pub fn parse_version(&self, input: &str) -> Result<Version, RezCoreError> {
-        // Use the new state machine parser for better performance
-        // but fall back to the original implementation for now
         Version::parse(input)
     }
 }
@@ -415,6 +610,97 @@ mod tests {
         assert!(parser.parse_tokens(&too_many_numeric).is_err());
     }
 
+    #[test]
+    fn test_mixed_alphanumeric_run_splits_on_class_boundary() {
+        let parser = StateMachineParser::new();
+
+        let (tokens, separators) = parser.parse_tokens("1a2").unwrap();
+        assert_eq!(tokens.len(), 3);
+        // tokens.len() == separators.len() + 1 must hold even with implicit splits.
+        assert_eq!(separators.len(), 2);
+        assert_eq!(separators[0], IMPLICIT_SEPARATOR);
+        assert_eq!(separators[1], IMPLICIT_SEPARATOR);
+
+        match (&tokens[0], &tokens[1], &tokens[2]) {
+            (TokenType::Numeric(a), TokenType::Alphanumeric(b), TokenType::Numeric(c)) => {
+                assert_eq!(*a, 1);
+                assert_eq!(b, "a");
+                assert_eq!(*c, 2);
+            }
+            other => panic!("unexpected token split: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_tokens_round_trip_token_separator_invariant() {
+        let parser = StateMachineParser::new();
+
+        for input in ["1.2.3", "1.2.3-alpha1", "1a2.3b", "release_1.0"] {
+            let (tokens, separators) = parser.parse_tokens(input).unwrap();
+            assert_eq!(
+                tokens.len(),
+                separators.len() + 1,
+                "tokens/separators invariant broken for '{}'",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_version_parser_parse_tokens_matches_state_machine() {
+        let legacy = VersionParser::new();
+        let direct = StateMachineParser::new();
+
+        let (legacy_tokens, legacy_separators) = legacy.parse_tokens("1.2.3-alpha1").unwrap();
+        let (direct_tokens, direct_separators) = direct.parse_tokens("1.2.3-alpha1").unwrap();
+
+        assert_eq!(legacy_tokens.len(), direct_tokens.len());
+        assert_eq!(legacy_separators, direct_separators);
+    }
+
+    #[test]
+    fn test_parse_tokens_detailed_reports_byte_and_char_offset() {
+        let parser = StateMachineParser::new();
+
+        let err = parser.parse_tokens_detailed("1.2.3@").unwrap_err();
+        assert_eq!(err.byte_offset, 5);
+        assert_eq!(err.char_index, 5);
+        // "3" hasn't been finalized into a token yet when '@' is hit.
+        assert_eq!(err.token_index, 2);
+    }
+
+    #[test]
+    fn test_parse_tokens_detailed_reports_token_index_on_trailing_separator() {
+        let parser = StateMachineParser::new();
+
+        let err = parser.parse_tokens_detailed("1.2.3.").unwrap_err();
+        // Three tokens (1, 2, 3) were already finalized before the
+        // dangling trailing separator was found.
+        assert_eq!(err.token_index, 3);
+    }
+
+    #[test]
+    fn test_version_parse_error_renders_caret_diagnostic() {
+        let parser = StateMachineParser::new();
+
+        let err = parser.parse_tokens_detailed("1.2.3@").unwrap_err();
+        let diagnostic = err.render_caret_diagnostic("1.2.3@");
+        assert_eq!(diagnostic, "1.2.3@\n     ^");
+    }
+
+    #[test]
+    fn test_parse_tokens_collapses_to_rez_core_error() {
+        let parser = StateMachineParser::new();
+
+        match parser.parse_tokens("1.2.3@") {
+            Err(RezCoreError::VersionParse(message)) => {
+                assert!(message.contains("Invalid character"));
+                assert!(message.contains("byte 5"));
+            }
+            other => panic!("expected VersionParse error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_character_classification() {
         assert!(StateMachineParser::is_valid_separator('.'));
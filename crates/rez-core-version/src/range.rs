@@ -19,6 +19,9 @@ pub struct VersionRange {
     lower_inclusive: bool,
     upper_version: Option<Version>,
     upper_inclusive: bool,
+    /// Versions excluded from the range by a `!=` clause, on top of the
+    /// lower/upper bounds above.
+    excluded_versions: Vec<Version>,
 }
 
 impl Serialize for VersionRange {
@@ -64,9 +67,11 @@ impl VersionRange {
 
     /// Check if a version is contained in this range
     pub fn contains_version(&self, version: &Version) -> bool {
-        // Check lower bound
+        // Check lower bound. `cmp_for_range` ignores `version`'s local
+        // segment unless the bound itself specifies one, so e.g.
+        // `1.2.3+cu118` still satisfies `>=1.2.3`.
         if let Some(ref lower) = self.lower_version {
-            let cmp = version.cmp(lower);
+            let cmp = version.cmp_for_range(lower);
             if self.lower_inclusive {
                 if cmp == Ordering::Less {
                     return false;
@@ -80,7 +85,7 @@ impl VersionRange {
 
         // Check upper bound
         if let Some(ref upper) = self.upper_version {
-            let cmp = version.cmp(upper);
+            let cmp = version.cmp_for_range(upper);
             if self.upper_inclusive {
                 if cmp == Ordering::Greater {
                     return false;
@@ -92,6 +97,10 @@ impl VersionRange {
             }
         }
 
+        if self.excluded_versions.iter().any(|excluded| excluded == version) {
+            return false;
+        }
+
         true
     }
 
@@ -100,6 +109,11 @@ impl VersionRange {
         self.contains_version(version)
     }
 
+    /// Support Python's `version in range` syntax.
+    fn __contains__(&self, version: &Version) -> bool {
+        self.contains_version(version)
+    }
+
     /// Check if this range intersects with another range
     pub fn intersects(&self, other: &VersionRange) -> bool {
         // Simple intersection check
@@ -178,12 +192,16 @@ impl VersionRange {
             upper_inclusive,
         );
 
+        let mut excluded_versions = self.excluded_versions.clone();
+        excluded_versions.extend(other.excluded_versions.iter().cloned());
+
         Some(VersionRange {
             range_str,
             lower_version,
             lower_inclusive,
             upper_version,
             upper_inclusive,
+            excluded_versions,
         })
     }
 
@@ -226,12 +244,17 @@ impl VersionRange {
             upper_inclusive,
         );
 
+        // A union can re-admit a version either side had excluded on its
+        // own, so (unlike `intersect`) exclusions aren't simply merged;
+        // conservatively drop them rather than risk excluding a version
+        // the union should actually contain.
         Some(VersionRange {
             range_str,
             lower_version,
             lower_inclusive,
             upper_version,
             upper_inclusive,
+            excluded_versions: Vec::new(),
         })
     }
 
@@ -261,7 +284,28 @@ impl VersionRange {
 
     /// Check if this range is the "any" range (matches all versions)
     pub fn is_any(&self) -> bool {
-        self.lower_version.is_none() && self.upper_version.is_none()
+        self.lower_version.is_none() && self.upper_version.is_none() && self.excluded_versions.is_empty()
+    }
+
+    /// Check if this range excludes every version (e.g. `>=2,<1`, or an
+    /// exact version that's also excluded, e.g. `==1.0.0,!=1.0.0`)
+    pub fn is_empty(&self) -> bool {
+        if let (Some(ref lower), Some(ref upper)) = (&self.lower_version, &self.upper_version) {
+            if lower == upper
+                && self.lower_inclusive
+                && self.upper_inclusive
+                && self.excluded_versions.iter().any(|excluded| excluded == lower)
+            {
+                return true;
+            }
+            match lower.cmp(upper) {
+                Ordering::Greater => true,
+                Ordering::Equal => !(self.lower_inclusive && self.upper_inclusive),
+                Ordering::Less => false,
+            }
+        } else {
+            false
+        }
     }
 
     /// Create a range from a single version with an operator
@@ -271,6 +315,17 @@ impl VersionRange {
         version: &Version,
         op: Option<&str>,
     ) -> PyResult<Self> {
+        if matches!(op, Some("!=") | Some("ne")) {
+            return Ok(VersionRange {
+                range_str: format!("!={}", version.as_str()),
+                lower_version: None,
+                lower_inclusive: true,
+                upper_version: None,
+                upper_inclusive: true,
+                excluded_versions: vec![version.clone()],
+            });
+        }
+
         let (lower_version, lower_inclusive, upper_version, upper_inclusive) = match op {
             None => {
                 // No operator means "version or greater, but less than next version"
@@ -317,6 +372,7 @@ impl VersionRange {
             lower_inclusive,
             upper_version,
             upper_inclusive,
+            excluded_versions: Vec::new(),
         })
     }
 
@@ -347,6 +403,7 @@ impl VersionRange {
             lower_inclusive,
             upper_version,
             upper_inclusive,
+            excluded_versions: Vec::new(),
         })
     }
 
@@ -374,6 +431,7 @@ impl VersionRange {
             lower_inclusive: true,
             upper_version,
             upper_inclusive: true,
+            excluded_versions: Vec::new(),
         })
     }
 
@@ -406,6 +464,7 @@ impl VersionRange {
                 lower_inclusive: true,
                 upper_version: None,
                 upper_inclusive: true,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -414,6 +473,12 @@ impl VersionRange {
             return Self::parse_compound_range(s);
         }
 
+        // Check for the compatible-release operator (~=1.2.0) before the
+        // plain tilde range below, since both start with '~'.
+        if s.starts_with("~=") {
+            return Self::parse_single_condition(s);
+        }
+
         // Check for tilde range (~1.2.0)
         if s.starts_with('~') {
             return Self::parse_tilde_range(&s[1..]);
@@ -475,6 +540,7 @@ impl VersionRange {
         let mut lower_inclusive = true;
         let mut upper_version: Option<Version> = None;
         let mut upper_inclusive = true;
+        let mut excluded_versions: Vec<Version> = Vec::new();
 
         for condition in conditions {
             if condition.is_empty() {
@@ -483,6 +549,7 @@ impl VersionRange {
 
             // Parse each condition and merge bounds
             let single_range = Self::parse_single_condition(condition)?;
+            excluded_versions.extend(single_range.excluded_versions.iter().cloned());
 
             // Merge lower bounds (take the more restrictive one)
             if let Some(ref new_lower) = single_range.lower_version {
@@ -542,6 +609,7 @@ impl VersionRange {
             lower_inclusive,
             upper_version,
             upper_inclusive,
+            excluded_versions,
         })
     }
 
@@ -549,6 +617,23 @@ impl VersionRange {
     fn parse_single_condition(s: &str) -> Result<Self, RezCoreError> {
         let s = s.trim();
 
+        if s.starts_with("!=") {
+            let version_str = &s[2..];
+            let version = Version::parse(version_str)?;
+            return Ok(Self {
+                range_str: s.to_string(),
+                lower_version: None,
+                lower_inclusive: true,
+                upper_version: None,
+                upper_inclusive: true,
+                excluded_versions: vec![version],
+            });
+        }
+
+        if s.starts_with("~=") {
+            return Self::parse_compatible_release_range(&s[2..]);
+        }
+
         if s.starts_with(">=") {
             let version_str = &s[2..];
             let version = Version::parse(version_str)?;
@@ -558,6 +643,7 @@ impl VersionRange {
                 lower_inclusive: true,
                 upper_version: None,
                 upper_inclusive: true,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -570,6 +656,7 @@ impl VersionRange {
                 lower_inclusive: false,
                 upper_version: None,
                 upper_inclusive: true,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -582,6 +669,7 @@ impl VersionRange {
                 lower_inclusive: true,
                 upper_version: Some(version),
                 upper_inclusive: true,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -594,6 +682,7 @@ impl VersionRange {
                 lower_inclusive: true,
                 upper_version: Some(version),
                 upper_inclusive: false,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -606,6 +695,7 @@ impl VersionRange {
                 lower_inclusive: true,
                 upper_version: Some(version),
                 upper_inclusive: true,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -618,6 +708,7 @@ impl VersionRange {
                 lower_inclusive: true,
                 upper_version: None,
                 upper_inclusive: true,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -630,6 +721,7 @@ impl VersionRange {
                 lower_inclusive: true,
                 upper_version: Some(next_version),
                 upper_inclusive: false,
+                excluded_versions: Vec::new(),
             });
         }
 
@@ -653,6 +745,47 @@ impl VersionRange {
             lower_inclusive: true,
             upper_version: Some(upper_version),
             upper_inclusive: false,
+            excluded_versions: Vec::new(),
+        })
+    }
+
+    /// Parse a PEP 440-style compatible-release range like "~=1.4.2",
+    /// meaning "compatible with 1.4.2": `>=1.4.2, <1.5`. Truncates the
+    /// last component and bumps the one before it; `~=X.Y` (only two
+    /// components) means `>=X.Y, <X+1`.
+    fn parse_compatible_release_range(version_str: &str) -> Result<Self, RezCoreError> {
+        let base_version = Version::parse(version_str)?;
+
+        let components: Vec<&str> = version_str.split(['.', '-', '_']).collect();
+        if components.len() < 2 {
+            return Err(RezCoreError::VersionParse(format!(
+                "Compatible-release operator requires at least two version components: '~={}'",
+                version_str
+            )));
+        }
+
+        let bump_index = components.len() - 2;
+        let bumped: u64 = components[bump_index].parse().map_err(|_| {
+            RezCoreError::VersionParse(format!(
+                "Compatible-release operator requires a numeric component: '~={}'",
+                version_str
+            ))
+        })?;
+
+        let mut upper_components: Vec<String> = components[..bump_index]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        upper_components.push((bumped + 1).to_string());
+        let upper_version = Version::parse(&upper_components.join("."))?;
+
+        Ok(Self {
+            range_str: format!("~={}", version_str),
+            lower_version: Some(base_version),
+            lower_inclusive: true,
+            upper_version: Some(upper_version),
+            upper_inclusive: false,
+            excluded_versions: Vec::new(),
         })
     }
 
@@ -670,6 +803,7 @@ impl VersionRange {
             lower_inclusive: true,
             upper_version: Some(upper_version),
             upper_inclusive: false,
+            excluded_versions: Vec::new(),
         })
     }
 }
@@ -788,4 +922,20 @@ mod tests {
         let range = VersionRange::parse("^1.0.0").unwrap();
         assert_eq!(range.range_str, "^1.0.0");
     }
+
+    #[test]
+    fn test_contains_version_ignores_local_segment_by_default() {
+        let range = VersionRange::parse(">=1.2.3").unwrap();
+        let local = Version::parse("1.2.3+cu118").unwrap();
+        assert!(range.contains_version(&local));
+    }
+
+    #[test]
+    fn test_contains_version_respects_local_segment_on_exact_bound() {
+        let range = VersionRange::parse("==1.2.3+cu118").unwrap();
+        let matching = Version::parse("1.2.3+cu118").unwrap();
+        let mismatched = Version::parse("1.2.3+cu117").unwrap();
+        assert!(range.contains_version(&matching));
+        assert!(!range.contains_version(&mismatched));
+    }
 }
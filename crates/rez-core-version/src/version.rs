@@ -17,6 +17,72 @@ use serde::{Serialize, Deserialize};
 /// Global state machine parser instance for optimal performance
 static OPTIMIZED_PARSER: Lazy<StateMachineParser> = Lazy::new(|| StateMachineParser::new());
 
+/// One alternating numeric/alphabetic piece of a version token, e.g.
+/// `"1alpha2"` splits into `[Numeric(1), Alpha("alpha"), Numeric(2)]`.
+/// Numeric subtokens compare as integers (so `10 > 9`, not `"10" < "9"`
+/// lexicographically) and always sort above an alphabetic subtoken at the
+/// same position.
+#[cfg(not(feature = "python-bindings"))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Subtoken {
+    Numeric(u64),
+    Alpha(String),
+}
+
+#[cfg(not(feature = "python-bindings"))]
+impl PartialOrd for Subtoken {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(not(feature = "python-bindings"))]
+impl Ord for Subtoken {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Subtoken::Numeric(a), Subtoken::Numeric(b)) => a.cmp(b),
+            (Subtoken::Alpha(a), Subtoken::Alpha(b)) => a.cmp(b),
+            // Numeric always outranks alpha at the same position.
+            (Subtoken::Numeric(_), Subtoken::Alpha(_)) => Ordering::Greater,
+            (Subtoken::Alpha(_), Subtoken::Numeric(_)) => Ordering::Less,
+        }
+    }
+}
+
+/// Split a single version token (e.g. `"1alpha2"`) into its alternating
+/// numeric and alphabetic subtokens.
+#[cfg(not(feature = "python-bindings"))]
+fn tokenize_subtokens(token: &str) -> Vec<Subtoken> {
+    let mut subtokens = Vec::new();
+    let mut chars = token.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_digit() {
+            let mut digits = String::new();
+            while let Some(&c) = chars.peek() {
+                if !c.is_ascii_digit() {
+                    break;
+                }
+                digits.push(c);
+                chars.next();
+            }
+            subtokens.push(Subtoken::Numeric(digits.parse().unwrap_or(0)));
+        } else {
+            let mut alpha = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_digit() {
+                    break;
+                }
+                alpha.push(c);
+                chars.next();
+            }
+            subtokens.push(Subtoken::Alpha(alpha));
+        }
+    }
+
+    subtokens
+}
+
 /// High-performance version representation compatible with rez
 #[cfg_attr(feature = "python-bindings", pyclass)]
 #[derive(Debug)]
@@ -27,8 +93,21 @@ pub struct Version {
     /// Version tokens (non-Python version)
     #[cfg(not(feature = "python-bindings"))]
     tokens: Vec<String>,
+    /// Cached alternating numeric/alphabetic subtokens for each entry of
+    /// `tokens`, parsed once at construction time. Comparison is a hot
+    /// path in the resolver, so this avoids re-tokenizing `tokens` on
+    /// every `cmp`/`Eq`/`Hash` call.
+    #[cfg(not(feature = "python-bindings"))]
+    parsed_tokens: Vec<Vec<Subtoken>>,
     /// Separators between tokens
     separators: Vec<String>,
+    /// PEP 440-style epoch (`N!` prefix). Dominates all other ordering;
+    /// defaults to 0 when not specified.
+    epoch: u64,
+    /// PEP 440-style local version segment (`+label` suffix), e.g. the
+    /// `cu118` in `1.2.3+cu118`. Only compared between otherwise-equal
+    /// public versions; see [`Version::cmp_for_range`].
+    local: Option<String>,
     /// Cached string representation
     #[cfg_attr(feature = "python-bindings", pyo3(get))]
     string_repr: String,
@@ -77,6 +156,8 @@ impl Version {
             Self {
                 tokens: cloned_tokens,
                 separators: self.separators.clone(),
+                epoch: self.epoch,
+                local: self.local.clone(),
                 string_repr: self.string_repr.clone(),
                 cached_hash: self.cached_hash,
             }
@@ -105,6 +186,8 @@ impl Version {
             Self {
                 tokens: new_tokens,
                 separators: new_separators,
+                epoch: self.epoch,
+                local: None,
                 string_repr,
                 cached_hash: None,
             }
@@ -134,6 +217,8 @@ impl Version {
             Ok(Self {
                 tokens: new_tokens,
                 separators: self.separators.clone(),
+                epoch: self.epoch,
+                local: None,
                 string_repr,
                 cached_hash: None,
             })
@@ -287,22 +372,64 @@ impl Version {
 }
 
 impl Version {
+    /// Split a leading PEP 440-style epoch segment (`N!`) off a version
+    /// string, e.g. `"1!2.3"` -> `(Some(1), "2.3")`.
+    fn split_epoch(s: &str) -> Result<(Option<u64>, &str), RezCoreError> {
+        match s.find('!') {
+            Some(idx) => {
+                let epoch_str = &s[..idx];
+                let epoch = epoch_str.parse::<u64>().map_err(|_| {
+                    RezCoreError::VersionParse(format!("Invalid epoch in version: '{}'", s))
+                })?;
+                Ok((Some(epoch), &s[idx + 1..]))
+            }
+            None => Ok((None, s)),
+        }
+    }
+
+    /// Split a trailing PEP 440-style local version segment (`+label`) off
+    /// an (epoch-stripped) version string, e.g. `"1.2.3+cu118"` ->
+    /// `("1.2.3", Some("cu118"))`. Only the first `+` is treated as the
+    /// local separator.
+    fn split_local(s: &str) -> Result<(&str, Option<String>), RezCoreError> {
+        match s.find('+') {
+            Some(idx) => {
+                let local = &s[idx + 1..];
+                if local.is_empty()
+                    || !local.chars().all(|c| c.is_alphanumeric() || c == '.' || c == '-')
+                {
+                    return Err(RezCoreError::VersionParse(format!(
+                        "Invalid local version segment in: '{}'",
+                        s
+                    )));
+                }
+                Ok((&s[..idx], Some(local.to_string())))
+            }
+            None => Ok((s, None)),
+        }
+    }
+
     /// Internal parsing function that runs without GIL
-    /// Returns (tokens, separators) as pure Rust data
-    fn parse_internal_gil_free(s: &str) -> Result<(Vec<String>, Vec<String>), RezCoreError> {
+    /// Returns (epoch, tokens, separators, local) as pure Rust data
+    fn parse_internal_gil_free(
+        s: &str,
+    ) -> Result<(Option<u64>, Vec<String>, Vec<String>, Option<String>), RezCoreError> {
+        let (epoch, rest) = Self::split_epoch(s)?;
+        let (public, local) = Self::split_local(rest)?;
+
         // Validate version format - reject obvious invalid patterns
-        if s.starts_with('v') || s.starts_with('V') {
+        if public.starts_with('v') || public.starts_with('V') {
             return Err(RezCoreError::VersionParse(format!("Version prefixes not supported: '{}'", s)));
         }
 
         // Check for invalid characters or patterns
-        if s.contains("..") || s.starts_with('.') || s.ends_with('.') {
+        if public.contains("..") || public.starts_with('.') || public.ends_with('.') {
             return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
         }
 
         // Use regex to find tokens (alphanumeric + underscore)
         let token_regex = Regex::new(r"[a-zA-Z0-9_]+").unwrap();
-        let tokens: Vec<&str> = token_regex.find_iter(s).map(|m| m.as_str()).collect();
+        let tokens: Vec<&str> = token_regex.find_iter(public).map(|m| m.as_str()).collect();
 
         if tokens.is_empty() {
             return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
@@ -320,7 +447,7 @@ impl Version {
         }
 
         // Extract separators
-        let separators: Vec<&str> = token_regex.split(s).collect();
+        let separators: Vec<&str> = token_regex.split(public).collect();
 
         // Validate separators (should be empty at start/end, single char in middle)
         if !separators[0].is_empty() || !separators[separators.len()-1].is_empty() {
@@ -331,8 +458,9 @@ impl Version {
             if sep.len() > 1 {
                 return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
             }
-            // Only allow specific separators
-            if !matches!(*sep, "." | "-" | "_" | "+") {
+            // Only allow specific separators (the local-version '+' was
+            // already split off above)
+            if !matches!(*sep, "." | "-" | "_") {
                 return Err(RezCoreError::VersionParse(format!("Invalid separator '{}' in version: '{}'", sep, s)));
             }
         }
@@ -367,15 +495,17 @@ impl Version {
             .map(|s| s.to_string())
             .collect();
 
-        Ok((token_strings, sep_strings))
+        Ok((epoch, token_strings, sep_strings, local))
     }
 
     /// Create Version with Python tokens (requires GIL)
     #[cfg(feature = "python-bindings")]
     fn create_version_with_python_tokens(
         py: Python<'_>,
+        epoch: Option<u64>,
         tokens: Vec<String>,
         separators: Vec<String>,
+        local: Option<String>,
         original_str: &str,
     ) -> Result<Self, RezCoreError> {
         // Create rez-compatible tokens
@@ -392,6 +522,8 @@ impl Version {
         Ok(Self {
             tokens: py_tokens,
             separators,
+            epoch: epoch.unwrap_or(0),
+            local,
             string_repr: original_str.to_string(),
             cached_hash: None,
         })
@@ -463,7 +595,11 @@ impl Version {
     pub fn inf() -> Self {
         Self {
             tokens: vec![],
+            #[cfg(not(feature = "python-bindings"))]
+            parsed_tokens: vec![],
             separators: vec![],
+            epoch: 0,
+            local: None,
             string_repr: "inf".to_string(),
             cached_hash: None,
         }
@@ -478,7 +614,11 @@ impl Version {
     pub fn empty() -> Self {
         Self {
             tokens: vec![],
+            #[cfg(not(feature = "python-bindings"))]
+            parsed_tokens: vec![],
             separators: vec![],
+            epoch: 0,
+            local: None,
             string_repr: "".to_string(),
             cached_hash: None,
         }
@@ -571,8 +711,11 @@ impl Version {
             return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
         }
 
+        let (epoch, rest) = Self::split_epoch(s)?;
+        let (public, local) = Self::split_local(rest)?;
+
         // Use the optimized state machine parser
-        let (tokens, separators) = OPTIMIZED_PARSER.parse_tokens(s)?;
+        let (tokens, separators) = OPTIMIZED_PARSER.parse_tokens(public)?;
 
         // Convert to Python tokens for compatibility
         Python::with_gil(|py| {
@@ -604,6 +747,8 @@ impl Version {
             Ok(Self {
                 tokens: py_tokens,
                 separators: sep_strings,
+                epoch: epoch.unwrap_or(0),
+                local,
                 string_repr: s.to_string(),
                 cached_hash: None,
             })
@@ -667,9 +812,9 @@ impl Version {
                 // All validation and token extraction in GIL-free zone
                 Self::parse_internal_gil_free(s)
             })
-            .and_then(|(tokens, separators)| {
+            .and_then(|(epoch, tokens, separators, local)| {
                 // Convert to Python objects with GIL
-                Self::create_version_with_python_tokens(py, tokens, separators, s)
+                Self::create_version_with_python_tokens(py, epoch, tokens, separators, local, s)
             })
         })
     }
@@ -704,89 +849,10 @@ impl Version {
             return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
         }
 
-        Python::with_gil(|py| {
-            // Use regex to find tokens (alphanumeric + underscore)
-            let token_regex = Regex::new(r"[a-zA-Z0-9_]+").unwrap();
-            let tokens: Vec<&str> = token_regex.find_iter(s).map(|m| m.as_str()).collect();
-
-            if tokens.is_empty() {
-                return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
-            }
-
-            // Check for too many numeric-only tokens (reject versions like 1.2.3.4.5.6)
-            let numeric_tokens: Vec<_> = tokens.iter().filter(|t| t.chars().all(|c| c.is_ascii_digit())).collect();
-            if numeric_tokens.len() > 5 {
-                return Err(RezCoreError::VersionParse(format!("Version too complex: '{}'", s)));
-            }
-
-            // Check for too many tokens overall
-            if tokens.len() > 10 {
-                return Err(RezCoreError::VersionParse(format!("Version too complex: '{}'", s)));
-            }
-
-            // Extract separators
-            let separators: Vec<&str> = token_regex.split(s).collect();
-
-            // Validate separators (should be empty at start/end, single char in middle)
-            if !separators[0].is_empty() || !separators[separators.len()-1].is_empty() {
-                return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
-            }
-
-            for sep in &separators[1..separators.len()-1] {
-                if sep.len() > 1 {
-                    return Err(RezCoreError::VersionParse(format!("Invalid version syntax: '{}'", s)));
-                }
-                // Only allow specific separators
-                if !matches!(*sep, "." | "-" | "_" | "+") {
-                    return Err(RezCoreError::VersionParse(format!("Invalid separator '{}' in version: '{}'", sep, s)));
-                }
-            }
-
-            // Validate tokens before creating them
-            for token_str in &tokens {
-                // Check if token contains only valid characters
-                if !token_str.chars().all(|c| c.is_alphanumeric() || c == '_') {
-                    return Err(RezCoreError::VersionParse(format!("Invalid characters in token: '{}'", token_str)));
-                }
-
-                // Check for invalid patterns
-                if token_str.starts_with('_') || token_str.ends_with('_') {
-                    return Err(RezCoreError::VersionParse(format!("Invalid token format: '{}'", token_str)));
-                }
-
-                // Reject tokens that are purely alphabetic and don't look like version components
-                if token_str.chars().all(|c| c.is_alphabetic()) && token_str.len() > 10 {
-                    return Err(RezCoreError::VersionParse(format!("Invalid version token: '{}'", token_str)));
-                }
-
-                // Reject common invalid patterns
-                if *token_str == "not" || *token_str == "version" {
-                    return Err(RezCoreError::VersionParse(format!("Invalid version token: '{}'", token_str)));
-                }
-            }
-
-            // Create rez-compatible tokens
-            let mut py_tokens = Vec::new();
-            for token_str in tokens {
-                // For now, create all tokens as AlphanumericVersionToken
-                // TODO: Implement proper NumericToken vs AlphanumericVersionToken distinction
-                let alpha_class = py.get_type::<AlphanumericVersionToken>();
-                let py_token = alpha_class.call1((token_str,))
-                    .map_err(|e| RezCoreError::PyO3(e))?.into();
-                py_tokens.push(py_token);
-            }
-
-            let sep_strings: Vec<String> = separators[1..separators.len()-1]
-                .iter()
-                .map(|s| s.to_string())
-                .collect();
+        let (epoch, tokens, separators, local) = Self::parse_internal_gil_free(s)?;
 
-            Ok(Self {
-                tokens: py_tokens,
-                separators: sep_strings,
-                string_repr: s.to_string(),
-                cached_hash: None,
-            })
+        Python::with_gil(|py| {
+            Self::create_version_with_python_tokens(py, epoch, tokens, separators, local, s)
         })
     }
 
@@ -811,11 +877,15 @@ impl Version {
         }
 
         // Parse using the GIL-free method
-        let (tokens, separators) = Self::parse_internal_gil_free(s)?;
+        let (epoch, tokens, separators, local) = Self::parse_internal_gil_free(s)?;
+        let parsed_tokens = tokens.iter().map(|token| tokenize_subtokens(token)).collect();
 
         Ok(Self {
             tokens,
+            parsed_tokens,
             separators,
+            epoch: epoch.unwrap_or(0),
+            local,
             string_repr: s.to_string(),
             cached_hash: None,
         })
@@ -919,8 +989,13 @@ impl Version {
             (false, false) => {} // Continue with normal comparison
         }
 
+        // Epoch dominates all other ordering
+        if self.epoch != other.epoch {
+            return self.epoch.cmp(&other.epoch);
+        }
+
         // Compare tokens using rez logic
-        Python::with_gil(|py| {
+        let token_cmp = Python::with_gil(|py| {
             let max_len = self.tokens.len().max(other.tokens.len());
 
             for i in 0..max_len {
@@ -982,7 +1057,12 @@ impl Version {
             }
 
             Ordering::Equal
-        })
+        });
+
+        if token_cmp != Ordering::Equal {
+            return token_cmp;
+        }
+        Self::compare_local(&self.local, &other.local)
     }
 
     /// Compare two versions using rez-compatible rules (non-Python version)
@@ -1004,34 +1084,123 @@ impl Version {
             (false, false) => {} // Continue with normal comparison
         }
 
-        // Compare tokens using string comparison for now
-        Self::compare_token_strings(&self.tokens, &other.tokens)
+        // Epoch dominates all other ordering
+        if self.epoch != other.epoch {
+            return self.epoch.cmp(&other.epoch);
+        }
+
+        // Compare tokens using rez-compatible subtoken comparison
+        let token_cmp = Self::compare_parsed_tokens(&self.parsed_tokens, &other.parsed_tokens);
+        if token_cmp != Ordering::Equal {
+            return token_cmp;
+        }
+        Self::compare_local(&self.local, &other.local)
     }
 
-    /// Simple string-based token comparison for non-Python version
+    /// Rez-compatible, element-wise comparison of two versions' cached
+    /// subtoken lists: compares token-by-token, and within a token,
+    /// subtoken-by-subtoken (numeric subtokens as integers, alpha
+    /// subtokens lexicographically, numeric always outranking alpha at
+    /// the same position). When one version runs out of tokens, the
+    /// shorter one is smaller, unless the longer side's next token is a
+    /// pre-release marker (starts with an alpha subtoken), in which case
+    /// the pre-release version is treated as smaller instead.
     #[cfg(not(feature = "python-bindings"))]
-    fn compare_token_strings(tokens1: &[String], tokens2: &[String]) -> Ordering {
-        for (t1, t2) in tokens1.iter().zip(tokens2.iter()) {
-            // Try to parse as numbers first
-            match (t1.parse::<i64>(), t2.parse::<i64>()) {
-                (Ok(n1), Ok(n2)) => {
-                    let cmp = n1.cmp(&n2);
-                    if cmp != Ordering::Equal {
-                        return cmp;
-                    }
-                }
-                _ => {
-                    // Fall back to string comparison
+    fn compare_parsed_tokens(tokens1: &[Vec<Subtoken>], tokens2: &[Vec<Subtoken>]) -> Ordering {
+        let max_len = tokens1.len().max(tokens2.len());
+
+        for i in 0..max_len {
+            match (tokens1.get(i), tokens2.get(i)) {
+                (Some(t1), Some(t2)) => {
                     let cmp = t1.cmp(t2);
                     if cmp != Ordering::Equal {
                         return cmp;
                     }
                 }
+                (Some(extra), None) => {
+                    return if matches!(extra.first(), Some(Subtoken::Alpha(_))) {
+                        Ordering::Less // Pre-release is less than release
+                    } else {
+                        Ordering::Greater // More tokens = greater (default)
+                    };
+                }
+                (None, Some(extra)) => {
+                    return if matches!(extra.first(), Some(Subtoken::Alpha(_))) {
+                        Ordering::Greater // Release is greater than pre-release
+                    } else {
+                        Ordering::Less // Fewer tokens = less (default)
+                    };
+                }
+                (None, None) => break,
+            }
+        }
+
+        Ordering::Equal
+    }
+
+    /// Compare two local version segments PEP 440-style: absence sorts
+    /// before presence, and present segments are compared piece-wise
+    /// (split on `.`/`-`), numeric segments compared numerically and
+    /// always ranked above alphanumeric segments at the same position.
+    fn compare_local(a: &Option<String>, b: &Option<String>) -> Ordering {
+        let (a, b) = match (a, b) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => (a, b),
+        };
+
+        let a_segs: Vec<&str> = a.split(['.', '-']).collect();
+        let b_segs: Vec<&str> = b.split(['.', '-']).collect();
+
+        for i in 0..a_segs.len().max(b_segs.len()) {
+            let ord = match (a_segs.get(i), b_segs.get(i)) {
+                (Some(sa), Some(sb)) => match (sa.parse::<u64>(), sb.parse::<u64>()) {
+                    (Ok(na), Ok(nb)) => na.cmp(&nb),
+                    (Ok(_), Err(_)) => Ordering::Greater,
+                    (Err(_), Ok(_)) => Ordering::Less,
+                    (Err(_), Err(_)) => sa.cmp(sb),
+                },
+                (Some(_), None) => Ordering::Greater,
+                (None, Some(_)) => Ordering::Less,
+                (None, None) => break,
+            };
+            if ord != Ordering::Equal {
+                return ord;
             }
         }
 
-        // If all compared tokens are equal, compare lengths
-        tokens1.len().cmp(&tokens2.len())
+        Ordering::Equal
+    }
+
+    /// This version's PEP 440-style epoch (`N!` prefix), or 0 if unspecified.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// This version's PEP 440-style local segment (`+label` suffix), if any.
+    pub fn local_version(&self) -> Option<&str> {
+        self.local.as_deref()
+    }
+
+    /// A clone of this version with any local segment stripped.
+    pub fn without_local(&self) -> Self {
+        let mut v = self.clone();
+        v.local = None;
+        v
+    }
+
+    /// Compare `self` against `bound` the way a range comparator would:
+    /// if `bound` doesn't specify a local version segment, `self`'s local
+    /// segment (if any) is ignored, so e.g. `1.2.3+cu118` still satisfies
+    /// a bound of `1.2.3`. If `bound` does specify a local segment, the
+    /// comparison is exact.
+    pub fn cmp_for_range(&self, bound: &Self) -> Ordering {
+        if bound.local.is_none() && self.local.is_some() {
+            self.without_local().cmp(bound)
+        } else {
+            self.cmp(bound)
+        }
     }
 
 }
@@ -1057,12 +1226,26 @@ impl Ord for Version {
     }
 }
 
+#[cfg(feature = "python-bindings")]
 impl Hash for Version {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.string_repr.hash(state);
     }
 }
 
+#[cfg(not(feature = "python-bindings"))]
+impl Hash for Version {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash the same canonical representation `cmp`/`Eq` compare on
+        // (parsed tokens, epoch, local) rather than `string_repr`, since
+        // `cmp` ignores separators entirely (e.g. "1.2" and "1-2" compare
+        // equal) while `string_repr` would not.
+        self.parsed_tokens.hash(state);
+        self.epoch.hash(state);
+        self.local.hash(state);
+    }
+}
+
 #[cfg(feature = "python-bindings")]
 impl Clone for Version {
     fn clone(&self) -> Self {
@@ -1075,6 +1258,8 @@ impl Clone for Version {
             Self {
                 tokens: cloned_tokens,
                 separators: self.separators.clone(),
+                epoch: self.epoch,
+                local: self.local.clone(),
                 string_repr: self.string_repr.clone(),
                 cached_hash: self.cached_hash,
             }
@@ -1087,7 +1272,10 @@ impl Clone for Version {
     fn clone(&self) -> Self {
         Self {
             tokens: self.tokens.clone(),
+            parsed_tokens: self.parsed_tokens.clone(),
             separators: self.separators.clone(),
+            epoch: self.epoch,
+            local: self.local.clone(),
             string_repr: self.string_repr.clone(),
             cached_hash: self.cached_hash,
         }
@@ -1203,5 +1391,40 @@ mod tests {
         assert_eq!(trimmed.__len__(), 2);
     }
 
+    #[test]
+    fn test_version_epoch_dominates_ordering() {
+        let v1 = Version::parse("1!1.0.0").unwrap();
+        let v2 = Version::parse("2.0.0").unwrap();
+        assert_eq!(v1.epoch(), 1);
+        assert_eq!(v2.epoch(), 0);
+        // Epoch 1 outranks epoch 0 regardless of the public version.
+        assert_eq!(v1.cmp(&v2), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_version_local_segment_parsing_and_ordering() {
+        let base = Version::parse("1.2.3").unwrap();
+        let local = Version::parse("1.2.3+cu118").unwrap();
+        assert_eq!(local.local_version(), Some("cu118"));
+        assert_eq!(base.local_version(), None);
+        // Same public version: presence of a local segment sorts higher.
+        assert_eq!(base.cmp(&local), Ordering::Less);
 
+        let local_older = Version::parse("1.2.3+cu117").unwrap();
+        assert_eq!(local_older.cmp(&local), Ordering::Less);
+    }
+
+    #[test]
+    fn test_version_cmp_for_range_ignores_local_unless_bound_has_one() {
+        let bound = Version::parse("1.2.3").unwrap();
+        let local = Version::parse("1.2.3+cu118").unwrap();
+
+        // A bare bound ignores the candidate's local segment.
+        assert_eq!(local.cmp_for_range(&bound), Ordering::Equal);
+        // A bound with its own local segment compares exactly.
+        let exact_bound = Version::parse("1.2.3+cu118").unwrap();
+        assert_eq!(local.cmp_for_range(&exact_bound), Ordering::Equal);
+        let mismatched_bound = Version::parse("1.2.3+cu117").unwrap();
+        assert_eq!(local.cmp_for_range(&mismatched_bound), Ordering::Greater);
+    }
 }
@@ -3,6 +3,7 @@
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 use std::path::PathBuf;
 
@@ -66,6 +67,30 @@ pub struct RezCoreConfig {
 
     /// Terminal type
     pub terminal_emulator_command: String,
+
+    /// Custom command aliases, e.g. `"co" -> ["context"]`, resolved when a
+    /// subcommand name isn't recognized (mirrors cargo's `[alias]` table).
+    pub aliases: HashMap<String, AliasValue>,
+}
+
+/// An alias's expansion, as written in config: either a single string
+/// (split on whitespace when expanded) or an explicit list of argument
+/// tokens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AliasValue {
+    Single(String),
+    List(Vec<String>),
+}
+
+impl AliasValue {
+    /// Expand this alias value into argument tokens.
+    pub fn tokens(&self) -> Vec<String> {
+        match self {
+            AliasValue::Single(s) => s.split_whitespace().map(str::to_string).collect(),
+            AliasValue::List(tokens) => tokens.clone(),
+        }
+    }
 }
 
 /// Cache configuration
@@ -138,6 +163,7 @@ impl Default for RezCoreConfig {
                 "xterm"
             }
             .to_string(),
+            aliases: HashMap::new(),
         }
     }
 }
@@ -236,4 +262,65 @@ impl RezCoreConfig {
 
         Some(current.clone())
     }
+
+    /// Every dotted field path [`Self::get_field`] can resolve, e.g.
+    /// `"version"`, `"cache"`, `"cache.memory_cache_size"` — both leaves and
+    /// the objects containing them. Lets a caller (e.g. the `config` CLI
+    /// command) offer "did you mean ...?" suggestions for an unknown field.
+    pub fn field_paths(&self) -> Vec<String> {
+        let mut paths = Vec::new();
+        if let Ok(config_json) = serde_json::to_value(self) {
+            Self::collect_field_paths(&config_json, String::new(), &mut paths);
+        }
+        paths
+    }
+
+    fn collect_field_paths(value: &serde_json::Value, prefix: String, paths: &mut Vec<String>) {
+        let serde_json::Value::Object(fields) = value else {
+            return;
+        };
+        for (key, child) in fields {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            paths.push(path.clone());
+            Self::collect_field_paths(child, path, paths);
+        }
+    }
+
+    /// Resolve an unrecognized subcommand `name` through `aliases`,
+    /// expanding chained aliases (an alias's first token naming another
+    /// alias) up to [`Self::MAX_ALIAS_DEPTH`] hops. Returns `None` if
+    /// `name` isn't an alias, or if it shadows one of `known_commands` —
+    /// a real built-in command should just run, never be replaced.
+    pub fn resolve_alias(&self, name: &str, known_commands: &[String]) -> Option<Vec<String>> {
+        if known_commands.iter().any(|c| c == name) {
+            return None;
+        }
+
+        let mut tokens = self.aliases.get(name)?.tokens();
+
+        for _ in 0..Self::MAX_ALIAS_DEPTH {
+            let Some(head) = tokens.first().cloned() else {
+                break;
+            };
+            if known_commands.iter().any(|c| c == &head) {
+                break;
+            }
+            let Some(next) = self.aliases.get(&head) else {
+                break;
+            };
+            let mut expanded = next.tokens();
+            expanded.extend_from_slice(&tokens[1..]);
+            tokens = expanded;
+        }
+
+        Some(tokens)
+    }
+
+    /// Max alias-expansion hops, so a misconfigured alias-to-alias cycle
+    /// (`co = "ctx"`, `ctx = "co"`) can't recurse forever.
+    const MAX_ALIAS_DEPTH: usize = 8;
 }
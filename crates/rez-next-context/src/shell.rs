@@ -6,7 +6,16 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command as AsyncCommand;
+use tokio_util::sync::CancellationToken;
+
+/// Grace period a child gets to exit on its own before
+/// [`ShellExecutor::terminate_child`] kills it outright. On Unix the child
+/// is sent `SIGTERM` first; on Windows no signal is sent, so the child
+/// just gets this much time before the hard kill.
+const TERMINATION_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_secs(5);
 
 /// Supported shell types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -21,6 +30,10 @@ pub enum ShellType {
     Cmd,
     /// PowerShell
     PowerShell,
+    /// In-process command interpreter (see `rez_next_shell::interp`) —
+    /// runs the same command line identically on every OS instead of
+    /// shelling out to a host shell.
+    InterpreterShell,
 }
 
 impl ShellType {
@@ -32,6 +45,9 @@ impl ShellType {
             ShellType::Fish => "fish",
             ShellType::Cmd => "cmd",
             ShellType::PowerShell => "powershell",
+            // No subprocess is spawned for this variant; ShellExecutor
+            // short-circuits to the in-process interpreter instead.
+            ShellType::InterpreterShell => "",
         }
     }
 
@@ -42,6 +58,7 @@ impl ShellType {
             ShellType::Fish => "fish",
             ShellType::Cmd => "bat",
             ShellType::PowerShell => "ps1",
+            ShellType::InterpreterShell => "sh",
         }
     }
 
@@ -52,12 +69,20 @@ impl ShellType {
             ShellType::Fish => "-c",
             ShellType::Cmd => "/c",
             ShellType::PowerShell => "-Command",
+            ShellType::InterpreterShell => "",
         }
     }
 
     /// Detect the current shell from environment
     pub fn detect() -> Self {
-        if let Ok(shell) = std::env::var("SHELL") {
+        Self::detect_with_env(&SystemEnv)
+    }
+
+    /// Same as [`Self::detect`], but reads variables through `env`
+    /// instead of `std::env` directly, so detection can be unit-tested
+    /// against a [`MockEnv`] instead of the host's real environment.
+    pub fn detect_with_env(env: &dyn Env) -> Self {
+        if let Some(shell) = env.var("SHELL") {
             if shell.contains("bash") {
                 return ShellType::Bash;
             } else if shell.contains("zsh") {
@@ -69,7 +94,7 @@ impl ShellType {
 
         // Check for Windows
         if cfg!(windows) {
-            if std::env::var("PSModulePath").is_ok() {
+            if env.var("PSModulePath").is_some() {
                 ShellType::PowerShell
             } else {
                 ShellType::Cmd
@@ -80,6 +105,68 @@ impl ShellType {
     }
 }
 
+/// Reads process environment variables. Production code uses
+/// [`SystemEnv`]; tests inject a [`MockEnv`] so shell detection doesn't
+/// depend on the host's actual `SHELL`/`PSModulePath`.
+pub trait Env: Send + Sync {
+    /// Look up an environment variable, returning `None` if it's unset.
+    fn var(&self, key: &str) -> Option<String>;
+}
+
+/// The real [`Env`]: reads straight from `std::env`.
+#[derive(Debug, Clone, Default)]
+pub struct SystemEnv;
+
+impl Env for SystemEnv {
+    fn var(&self, key: &str) -> Option<String> {
+        std::env::var(key).ok()
+    }
+}
+
+/// Spawns and waits on shell commands on behalf of [`ShellExecutor`].
+/// Production code uses [`SystemCommandRunner`]; tests inject a
+/// `MockRunner` (see the `tests` module below) to assert on
+/// `command_exists`/`get_shell_info` behavior without spawning real
+/// processes.
+#[async_trait::async_trait]
+pub trait CommandRunner: Send + Sync {
+    /// Run `command` under `shell_type` and wait for it to finish.
+    async fn run(
+        &self,
+        shell_type: &ShellType,
+        command: &str,
+        working_directory: Option<&PathBuf>,
+        environment: &HashMap<String, String>,
+        timeout_seconds: u64,
+    ) -> Result<CommandResult, RezCoreError>;
+}
+
+/// The real [`CommandRunner`]: delegates to [`ShellExecutor::execute`],
+/// which spawns a child process (or runs the in-process interpreter for
+/// [`ShellType::InterpreterShell`]).
+#[derive(Debug, Clone, Default)]
+pub struct SystemCommandRunner;
+
+#[async_trait::async_trait]
+impl CommandRunner for SystemCommandRunner {
+    async fn run(
+        &self,
+        shell_type: &ShellType,
+        command: &str,
+        working_directory: Option<&PathBuf>,
+        environment: &HashMap<String, String>,
+        timeout_seconds: u64,
+    ) -> Result<CommandResult, RezCoreError> {
+        let mut executor = ShellExecutor::with_shell(shell_type.clone())
+            .with_environment(environment.clone())
+            .with_timeout(timeout_seconds);
+        if let Some(wd) = working_directory {
+            executor = executor.with_working_directory(wd.clone());
+        }
+        executor.execute(command).await
+    }
+}
+
 /// Shell command execution result
 // #[pyclass]  // Temporarily disabled due to DLL issues
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,9 +225,159 @@ impl CommandResult {
     }
 }
 
+/// Which stream an [`OutputLine`] from [`ShellExecutor::execute_streaming`]
+/// came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    /// Standard output
+    Stdout,
+    /// Standard error
+    Stderr,
+}
+
+/// A single line of output from [`ShellExecutor::execute_streaming`],
+/// tagged with which stream it came from. The line has its trailing
+/// newline stripped, matching `BufRead::lines()`.
+#[derive(Debug, Clone)]
+pub struct OutputLine {
+    /// Which stream the line came from
+    pub stream: OutputStream,
+    /// The line's contents, without the trailing newline
+    pub line: String,
+}
+
+/// Input to feed a spawned shell command's stdin, used by
+/// [`ShellExecutor::execute_with_input`] and
+/// [`ShellExecutor::execute_streaming_with_input`].
+#[derive(Debug, Clone)]
+pub enum ShellInput {
+    /// Write this string to stdin, then close it so the child sees EOF.
+    Inline(String),
+    /// Read this file's contents and write them to stdin, then close it.
+    File(PathBuf),
+    /// Don't pipe stdin at all; the child inherits the parent's stdin.
+    /// Needed for commands that prompt interactively.
+    Inherit,
+}
+
+/// Where a [`Redirect`] sends the stream: to a file, or duplicated from
+/// another file descriptor (e.g. `2>&1`).
+#[derive(Debug, Clone)]
+pub enum RedirectTarget {
+    File(PathBuf),
+    Fd(u32),
+}
+
+/// A single stream redirect attached to a [`Pipeline`]'s last stage, e.g.
+/// `> out.txt` is `Redirect { fd: 1, target: RedirectTarget::File(..) }`
+/// and `2>&1` is `Redirect { fd: 2, target: RedirectTarget::Fd(1) }`.
+#[derive(Debug, Clone)]
+pub struct Redirect {
+    pub fd: u32,
+    pub target: RedirectTarget,
+}
+
+/// A sequence of commands joined by `|`, each run under the executor's
+/// configured host shell, with `redirects` applied to the last stage.
+/// This is the typed equivalent of a shell pipeline string like
+/// `echo hello | sort > out.txt`, for callers that want to build one up
+/// as Rust values instead of string-concatenating shell operators.
+#[derive(Debug, Clone, Default)]
+pub struct Pipeline {
+    pub commands: Vec<String>,
+    pub redirects: Vec<Redirect>,
+}
+
+impl Pipeline {
+    /// Create a pipeline from its `|`-joined command stages.
+    pub fn new(commands: Vec<String>) -> Self {
+        Self {
+            commands,
+            redirects: Vec::new(),
+        }
+    }
+
+    /// Attach a redirect to the pipeline's last stage.
+    pub fn with_redirect(mut self, redirect: Redirect) -> Self {
+        self.redirects.push(redirect);
+        self
+    }
+}
+
+/// A group of [`Pipeline`]s, run either one after another (`;`) or all at
+/// once (`&`).
+#[derive(Debug, Clone)]
+pub enum CommandGroup {
+    /// `a ; b` — run every pipeline in order, regardless of exit code.
+    Sequential(Vec<Pipeline>),
+    /// `a & b` — run every pipeline concurrently.
+    Parallel(Vec<Pipeline>),
+}
+
+/// Options for [`ShellExecutor::watch`].
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Only re-run on changes to paths matching one of these glob
+    /// patterns (`*` and `?` wildcards, `**` for any number of
+    /// directories). Empty means no include filter — everything matches.
+    pub include: Vec<String>,
+    /// Never re-run on changes to paths matching one of these glob
+    /// patterns, even if they also match `include`.
+    pub exclude: Vec<String>,
+    /// How long to wait after a relevant change before re-running; any
+    /// further events arriving within that window are folded into the
+    /// same re-run, so a burst of saves only triggers one.
+    pub debounce: std::time::Duration,
+}
+
+impl WatchOptions {
+    fn path_is_relevant(&self, path: &std::path::Path) -> bool {
+        let text = path.to_string_lossy();
+        if self.exclude.iter().any(|pattern| glob_matches(pattern, &text)) {
+            return false;
+        }
+        self.include.is_empty()
+            || self
+                .include
+                .iter()
+                .any(|pattern| glob_matches(pattern, &text))
+    }
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+            debounce: std::time::Duration::from_millis(200),
+        }
+    }
+}
+
+/// Match `text` against a shell-style glob `pattern` (`*` for anything
+/// except a directory separator, `**` for any number of directories,
+/// `?` for a single character).
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+
+    // `**` is parked on a placeholder so the single-`*` replacement
+    // doesn't rewrite the `*` inside its `.*`.
+    let regex_pattern = pattern
+        .replace("**", "\u{0}")
+        .replace('*', "[^/]*")
+        .replace('?', ".")
+        .replace('\u{0}', ".*");
+
+    regex::Regex::new(&format!("^{}$", regex_pattern))
+        .map(|re| re.is_match(text))
+        .unwrap_or(text == pattern)
+}
+
 /// Shell executor for running commands in resolved contexts
 // #[pyclass]  // Temporarily disabled due to DLL issues
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ShellExecutor {
     /// Shell type to use
     shell_type: ShellType,
@@ -150,6 +387,15 @@ pub struct ShellExecutor {
     environment: HashMap<String, String>,
     /// Timeout for command execution (in seconds)
     timeout_seconds: u64,
+    /// Runner used by idempotent probes (`command_exists`,
+    /// `get_shell_info`). Defaults to [`SystemCommandRunner`]; tests
+    /// swap in a `MockRunner` via [`Self::with_runner`].
+    runner: Arc<dyn CommandRunner>,
+    /// Cache of [`Self::get_shell_info`], since a shell's version and
+    /// executable path can't change over the executor's lifetime.
+    shell_info_cache: Arc<tokio::sync::OnceCell<ShellInfo>>,
+    /// Cache of [`Self::command_exists`] results, keyed by command name.
+    command_exists_cache: Arc<Mutex<HashMap<String, bool>>>,
 }
 
 // Python methods temporarily disabled due to DLL issues
@@ -215,9 +461,20 @@ impl ShellExecutor {
             working_directory: None,
             environment: HashMap::new(),
             timeout_seconds: 300, // 5 minutes default
+            runner: Arc::new(SystemCommandRunner),
+            shell_info_cache: Arc::new(tokio::sync::OnceCell::new()),
+            command_exists_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Override the [`CommandRunner`] used by `command_exists` and
+    /// `get_shell_info`, so tests can assert on their behavior without
+    /// spawning a real shell.
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
     /// Set the environment variables
     pub fn with_environment(mut self, environment: HashMap<String, String>) -> Self {
         self.environment = environment;
@@ -238,6 +495,35 @@ impl ShellExecutor {
 
     /// Execute a command and wait for completion
     pub async fn execute(&self, command: &str) -> Result<CommandResult, RezCoreError> {
+        // A token that's never cancelled: this just routes the timeout
+        // path through `execute_cancellable`'s shared kill-on-timeout logic.
+        self.execute_cancellable(command, CancellationToken::new())
+            .await
+    }
+
+    /// Execute a command, same as [`Self::execute`], but also watch
+    /// `cancel` so long-running resolved-context commands can be aborted
+    /// programmatically (e.g. in response to a forwarded `SIGINT`). Both
+    /// cancellation and hitting `timeout_seconds` take the same path:
+    /// the child is asked to terminate gracefully via
+    /// [`Self::terminate_child`] instead of being dropped and possibly
+    /// orphaned.
+    pub async fn execute_cancellable(
+        &self,
+        command: &str,
+        cancel: CancellationToken,
+    ) -> Result<CommandResult, RezCoreError> {
+        if self.shell_type == ShellType::InterpreterShell {
+            // The in-process interpreter has no child process to signal;
+            // cancellation just races the interpreted run itself.
+            return tokio::select! {
+                result = self.execute_interpreted(command) => result,
+                _ = cancel.cancelled() => Err(RezCoreError::ExecutionError(
+                    "Command cancelled".to_string(),
+                )),
+            };
+        }
+
         let start_time = std::time::Instant::now();
 
         let mut cmd = AsyncCommand::new(self.shell_type.executable());
@@ -256,22 +542,319 @@ impl ShellExecutor {
             cmd.env(key, value);
         }
 
-        // Execute with timeout
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| RezCoreError::ExecutionError(format!("Failed to spawn command: {}", e)))?;
+
+        // Drain stdout/stderr concurrently with waiting on the child,
+        // rather than after, so a chatty child can't deadlock by filling
+        // its pipe buffer before exiting.
+        use tokio::io::AsyncReadExt;
+        let mut stdout_pipe = child.stdout.take();
+        let mut stderr_pipe = child.stderr.take();
+        let stdout_task = tokio::spawn(async move {
+            let mut buf = Vec::new();
+            if let Some(pipe) = stdout_pipe.as_mut() {
+                let _ = pipe.read_to_end(&mut buf).await;
+            }
+            buf
+        });
+        let stderr_task = tokio::spawn(async move {
+            let mut buf = Vec::new();
+            if let Some(pipe) = stderr_pipe.as_mut() {
+                let _ = pipe.read_to_end(&mut buf).await;
+            }
+            buf
+        });
+
+        let status = tokio::select! {
+            result = child.wait() => {
+                result.map_err(|e| RezCoreError::ExecutionError(format!("Failed to execute command: {}", e)))?
+            }
+            _ = cancel.cancelled() => {
+                Self::terminate_child(&mut child).await;
+                stdout_task.abort();
+                stderr_task.abort();
+                return Err(RezCoreError::ExecutionError("Command cancelled".to_string()));
+            }
+            _ = tokio::time::sleep(std::time::Duration::from_secs(self.timeout_seconds)) => {
+                Self::terminate_child(&mut child).await;
+                stdout_task.abort();
+                stderr_task.abort();
+                return Err(RezCoreError::ExecutionError("Command execution timeout".to_string()));
+            }
+        };
+
+        let stdout = stdout_task.await.unwrap_or_default();
+        let stderr = stderr_task.await.unwrap_or_default();
+        let execution_time_ms = start_time.elapsed().as_millis() as u64;
+
+        Ok(CommandResult {
+            exit_code: status.code().unwrap_or(-1),
+            stdout: String::from_utf8_lossy(&stdout).to_string(),
+            stderr: String::from_utf8_lossy(&stderr).to_string(),
+            execution_time_ms,
+        })
+    }
+
+    /// Same as [`Self::execute`], but feeds `input` to the spawned
+    /// shell's stdin before waiting for it to finish, for commands that
+    /// read from stdin (e.g. piping data through a filter) rather than
+    /// only taking arguments.
+    pub async fn execute_with_input(
+        &self,
+        command: &str,
+        input: ShellInput,
+    ) -> Result<CommandResult, RezCoreError> {
+        let start_time = std::time::Instant::now();
+
+        let mut cmd = AsyncCommand::new(self.shell_type.executable());
+        cmd.arg(self.shell_type.command_flag())
+            .arg(command)
+            .stdin(match input {
+                ShellInput::Inherit => Stdio::inherit(),
+                _ => Stdio::piped(),
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(ref wd) = self.working_directory {
+            cmd.current_dir(wd);
+        }
+        for (key, value) in &self.environment {
+            cmd.env(key, value);
+        }
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| RezCoreError::ExecutionError(format!("Failed to spawn command: {}", e)))?;
+
+        Self::write_stdin(&mut child, input).await?;
+
         let output = tokio::time::timeout(
             std::time::Duration::from_secs(self.timeout_seconds),
-            cmd.output(),
+            child.wait_with_output(),
         )
         .await
         .map_err(|_| RezCoreError::ExecutionError("Command execution timeout".to_string()))?
         .map_err(|e| RezCoreError::ExecutionError(format!("Failed to execute command: {}", e)))?;
 
-        let execution_time_ms = start_time.elapsed().as_millis() as u64;
-
         Ok(CommandResult {
             exit_code: output.status.code().unwrap_or(-1),
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
-            execution_time_ms,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Write `input`'s contents to `child`'s stdin, then drop the handle
+    /// to close the pipe so the child sees EOF. A no-op for
+    /// [`ShellInput::Inherit`], which never attaches a pipe in the first
+    /// place.
+    async fn write_stdin(
+        child: &mut tokio::process::Child,
+        input: ShellInput,
+    ) -> Result<(), RezCoreError> {
+        use tokio::io::AsyncWriteExt;
+
+        let bytes = match input {
+            ShellInput::Inherit => return Ok(()),
+            ShellInput::Inline(text) => text.into_bytes(),
+            ShellInput::File(path) => tokio::fs::read(&path).await.map_err(|e| {
+                RezCoreError::ExecutionError(format!(
+                    "Failed to read stdin file '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })?,
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(&bytes)
+                .await
+                .map_err(|e| RezCoreError::ExecutionError(format!("Failed to write to stdin: {}", e)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Forward a termination request to `child`: send `SIGTERM` (Unix
+    /// only — Windows has no portable equivalent for an arbitrary child)
+    /// and give it [`TERMINATION_GRACE_PERIOD`] to exit on its own,
+    /// escalating to a hard `SIGKILL` if it's still running afterwards.
+    /// Used by both the cancellation and timeout paths so neither leaks
+    /// the child process.
+    async fn terminate_child(child: &mut tokio::process::Child) {
+        #[cfg(unix)]
+        {
+            if let Some(pid) = child.id() {
+                // SAFETY: `pid` is a live child PID owned by `child`; `kill`
+                // with `SIGTERM` is a request the process may ignore and
+                // does not itself invalidate any memory.
+                unsafe {
+                    libc::kill(pid as libc::pid_t, libc::SIGTERM);
+                }
+            }
+        }
+
+        if tokio::time::timeout(TERMINATION_GRACE_PERIOD, child.wait())
+            .await
+            .is_err()
+        {
+            let _ = child.kill().await;
+            let _ = child.wait().await;
+        }
+    }
+
+    /// Run `command` through [`rez_next_shell::interp::Interpreter`]
+    /// instead of a host shell, so package `commands()` blocks behave
+    /// identically on every OS.
+    async fn execute_interpreted(&self, command: &str) -> Result<CommandResult, RezCoreError> {
+        let start_time = std::time::Instant::now();
+
+        let mut interpreter = rez_next_shell::interp::Interpreter::with_environment(
+            self.environment.clone(),
+        );
+        if let Some(ref wd) = self.working_directory {
+            interpreter.set_working_directory(wd.clone());
+        }
+
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(self.timeout_seconds),
+            interpreter.run(command),
+        )
+        .await
+        .map_err(|_| RezCoreError::ExecutionError("Command execution timeout".to_string()))??;
+
+        Ok(CommandResult {
+            exit_code: result.exit_code,
+            stdout: result.stdout,
+            stderr: result.stderr,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Execute `command`, invoking `on_line` once per line of output as
+    /// it arrives instead of buffering all of stdout/stderr until the
+    /// process exits — useful for progress reporting and live log
+    /// display on long-running builds. The final [`CommandResult`] is
+    /// still assembled from the same lines once the process finishes.
+    /// The usual `timeout_seconds` wall-clock timeout still applies;
+    /// callers that want a timeout on silence instead can track the gap
+    /// between `on_line` invocations themselves.
+    pub async fn execute_streaming(
+        &self,
+        command: &str,
+        on_line: impl FnMut(OutputLine),
+    ) -> Result<CommandResult, RezCoreError> {
+        self.execute_streaming_with_input(command, ShellInput::Inherit, on_line)
+            .await
+    }
+
+    /// Same as [`Self::execute_streaming`], but feeds `input` to the
+    /// spawned shell's stdin first, the same way [`Self::execute_with_input`]
+    /// extends [`Self::execute`].
+    pub async fn execute_streaming_with_input(
+        &self,
+        command: &str,
+        input: ShellInput,
+        mut on_line: impl FnMut(OutputLine),
+    ) -> Result<CommandResult, RezCoreError> {
+        let start_time = std::time::Instant::now();
+
+        let mut cmd = AsyncCommand::new(self.shell_type.executable());
+        cmd.arg(self.shell_type.command_flag())
+            .arg(command)
+            .stdin(match input {
+                ShellInput::Inherit => Stdio::inherit(),
+                _ => Stdio::piped(),
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(ref wd) = self.working_directory {
+            cmd.current_dir(wd);
+        }
+        for (key, value) in &self.environment {
+            cmd.env(key, value);
+        }
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| RezCoreError::ExecutionError(format!("Failed to spawn command: {}", e)))?;
+
+        Self::write_stdin(&mut child, input).await?;
+
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| RezCoreError::ExecutionError("Failed to capture stdout".to_string()))?;
+        let stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| RezCoreError::ExecutionError("Failed to capture stderr".to_string()))?;
+
+        let mut stdout_lines = BufReader::new(stdout).lines();
+        let mut stderr_lines = BufReader::new(stderr).lines();
+
+        let mut stdout_data = String::new();
+        let mut stderr_data = String::new();
+        let mut stdout_done = false;
+        let mut stderr_done = false;
+
+        let drain_lines = async {
+            while !stdout_done || !stderr_done {
+                tokio::select! {
+                    result = stdout_lines.next_line(), if !stdout_done => {
+                        match result.map_err(|e| {
+                            RezCoreError::ExecutionError(format!("Failed to read stdout: {}", e))
+                        })? {
+                            Some(line) => {
+                                stdout_data.push_str(&line);
+                                stdout_data.push('\n');
+                                on_line(OutputLine { stream: OutputStream::Stdout, line });
+                            }
+                            None => stdout_done = true,
+                        }
+                    }
+                    result = stderr_lines.next_line(), if !stderr_done => {
+                        match result.map_err(|e| {
+                            RezCoreError::ExecutionError(format!("Failed to read stderr: {}", e))
+                        })? {
+                            Some(line) => {
+                                stderr_data.push_str(&line);
+                                stderr_data.push('\n');
+                                on_line(OutputLine { stream: OutputStream::Stderr, line });
+                            }
+                            None => stderr_done = true,
+                        }
+                    }
+                }
+            }
+            Ok::<(), RezCoreError>(())
+        };
+
+        tokio::time::timeout(
+            std::time::Duration::from_secs(self.timeout_seconds),
+            drain_lines,
+        )
+        .await
+        .map_err(|_| RezCoreError::ExecutionError("Command execution timeout".to_string()))??;
+
+        let status = tokio::time::timeout(
+            std::time::Duration::from_secs(self.timeout_seconds),
+            child.wait(),
+        )
+        .await
+        .map_err(|_| RezCoreError::ExecutionError("Command execution timeout".to_string()))?
+        .map_err(|e| RezCoreError::ExecutionError(format!("Failed to execute command: {}", e)))?;
+
+        Ok(CommandResult {
+            exit_code: status.code().unwrap_or(-1),
+            stdout: stdout_data,
+            stderr: stderr_data,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
         })
     }
 
@@ -300,6 +883,216 @@ impl ShellExecutor {
         Ok(child.id().unwrap_or(0))
     }
 
+    /// Run a [`CommandGroup`]: `Sequential` pipelines run one after
+    /// another (always continuing, matching `;`); `Parallel` pipelines
+    /// all run concurrently, their results collected in the original
+    /// order once every pipeline has finished.
+    pub async fn execute_pipeline(
+        &self,
+        group: &CommandGroup,
+    ) -> Result<Vec<CommandResult>, RezCoreError> {
+        match group {
+            CommandGroup::Sequential(pipelines) => {
+                let mut results = Vec::with_capacity(pipelines.len());
+                for pipeline in pipelines {
+                    results.push(self.execute_pipeline_stages(pipeline).await?);
+                }
+                Ok(results)
+            }
+            CommandGroup::Parallel(pipelines) => {
+                let handles: Vec<_> = pipelines
+                    .iter()
+                    .cloned()
+                    .map(|pipeline| {
+                        let executor = self.clone();
+                        tokio::spawn(async move { executor.execute_pipeline_stages(&pipeline).await })
+                    })
+                    .collect();
+
+                let mut results = Vec::with_capacity(handles.len());
+                for handle in handles {
+                    let result = handle.await.map_err(|e| {
+                        RezCoreError::ExecutionError(format!("Pipeline task panicked: {}", e))
+                    })??;
+                    results.push(result);
+                }
+                Ok(results)
+            }
+        }
+    }
+
+    /// Run every stage of `pipeline`, piping each stage's stdout into the
+    /// next stage's stdin, and applying `pipeline.redirects` to the last
+    /// stage. Returns a single aggregated result the same way a real
+    /// shell pipeline reports the exit code of its last stage.
+    async fn execute_pipeline_stages(&self, pipeline: &Pipeline) -> Result<CommandResult, RezCoreError> {
+        if pipeline.commands.is_empty() {
+            return Err(RezCoreError::ExecutionError(
+                "Pipeline has no commands".to_string(),
+            ));
+        }
+
+        let start_time = std::time::Instant::now();
+        let last = pipeline.commands.len() - 1;
+        let mut children = Vec::with_capacity(pipeline.commands.len());
+
+        for (index, command) in pipeline.commands.iter().enumerate() {
+            let mut cmd = AsyncCommand::new(self.shell_type.executable());
+            cmd.arg(self.shell_type.command_flag()).arg(command);
+
+            if let Some(ref wd) = self.working_directory {
+                cmd.current_dir(wd);
+            }
+            for (key, value) in &self.environment {
+                cmd.env(key, value);
+            }
+
+            cmd.stdin(if index == 0 {
+                Stdio::null()
+            } else {
+                Stdio::piped()
+            });
+            cmd.stdout(Stdio::piped());
+            cmd.stderr(Stdio::piped());
+
+            if index == last {
+                self.apply_redirects(&mut cmd, &pipeline.redirects).await?;
+            }
+
+            let child = cmd.spawn().map_err(|e| {
+                RezCoreError::ExecutionError(format!(
+                    "Failed to spawn pipeline stage '{}': {}",
+                    command, e
+                ))
+            })?;
+            children.push(child);
+        }
+
+        // Splice each stage's stdout into the next stage's stdin
+        // concurrently, rather than buffering a whole stage's output
+        // before starting the next one.
+        let mut copy_tasks = Vec::new();
+        for i in 0..children.len() - 1 {
+            if let (Some(mut stdout), Some(mut stdin)) =
+                (children[i].stdout.take(), children[i + 1].stdin.take())
+            {
+                copy_tasks.push(tokio::spawn(async move {
+                    let _ = tokio::io::copy(&mut stdout, &mut stdin).await;
+                }));
+            }
+        }
+        for task in copy_tasks {
+            let _ = task.await;
+        }
+
+        let mut exit_code = 0;
+        let mut stderr_parts = Vec::new();
+        let mut stdout_data = String::new();
+
+        for (index, child) in children.into_iter().enumerate() {
+            let output = tokio::time::timeout(
+                std::time::Duration::from_secs(self.timeout_seconds),
+                child.wait_with_output(),
+            )
+            .await
+            .map_err(|_| RezCoreError::ExecutionError("Pipeline stage timeout".to_string()))?
+            .map_err(|e| {
+                RezCoreError::ExecutionError(format!("Pipeline stage failed: {}", e))
+            })?;
+
+            exit_code = output.status.code().unwrap_or(-1);
+            if !output.stderr.is_empty() {
+                stderr_parts.push(String::from_utf8_lossy(&output.stderr).to_string());
+            }
+            if index == last {
+                stdout_data = String::from_utf8_lossy(&output.stdout).to_string();
+            }
+        }
+
+        Ok(CommandResult {
+            exit_code,
+            stdout: stdout_data,
+            stderr: stderr_parts.join(""),
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Apply `redirects` to `cmd`. Stdout (`fd: 1`) redirects are applied
+    /// first so a trailing `2>&1` can duplicate whichever destination
+    /// stdout ended up with, matching the common `cmd > out.txt 2>&1`
+    /// idiom; redirects in the reverse order aren't specially handled.
+    async fn apply_redirects(
+        &self,
+        cmd: &mut AsyncCommand,
+        redirects: &[Redirect],
+    ) -> Result<(), RezCoreError> {
+        let mut stdout_file: Option<std::fs::File> = None;
+
+        for redirect in redirects {
+            if redirect.fd == 1 {
+                if let RedirectTarget::File(path) = &redirect.target {
+                    let file = tokio::fs::File::create(path).await.map_err(|e| {
+                        RezCoreError::ExecutionError(format!(
+                            "Failed to open '{}': {}",
+                            path.display(),
+                            e
+                        ))
+                    })?;
+                    let std_file = file.into_std().await;
+                    let clone = std_file.try_clone().map_err(|e| {
+                        RezCoreError::ExecutionError(format!("Failed to duplicate handle: {}", e))
+                    })?;
+                    cmd.stdout(Stdio::from(clone));
+                    stdout_file = Some(std_file);
+                }
+            }
+        }
+
+        for redirect in redirects {
+            if redirect.fd != 2 {
+                continue;
+            }
+            match &redirect.target {
+                RedirectTarget::File(path) => {
+                    let file = tokio::fs::File::create(path).await.map_err(|e| {
+                        RezCoreError::ExecutionError(format!(
+                            "Failed to open '{}': {}",
+                            path.display(),
+                            e
+                        ))
+                    })?;
+                    cmd.stderr(Stdio::from(file.into_std().await));
+                }
+                RedirectTarget::Fd(1) => {
+                    if let Some(ref file) = stdout_file {
+                        let clone = file.try_clone().map_err(|e| {
+                            RezCoreError::ExecutionError(format!(
+                                "Failed to duplicate handle: {}",
+                                e
+                            ))
+                        })?;
+                        cmd.stderr(Stdio::from(clone));
+                    } else {
+                        // Stdout isn't going to a file (e.g. it's piped
+                        // for capture), so there's no file descriptor to
+                        // duplicate. Stderr stays on its own pipe and is
+                        // captured separately from stdout; the two
+                        // streams are not merged.
+                        cmd.stderr(Stdio::piped());
+                    }
+                }
+                RedirectTarget::Fd(fd) => {
+                    return Err(RezCoreError::ExecutionError(format!(
+                        "Unsupported redirect: 2>&{}",
+                        fd
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Execute multiple commands in sequence
     pub async fn execute_batch(
         &self,
@@ -332,6 +1125,13 @@ impl ShellExecutor {
             )));
         }
 
+        if self.shell_type == ShellType::InterpreterShell {
+            let contents = tokio::fs::read_to_string(script_path).await.map_err(|e| {
+                RezCoreError::ExecutionError(format!("Failed to read script file: {}", e))
+            })?;
+            return self.execute_interpreted(&contents).await;
+        }
+
         let start_time = std::time::Instant::now();
 
         let mut cmd = AsyncCommand::new(self.shell_type.executable());
@@ -349,6 +1149,7 @@ impl ShellExecutor {
             ShellType::PowerShell => {
                 cmd.arg("-File").arg(script_path);
             }
+            ShellType::InterpreterShell => unreachable!("handled above"),
         }
 
         cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
@@ -383,6 +1184,13 @@ impl ShellExecutor {
 
     /// Start an interactive shell session
     pub async fn start_interactive_shell(&self) -> Result<(), RezCoreError> {
+        if self.shell_type == ShellType::InterpreterShell {
+            return Err(RezCoreError::ExecutionError(
+                "InterpreterShell has no interactive mode; use execute() for individual commands"
+                    .to_string(),
+            ));
+        }
+
         let mut cmd = AsyncCommand::new(self.shell_type.executable());
 
         // Set interactive flags
@@ -392,6 +1200,7 @@ impl ShellExecutor {
             ShellType::Fish => cmd.arg("-i"),
             ShellType::Cmd => &mut cmd, // No special flag needed
             ShellType::PowerShell => cmd.arg("-NoExit"),
+            ShellType::InterpreterShell => unreachable!("handled above"),
         };
 
         // Set working directory
@@ -431,6 +1240,16 @@ impl ShellExecutor {
 
     /// Check if a command exists in the current environment
     pub async fn command_exists(&self, command: &str) -> bool {
+        if let Some(cached) = self
+            .command_exists_cache
+            .lock()
+            .unwrap()
+            .get(command)
+            .copied()
+        {
+            return cached;
+        }
+
         let check_command = match self.shell_type {
             ShellType::Bash | ShellType::Zsh => format!("command -v {}", command),
             ShellType::Fish => format!("command -v {}", command),
@@ -438,25 +1257,72 @@ impl ShellExecutor {
             ShellType::PowerShell => {
                 format!("Get-Command {} -ErrorAction SilentlyContinue", command)
             }
+            // `which` is itself a real external binary, not shell syntax,
+            // so it's still platform-dependent even under the portable
+            // interpreter; good enough for the common Unix-like case.
+            ShellType::InterpreterShell => format!("which {}", command),
         };
 
-        match self.execute(&check_command).await {
+        let exists = match self
+            .runner
+            .run(
+                &self.shell_type,
+                &check_command,
+                self.working_directory.as_ref(),
+                &self.environment,
+                self.timeout_seconds,
+            )
+            .await
+        {
             Ok(result) => result.is_success() && !result.stdout.trim().is_empty(),
             Err(_) => false,
-        }
+        };
+
+        self.command_exists_cache
+            .lock()
+            .unwrap()
+            .insert(command.to_string(), exists);
+        exists
     }
 
-    /// Get shell information
+    /// Get shell information. The result is cached for the lifetime of
+    /// this executor, since a shell's version and executable path can't
+    /// change between calls.
     pub async fn get_shell_info(&self) -> Result<ShellInfo, RezCoreError> {
+        self.shell_info_cache
+            .get_or_try_init(|| self.probe_shell_info())
+            .await
+            .cloned()
+    }
+
+    async fn probe_shell_info(&self) -> Result<ShellInfo, RezCoreError> {
+        if self.shell_type == ShellType::InterpreterShell {
+            return Ok(ShellInfo {
+                shell_type: self.shell_type.clone(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                executable_path: "<in-process>".to_string(),
+            });
+        }
+
         let version_command = match self.shell_type {
             ShellType::Bash => "bash --version",
             ShellType::Zsh => "zsh --version",
             ShellType::Fish => "fish --version",
             ShellType::Cmd => "ver",
             ShellType::PowerShell => "$PSVersionTable.PSVersion",
+            ShellType::InterpreterShell => unreachable!("handled above"),
         };
 
-        let result = self.execute(version_command).await?;
+        let result = self
+            .runner
+            .run(
+                &self.shell_type,
+                version_command,
+                self.working_directory.as_ref(),
+                &self.environment,
+                self.timeout_seconds,
+            )
+            .await?;
 
         Ok(ShellInfo {
             shell_type: self.shell_type.clone(),
@@ -469,6 +1335,92 @@ impl ShellExecutor {
             executable_path: self.shell_type.executable().to_string(),
         })
     }
+
+    /// Run `command` once, then re-run it each time a relevant file
+    /// under `working_directory` changes, debouncing bursts of events
+    /// (e.g. a format-on-save plus the actual edit) into a single
+    /// re-run, until `cancel` fires. Borrowed from the `deno test
+    /// --watch` workflow: gives Rez users an edit-build-test loop over a
+    /// package's `commands()` block without an external file watcher.
+    /// Each re-run kills the previous in-flight one first, reusing the
+    /// same graceful-then-forceful kill path as
+    /// [`Self::execute_cancellable`]'s timeout/cancellation.
+    pub async fn watch(
+        &self,
+        command: &str,
+        opts: WatchOptions,
+        cancel: CancellationToken,
+    ) -> Result<(), RezCoreError> {
+        let watch_root = self
+            .working_directory
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<notify::Event>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if let Ok(event) = res {
+                let _ = tx.send(event);
+            }
+        })
+        .map_err(|e| RezCoreError::ExecutionError(format!("Failed to start file watcher: {}", e)))?;
+
+        notify::Watcher::watch(&mut watcher, &watch_root, notify::RecursiveMode::Recursive)
+            .map_err(|e| {
+                RezCoreError::ExecutionError(format!(
+                    "Failed to watch '{}': {}",
+                    watch_root.display(),
+                    e
+                ))
+            })?;
+
+        let mut run_cancel = CancellationToken::new();
+        let mut in_flight = tokio::spawn(self.spawn_watched_run(command, run_cancel.clone()));
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    run_cancel.cancel();
+                    let _ = in_flight.await;
+                    return Ok(());
+                }
+                event = rx.recv() => {
+                    let Some(event) = event else {
+                        return Ok(());
+                    };
+                    if !event.paths.iter().any(|p| opts.path_is_relevant(p)) {
+                        continue;
+                    }
+
+                    // Debounce: swallow any further events that arrive
+                    // within the debounce window before re-running.
+                    tokio::time::sleep(opts.debounce).await;
+                    while rx.try_recv().is_ok() {}
+
+                    run_cancel.cancel();
+                    let _ = in_flight.await;
+
+                    run_cancel = CancellationToken::new();
+                    in_flight = tokio::spawn(self.spawn_watched_run(command, run_cancel.clone()));
+                }
+            }
+        }
+    }
+
+    /// Build the future that runs `command` once under `cancel`,
+    /// ignoring its result (a failed or cancelled run under `watch`
+    /// isn't fatal to the watch loop — the next relevant change just
+    /// tries again).
+    fn spawn_watched_run(
+        &self,
+        command: &str,
+        cancel: CancellationToken,
+    ) -> impl std::future::Future<Output = ()> + Send + 'static {
+        let executor = self.clone();
+        let command = command.to_string();
+        async move {
+            let _ = executor.execute_cancellable(&command, cancel).await;
+        }
+    }
 }
 
 /// Shell information
@@ -487,3 +1439,143 @@ impl Default for ShellExecutor {
         Self::new()
     }
 }
+
+impl std::fmt::Debug for ShellExecutor {
+    // Hand-written because `runner` is a `dyn CommandRunner` trait
+    // object and doesn't implement `Debug`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShellExecutor")
+            .field("shell_type", &self.shell_type)
+            .field("working_directory", &self.working_directory)
+            .field("environment", &self.environment)
+            .field("timeout_seconds", &self.timeout_seconds)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct MockEnv {
+        vars: HashMap<String, String>,
+    }
+
+    impl MockEnv {
+        fn new(vars: &[(&str, &str)]) -> Self {
+            Self {
+                vars: vars
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            }
+        }
+    }
+
+    impl Env for MockEnv {
+        fn var(&self, key: &str) -> Option<String> {
+            self.vars.get(key).cloned()
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MockRunner {
+        responses: Mutex<HashMap<String, CommandResult>>,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl MockRunner {
+        fn new(responses: &[(&str, CommandResult)]) -> Self {
+            Self {
+                responses: Mutex::new(
+                    responses
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.clone()))
+                        .collect(),
+                ),
+                calls: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn call_count(&self) -> usize {
+            self.calls.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CommandRunner for MockRunner {
+        async fn run(
+            &self,
+            _shell_type: &ShellType,
+            command: &str,
+            _working_directory: Option<&PathBuf>,
+            _environment: &HashMap<String, String>,
+            _timeout_seconds: u64,
+        ) -> Result<CommandResult, RezCoreError> {
+            self.calls.lock().unwrap().push(command.to_string());
+            self.responses.lock().unwrap().get(command).cloned().ok_or_else(|| {
+                RezCoreError::ExecutionError(format!(
+                    "MockRunner: no response configured for '{}'",
+                    command
+                ))
+            })
+        }
+    }
+
+    fn ok_result(stdout: &str) -> CommandResult {
+        CommandResult {
+            exit_code: 0,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            execution_time_ms: 0,
+        }
+    }
+
+    #[test]
+    fn test_detect_with_env_prefers_shell_var() {
+        let env = MockEnv::new(&[("SHELL", "/usr/bin/zsh")]);
+        assert_eq!(ShellType::detect_with_env(&env), ShellType::Zsh);
+    }
+
+    #[test]
+    fn test_detect_with_env_falls_back_to_bash_on_unrecognized_shell() {
+        let env = MockEnv::new(&[("SHELL", "/usr/bin/tcsh")]);
+        if !cfg!(windows) {
+            assert_eq!(ShellType::detect_with_env(&env), ShellType::Bash);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_command_exists_uses_injected_runner_and_caches_result() {
+        let runner = Arc::new(MockRunner::new(&[("command -v git", ok_result("/usr/bin/git"))]));
+        let executor = ShellExecutor::with_shell(ShellType::Bash).with_runner(runner.clone());
+
+        assert!(executor.command_exists("git").await);
+        assert_eq!(runner.call_count(), 1);
+
+        // Second lookup is served from the cache, not the runner.
+        assert!(executor.command_exists("git").await);
+        assert_eq!(runner.call_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_command_exists_false_when_runner_errors() {
+        let runner = Arc::new(MockRunner::new(&[]));
+        let executor = ShellExecutor::with_shell(ShellType::Bash).with_runner(runner);
+
+        assert!(!executor.command_exists("nonexistent").await);
+    }
+
+    #[tokio::test]
+    async fn test_get_shell_info_caches_single_probe() {
+        let runner = Arc::new(MockRunner::new(&[("bash --version", ok_result("bash 5.2"))]));
+        let executor = ShellExecutor::with_shell(ShellType::Bash).with_runner(runner.clone());
+
+        let info = executor.get_shell_info().await.unwrap();
+        assert_eq!(info.version, "bash 5.2");
+
+        executor.get_shell_info().await.unwrap();
+        assert_eq!(runner.call_count(), 1);
+    }
+}
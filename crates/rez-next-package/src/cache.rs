@@ -0,0 +1,160 @@
+//! Binary (bincode) cache for compiled [`Package`]s, so loading the same
+//! manifest twice doesn't re-parse `package.py`/YAML/JSON the second time.
+//!
+//! Mirrors the lazy-cache pattern nenv uses for `versions.json` ->
+//! `versions.cache`: a blob keyed by the source file's mtime and size sits
+//! next to the manifest, `Package::load_cached` trusts it while that stamp
+//! still matches, and re-parses (rewriting the blob) the moment it doesn't.
+
+use crate::Package;
+use rez_next_common::RezCoreError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Bumped whenever [`CachedPackage`]'s layout changes incompatibly; a blob
+/// written by a different version is treated as a cache miss rather than
+/// an error.
+const CACHE_VERSION: u32 = 1;
+
+/// The source manifest's modification stamp, used to tell a cache blob is
+/// still fresh without re-parsing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct SourceStamp {
+    mtime_nanos: u128,
+    size: u64,
+}
+
+impl SourceStamp {
+    fn read(path: &Path) -> Result<Self, RezCoreError> {
+        let metadata = fs::metadata(path)?;
+        let mtime_nanos = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| RezCoreError::Cache(format!("Invalid mtime for {}: {}", path.display(), e)))?
+            .as_nanos();
+        Ok(Self {
+            mtime_nanos,
+            size: metadata.len(),
+        })
+    }
+}
+
+/// The on-disk bincode payload. `package` is encoded via [`Package`]'s own
+/// manual `Serialize`/`Deserialize`, which carries `config` losslessly as
+/// `ConfigValue` regardless of feature flags.
+#[derive(Serialize, Deserialize)]
+struct CachedPackage {
+    cache_version: u32,
+    stamp: SourceStamp,
+    package: Package,
+}
+
+/// Where [`Package::load_cached`] stores/looks up the compiled blob for
+/// `path`: alongside the manifest itself, as `<filename>.cache`.
+fn cache_path_for(path: &Path) -> PathBuf {
+    let mut cache_path = path.as_os_str().to_owned();
+    cache_path.push(".cache");
+    PathBuf::from(cache_path)
+}
+
+impl Package {
+    /// Load the package at `path`, preferring a fresh compiled cache blob
+    /// over re-parsing the manifest. Falls back to
+    /// [`crate::serialization::PackageSerializer::load_from_file`] on a
+    /// cache miss (absent, corrupt, wrong [`CACHE_VERSION`], or stamp
+    /// mismatch) and rewrites the cache before returning.
+    pub fn load_cached(path: &Path) -> Result<Package, RezCoreError> {
+        let stamp = SourceStamp::read(path)?;
+        let cache_path = cache_path_for(path);
+
+        if let Ok(bytes) = fs::read(&cache_path) {
+            if let Ok(cached) = bincode::deserialize::<CachedPackage>(&bytes) {
+                if cached.cache_version == CACHE_VERSION && cached.stamp == stamp {
+                    return Ok(cached.package);
+                }
+            }
+        }
+
+        let package = crate::serialization::PackageSerializer::load_from_file(path)?;
+        Self::write_cache(&cache_path, stamp, &package)?;
+        Ok(package)
+    }
+
+    /// Remove the compiled cache blob for `path`, if one exists.
+    pub fn clear_cache(path: &Path) -> Result<(), RezCoreError> {
+        let cache_path = cache_path_for(path);
+        match fs::remove_file(&cache_path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write_cache(cache_path: &Path, stamp: SourceStamp, package: &Package) -> Result<(), RezCoreError> {
+        let cached = CachedPackage {
+            cache_version: CACHE_VERSION,
+            stamp,
+            package: package.clone(),
+        };
+        let bytes = bincode::serialize(&cached)
+            .map_err(|e| RezCoreError::Cache(format!("Failed to encode package cache: {}", e)))?;
+        fs::write(cache_path, bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "python-bindings"))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_manifest(dir: &Path, contents: &str) -> PathBuf {
+        let path = dir.join("package.yaml");
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_cached_writes_and_reuses_blob() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_manifest(temp_dir.path(), "name: myapp\nversion: \"1.0.0\"\n");
+
+        let first = Package::load_cached(&path).unwrap();
+        assert_eq!(first.name, "myapp");
+        assert!(cache_path_for(&path).exists());
+
+        // Re-load should come from the cache blob rather than re-parsing;
+        // the result should still match regardless of which path was hit.
+        let second = Package::load_cached(&path).unwrap();
+        assert_eq!(second.name, first.name);
+        assert_eq!(second.version, first.version);
+    }
+
+    #[test]
+    fn test_stale_stamp_triggers_reparse() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_manifest(temp_dir.path(), "name: myapp\nversion: \"1.0.0\"\n");
+        Package::load_cached(&path).unwrap();
+
+        write_manifest(temp_dir.path(), "name: myapp\nversion: \"2.0.0\"\n");
+        let reloaded = Package::load_cached(&path).unwrap();
+        assert_eq!(reloaded.version.unwrap().as_str(), "2.0.0");
+    }
+
+    #[test]
+    fn test_clear_cache_removes_blob() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_manifest(temp_dir.path(), "name: myapp\nversion: \"1.0.0\"\n");
+        Package::load_cached(&path).unwrap();
+        assert!(cache_path_for(&path).exists());
+
+        Package::clear_cache(&path).unwrap();
+        assert!(!cache_path_for(&path).exists());
+
+        // Clearing an already-absent cache is a no-op, not an error.
+        assert!(Package::clear_cache(&path).is_ok());
+    }
+}
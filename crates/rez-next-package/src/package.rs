@@ -8,6 +8,446 @@ use rez_next_version::Version;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Comparison operator for a single [`VersionSpec`] comparator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparatorOp {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// A `major[.minor[.patch]]` version prefix, with an optional pre-release
+/// suffix (the `alpha` in `2.0.0-alpha`). Missing components are treated
+/// as unconstrained by [`ComparatorOp::Eq`] and as zero when a comparator
+/// needs a full [`Version`] to compare against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialVersion {
+    pub major: u64,
+    pub minor: Option<u64>,
+    pub patch: Option<u64>,
+    pub prerelease: Option<String>,
+}
+
+impl PartialVersion {
+    /// Parse `major[.minor[.patch]][-prerelease]`, e.g. `2023`, `1.2`,
+    /// `2.0.0-alpha`.
+    pub fn parse(s: &str) -> Result<Self, RezCoreError> {
+        let (numeric, prerelease) = match s.split_once('-') {
+            Some((numeric, pre)) => (numeric, Some(pre.to_string())),
+            None => (s, None),
+        };
+
+        let mut parts = numeric.split('.');
+        let major = parts
+            .next()
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| RezCoreError::RequirementParse(format!("Invalid version '{}'", s)))?
+            .parse::<u64>()
+            .map_err(|_| RezCoreError::RequirementParse(format!("Invalid version '{}'", s)))?;
+        let minor = parts
+            .next()
+            .map(|p| p.parse::<u64>())
+            .transpose()
+            .map_err(|_| RezCoreError::RequirementParse(format!("Invalid version '{}'", s)))?;
+        let patch = parts
+            .next()
+            .map(|p| p.parse::<u64>())
+            .transpose()
+            .map_err(|_| RezCoreError::RequirementParse(format!("Invalid version '{}'", s)))?;
+        if parts.next().is_some() {
+            return Err(RezCoreError::RequirementParse(format!(
+                "Invalid version '{}': at most major.minor.patch",
+                s
+            )));
+        }
+
+        Ok(Self {
+            major,
+            minor,
+            patch,
+            prerelease,
+        })
+    }
+
+    /// Render as a full `major.minor.patch[-prerelease]` string that
+    /// [`Version::parse`] accepts, defaulting missing components to zero.
+    pub fn to_full_version_string(&self) -> String {
+        let mut s = format!(
+            "{}.{}.{}",
+            self.major,
+            self.minor.unwrap_or(0),
+            self.patch.unwrap_or(0)
+        );
+        if let Some(ref prerelease) = self.prerelease {
+            s.push('-');
+            s.push_str(prerelease);
+        }
+        s
+    }
+
+    /// The caret (`^`) upper bound: bump the left-most non-zero component
+    /// and zero everything after it (`^1.2.3` -> `2.0.0`, `^0.2.3` ->
+    /// `0.3.0`, `^0.0.3` -> `0.0.4`).
+    fn caret_upper_bound(&self) -> PartialVersion {
+        let minor = self.minor.unwrap_or(0);
+        let patch = self.patch.unwrap_or(0);
+        if self.major > 0 {
+            PartialVersion {
+                major: self.major + 1,
+                minor: Some(0),
+                patch: Some(0),
+                prerelease: None,
+            }
+        } else if minor > 0 {
+            PartialVersion {
+                major: 0,
+                minor: Some(minor + 1),
+                patch: Some(0),
+                prerelease: None,
+            }
+        } else {
+            PartialVersion {
+                major: 0,
+                minor: Some(minor),
+                patch: Some(patch + 1),
+                prerelease: None,
+            }
+        }
+    }
+
+    /// The tilde (`~`) upper bound: bump minor if given (`~1.2` ->
+    /// `1.3.0`), else bump major (`~1` -> `2.0.0`).
+    fn tilde_upper_bound(&self) -> PartialVersion {
+        match self.minor {
+            Some(minor) => PartialVersion {
+                major: self.major,
+                minor: Some(minor + 1),
+                patch: Some(0),
+                prerelease: None,
+            },
+            None => PartialVersion {
+                major: self.major + 1,
+                minor: Some(0),
+                patch: Some(0),
+                prerelease: None,
+            },
+        }
+    }
+}
+
+/// A single `op partial_version` comparator, e.g. `>=2023` or `<2024`.
+#[derive(Debug, Clone)]
+pub struct Comparator {
+    pub op: ComparatorOp,
+    pub partial: PartialVersion,
+    /// `partial` rendered as a full `Version`, precomputed so evaluating a
+    /// comparator doesn't reparse on every call.
+    bound: Version,
+}
+
+impl Comparator {
+    fn new(op: ComparatorOp, partial: PartialVersion) -> Result<Self, RezCoreError> {
+        let bound = Version::parse(&partial.to_full_version_string())?;
+        Ok(Self { op, partial, bound })
+    }
+
+    /// Whether `version` satisfies this single comparator, reusing
+    /// [`Version`]'s ordering rather than lexical string comparison.
+    fn matches(&self, version: &Version) -> bool {
+        match self.op {
+            ComparatorOp::Gt => version > &self.bound,
+            ComparatorOp::Ge => version >= &self.bound,
+            ComparatorOp::Lt => version < &self.bound,
+            ComparatorOp::Le => version <= &self.bound,
+            // `==` only constrains the components the spec actually named,
+            // so `==2023` matches any `2023.x.y`.
+            ComparatorOp::Eq => self.eq_prefix_matches(version),
+        }
+    }
+
+    fn eq_prefix_matches(&self, version: &Version) -> bool {
+        // Re-derive the candidate's own major/minor/patch via the same
+        // parser used for `partial`, so e.g. `==2023` matches `2023.1.0`.
+        let Ok(candidate) = PartialVersion::parse(version.as_str()) else {
+            return false;
+        };
+        candidate.major == self.partial.major
+            && self
+                .partial
+                .minor
+                .map_or(true, |minor| candidate.minor == Some(minor))
+            && self
+                .partial
+                .patch
+                .map_or(true, |patch| candidate.patch == Some(patch))
+    }
+
+    /// Whether this comparator names a pre-release (e.g. `>=2.0.0-alpha`).
+    fn has_prerelease(&self) -> bool {
+        self.partial.prerelease.is_some()
+    }
+}
+
+/// A parsed version requirement: the comparators named in a
+/// `version_spec` string, ANDed together. Exposes the parsed bounds so
+/// callers can introspect them instead of re-parsing the raw string.
+#[derive(Debug, Clone)]
+pub struct VersionSpec {
+    pub comparators: Vec<Comparator>,
+}
+
+impl VersionSpec {
+    /// Parse a comma-separated list of comparators: `==`, `>=`, `<=`, `>`,
+    /// `<`, rez's trailing `X+` lower-bound, `A..B` half-open range, and
+    /// the caret/tilde shorthand `^X.Y` / `~X.Y`.
+    pub fn parse(spec: &str) -> Result<Self, RezCoreError> {
+        let mut comparators = Vec::new();
+        for part in spec.split(',') {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            comparators.extend(Self::parse_comparator_group(part)?);
+        }
+
+        if comparators.is_empty() {
+            return Err(RezCoreError::RequirementParse(format!(
+                "Empty version spec '{}'",
+                spec
+            )));
+        }
+
+        Ok(Self { comparators })
+    }
+
+    fn parse_comparator_group(part: &str) -> Result<Vec<Comparator>, RezCoreError> {
+        if let Some(rest) = part.strip_prefix(">=") {
+            Ok(vec![Comparator::new(ComparatorOp::Ge, PartialVersion::parse(rest)?)?])
+        } else if let Some(rest) = part.strip_prefix("<=") {
+            Ok(vec![Comparator::new(ComparatorOp::Le, PartialVersion::parse(rest)?)?])
+        } else if let Some(rest) = part.strip_prefix("==") {
+            Ok(vec![Comparator::new(ComparatorOp::Eq, PartialVersion::parse(rest)?)?])
+        } else if let Some(rest) = part.strip_prefix('>') {
+            Ok(vec![Comparator::new(ComparatorOp::Gt, PartialVersion::parse(rest)?)?])
+        } else if let Some(rest) = part.strip_prefix('<') {
+            Ok(vec![Comparator::new(ComparatorOp::Lt, PartialVersion::parse(rest)?)?])
+        } else if let Some(rest) = part.strip_prefix('^') {
+            let lower = PartialVersion::parse(rest)?;
+            let upper = lower.caret_upper_bound();
+            Ok(vec![
+                Comparator::new(ComparatorOp::Ge, lower)?,
+                Comparator::new(ComparatorOp::Lt, upper)?,
+            ])
+        } else if let Some(rest) = part.strip_prefix('~') {
+            let lower = PartialVersion::parse(rest)?;
+            let upper = lower.tilde_upper_bound();
+            Ok(vec![
+                Comparator::new(ComparatorOp::Ge, lower)?,
+                Comparator::new(ComparatorOp::Lt, upper)?,
+            ])
+        } else if let Some(rest) = part.strip_suffix('+') {
+            Ok(vec![Comparator::new(ComparatorOp::Ge, PartialVersion::parse(rest)?)?])
+        } else if let Some((lower, upper)) = part.split_once("..") {
+            Ok(vec![
+                Comparator::new(ComparatorOp::Ge, PartialVersion::parse(lower)?)?,
+                Comparator::new(ComparatorOp::Lt, PartialVersion::parse(upper)?)?,
+            ])
+        } else if let Some(prefix) = part.strip_suffix(".*").or_else(|| part.strip_suffix("*")) {
+            // Wildcard: `1.*` matches any `1.y.z` (bump major), `1.2.*`
+            // matches any `1.2.z` (bump minor) — the same "bump the last
+            // named component" rule as tilde, since both just widen the
+            // match to everything sharing the given prefix.
+            let prefix = prefix.trim_end_matches('.');
+            let lower = PartialVersion::parse(prefix)?;
+            let upper = lower.tilde_upper_bound();
+            Ok(vec![
+                Comparator::new(ComparatorOp::Ge, lower)?,
+                Comparator::new(ComparatorOp::Lt, upper)?,
+            ])
+        } else {
+            // Bare version with no operator: exact match, same as `==`.
+            Ok(vec![Comparator::new(ComparatorOp::Eq, PartialVersion::parse(part)?)?])
+        }
+    }
+
+    /// Whether some comparator names a pre-release of the same
+    /// major/minor/patch tuple as `version`, opting the spec in to matching
+    /// pre-release candidates of that tuple only.
+    fn admits_prerelease(&self, version: &Version) -> bool {
+        let Ok(candidate) = PartialVersion::parse(version.as_str()) else {
+            return false;
+        };
+        let tuple = |p: &PartialVersion| (p.major, p.minor.unwrap_or(0), p.patch.unwrap_or(0));
+        self.comparators
+            .iter()
+            .any(|c| c.has_prerelease() && tuple(&c.partial) == tuple(&candidate))
+    }
+
+    /// Whether `version` satisfies every comparator (ANDed), with
+    /// pre-release versions rejected unless the spec explicitly opts in
+    /// via a comparator that itself names a pre-release for the same
+    /// major/minor/patch tuple.
+    pub fn satisfied_by(&self, version: &Version) -> bool {
+        if version.is_prerelease() && !self.admits_prerelease(version) {
+            return false;
+        }
+
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+
+    /// Combine `self` and `other` into the spec that requires both to hold
+    /// (their comparators ANDed together), or `None` if the two specs'
+    /// bounds can't be satisfied by any single version (e.g. `>=2.0`
+    /// intersected with `<1.0`). `Eq` comparators are treated as a single
+    /// inclusive point for this check, which is precise for the simple
+    /// "pin vs range" conflicts this is meant to catch.
+    pub fn intersect(&self, other: &VersionSpec) -> Option<VersionSpec> {
+        let mut comparators = self.comparators.clone();
+        comparators.extend(other.comparators.iter().cloned());
+        if Self::bounds_conflict(&comparators) {
+            return None;
+        }
+        Some(VersionSpec { comparators })
+    }
+
+    /// Whether the combined lower/upper bounds implied by `comparators`
+    /// admit no version at all.
+    fn bounds_conflict(comparators: &[Comparator]) -> bool {
+        let mut lower: Option<(Version, bool)> = None;
+        let mut upper: Option<(Version, bool)> = None;
+
+        let tighten_lower = |slot: &mut Option<(Version, bool)>, candidate: (Version, bool)| {
+            *slot = Some(match slot.take() {
+                None => candidate,
+                Some(existing) if candidate.0 > existing.0 => candidate,
+                Some(existing) if candidate.0 < existing.0 => existing,
+                Some(existing) => (existing.0, existing.1 && candidate.1),
+            });
+        };
+        let tighten_upper = |slot: &mut Option<(Version, bool)>, candidate: (Version, bool)| {
+            *slot = Some(match slot.take() {
+                None => candidate,
+                Some(existing) if candidate.0 < existing.0 => candidate,
+                Some(existing) if candidate.0 > existing.0 => existing,
+                Some(existing) => (existing.0, existing.1 && candidate.1),
+            });
+        };
+
+        for c in comparators {
+            match c.op {
+                ComparatorOp::Ge => tighten_lower(&mut lower, (c.bound.clone(), true)),
+                ComparatorOp::Gt => tighten_lower(&mut lower, (c.bound.clone(), false)),
+                ComparatorOp::Le => tighten_upper(&mut upper, (c.bound.clone(), true)),
+                ComparatorOp::Lt => tighten_upper(&mut upper, (c.bound.clone(), false)),
+                ComparatorOp::Eq => {
+                    tighten_lower(&mut lower, (c.bound.clone(), true));
+                    tighten_upper(&mut upper, (c.bound.clone(), true));
+                }
+            }
+        }
+
+        match (lower, upper) {
+            (Some((lo, lo_inclusive)), Some((hi, hi_inclusive))) => {
+                if lo > hi {
+                    true
+                } else if !(lo < hi) {
+                    // Neither bound is strictly less than the other, so
+                    // (given a total order) they name the same version.
+                    !(lo_inclusive && hi_inclusive)
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+/// How [`Package::upgrade_requirements`] should pick the new version for
+/// each constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpgradeMode {
+    /// Bump only the lower bound, keeping the existing upper-bound
+    /// semantics intact (e.g. `^1.2` -> `^1.5` when `1.5` exists but `2.0`
+    /// doesn't satisfy `^1.2`).
+    Compatible,
+    /// Track the newest version available overall, regardless of the
+    /// existing upper bound (e.g. `^1.2` -> `^3.0` when `3.0` exists).
+    Latest,
+}
+
+/// One proposed edit from [`Package::upgrade_requirements`]: `name`'s
+/// constraint would change from `old_constraint` to `new_constraint` (both
+/// `None` only for a bare, unconstrained requirement, which is never
+/// rewritten).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementChange {
+    pub name: String,
+    pub old_constraint: Option<String>,
+    pub new_constraint: Option<String>,
+}
+
+/// The recognized shape of a `version_spec`, as written by the user —
+/// tracked from the raw text (rather than re-derived from parsed
+/// comparators) so the rewritten constraint preserves the author's choice
+/// of operator.
+enum ConstraintStyle {
+    Caret(String),
+    Tilde(String),
+    Wildcard(String),
+    /// `>=X` or the trailing `X+` shorthand — both open-ended lower bounds.
+    Ge(String),
+    /// `==X` (`true`) or the bare legacy `X` shorthand (`false`) — both
+    /// exact matches, rewritten back into whichever form they came from.
+    Pinned(bool, String),
+    /// A half-open range (`A..B`) or an explicit `>`/`<` bound: rewriting
+    /// these unambiguously would require guessing which side the user
+    /// wants widened, so they're left alone.
+    Unsupported,
+}
+
+fn classify_constraint(spec: &str) -> ConstraintStyle {
+    if let Some(rest) = spec.strip_prefix('^') {
+        ConstraintStyle::Caret(rest.to_string())
+    } else if let Some(rest) = spec.strip_prefix('~') {
+        ConstraintStyle::Tilde(rest.to_string())
+    } else if let Some(rest) = spec.strip_prefix(">=") {
+        ConstraintStyle::Ge(rest.to_string())
+    } else if let Some(rest) = spec.strip_suffix('+') {
+        ConstraintStyle::Ge(rest.to_string())
+    } else if let Some(rest) = spec.strip_prefix("==") {
+        ConstraintStyle::Pinned(true, rest.to_string())
+    } else if let Some(rest) = spec.strip_suffix(".*").or_else(|| spec.strip_suffix('*')) {
+        ConstraintStyle::Wildcard(rest.trim_end_matches('.').to_string())
+    } else if spec.contains("..") || spec.starts_with('>') || spec.starts_with('<') {
+        ConstraintStyle::Unsupported
+    } else {
+        // Bare legacy shorthand, e.g. "1.2": exact match.
+        ConstraintStyle::Pinned(false, spec.to_string())
+    }
+}
+
+/// Render `version` at the same `major[.minor[.patch]]` precision as
+/// `template` (so bumping `^1.2` picks a replacement like `1.5`, not
+/// `1.5.3`).
+fn render_with_precision(template: &str, version: &Version) -> Result<String, RezCoreError> {
+    let template_partial = PartialVersion::parse(template)?;
+    let candidate_partial = PartialVersion::parse(version.as_str())?;
+
+    let mut rendered = candidate_partial.major.to_string();
+    if template_partial.minor.is_some() {
+        rendered.push('.');
+        rendered.push_str(&candidate_partial.minor.unwrap_or(0).to_string());
+    }
+    if template_partial.patch.is_some() {
+        rendered.push('.');
+        rendered.push_str(&candidate_partial.patch.unwrap_or(0).to_string());
+    }
+    Ok(rendered)
+}
+
 /// Simple package requirement for basic functionality
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PackageRequirement {
@@ -38,16 +478,127 @@ impl PackageRequirement {
         }
     }
 
-    /// Parse a requirement string like "python-3.9" or "maya>=2023"
+    /// Parse a requirement string like "maya>=2023", "python-3.9+",
+    /// "python-3.6..3.9", or the legacy "python-3.9" shorthand. Dashed
+    /// package names (e.g. "open-exr") are preserved rather than having
+    /// their trailing segment mis-parsed as a version.
     pub fn parse(requirement_str: &str) -> Result<Self, RezCoreError> {
-        // Simple parsing - can be enhanced later
-        if let Some(dash_pos) = requirement_str.rfind('-') {
-            let name = requirement_str[..dash_pos].to_string();
-            let version = requirement_str[dash_pos + 1..].to_string();
-            Ok(Self::with_version(name, version))
+        let requirement_str = requirement_str.trim();
+        if requirement_str.is_empty() {
+            return Err(RezCoreError::RequirementParse(
+                "Empty requirement string".to_string(),
+            ));
+        }
+
+        // An explicit comparison operator can't appear in a package name,
+        // so its first occurrence unambiguously marks where the name ends
+        // and the version spec begins. `^`/`~` (caret/tilde shorthand) are
+        // just as unambiguous as the comparison operators.
+        if let Some(op_pos) = requirement_str.find(['>', '<', '=', '^', '~']) {
+            let name = requirement_str[..op_pos].to_string();
+            let version_spec = requirement_str[op_pos..].to_string();
+            Self::validate_name(&name)?;
+            VersionSpec::parse(&version_spec)?;
+            return Ok(Self::with_version(name, version_spec));
+        }
+
+        // rez's trailing lower-bound shorthand, `name-X.Y+`.
+        if let Some(before_plus) = requirement_str.strip_suffix('+') {
+            return match Self::split_at_valid_version(before_plus, |v| PartialVersion::parse(v).is_ok())
+            {
+                Some((name, version)) => {
+                    let version_spec = format!("{}+", version);
+                    VersionSpec::parse(&version_spec)?;
+                    Ok(Self::with_version(name, version_spec))
+                }
+                None => Err(RezCoreError::RequirementParse(format!(
+                    "Invalid requirement '{}': no valid version before '+'",
+                    requirement_str
+                ))),
+            };
+        }
+
+        // Half-open range shorthand, `name-X.Y..A.B`.
+        if requirement_str.contains("..") {
+            let is_valid_range = |v: &str| {
+                v.split_once("..")
+                    .map(|(lo, hi)| PartialVersion::parse(lo).is_ok() && PartialVersion::parse(hi).is_ok())
+                    .unwrap_or(false)
+            };
+            return match Self::split_at_valid_version(requirement_str, is_valid_range) {
+                Some((name, version)) => Ok(Self::with_version(name, version)),
+                None => Err(RezCoreError::RequirementParse(format!(
+                    "Invalid requirement '{}': no valid version range",
+                    requirement_str
+                ))),
+            };
+        }
+
+        // Legacy `name-version` shorthand: only treat the trailing segment
+        // as a version if it actually parses as one.
+        if let Some((name, version)) =
+            Self::split_at_valid_version(requirement_str, |v| PartialVersion::parse(v).is_ok())
+        {
+            return Ok(Self::with_version(name, version));
+        }
+
+        // Wildcard shorthand (`name1.*`, `name-1.2.*`): unlike the other
+        // forms this has no marker character before the version, so
+        // instead look for where the first digit starts and check that
+        // everything from there on parses as a version spec.
+        if let Some((name, version)) = Self::split_at_wildcard_version(requirement_str) {
+            return Ok(Self::with_version(name, version));
+        }
+
+        Self::validate_name(requirement_str)?;
+        Ok(Self::new(requirement_str.to_string()))
+    }
+
+    /// Split `s` into `(name, version)` at the first ascii digit, treating
+    /// a separating `-` immediately before it as consumed by the split
+    /// rather than part of the name, but only if the resulting tail is a
+    /// wildcard version spec (`1.*`, `1.2.*`) that actually parses.
+    fn split_at_wildcard_version(s: &str) -> Option<(String, String)> {
+        let digit_pos = s.find(|c: char| c.is_ascii_digit())?;
+        let name_end = if digit_pos > 0 && s.as_bytes()[digit_pos - 1] == b'-' {
+            digit_pos - 1
+        } else {
+            digit_pos
+        };
+        if name_end == 0 {
+            return None;
+        }
+        let version = &s[digit_pos..];
+        if !version.ends_with('*') {
+            return None;
+        }
+        VersionSpec::parse(version).ok()?;
+        Some((s[..name_end].to_string(), version.to_string()))
+    }
+
+    /// Split `s` at the last `-` whose trailing segment satisfies
+    /// `is_valid_version`, so dashed package names aren't mis-split when
+    /// there's no version suffix that would actually parse.
+    fn split_at_valid_version(
+        s: &str,
+        is_valid_version: impl Fn(&str) -> bool,
+    ) -> Option<(String, String)> {
+        let dash_pos = s.rfind('-')?;
+        let (name, version) = (&s[..dash_pos], &s[dash_pos + 1..]);
+        if !name.is_empty() && is_valid_version(version) {
+            Some((name.to_string(), version.to_string()))
         } else {
-            Ok(Self::new(requirement_str.to_string()))
+            None
+        }
+    }
+
+    fn validate_name(name: &str) -> Result<(), RezCoreError> {
+        if name.is_empty() {
+            return Err(RezCoreError::RequirementParse(
+                "Empty package name".to_string(),
+            ));
         }
+        Ok(())
     }
 
     /// Get the package name
@@ -60,12 +611,19 @@ impl PackageRequirement {
         self.version_spec.as_deref()
     }
 
-    /// Convert to string representation
+    /// Convert to string representation. Round-trips with `parse`: an
+    /// operator-led spec (`>=2023`, `^1.2`, `~1.2`) or a wildcard
+    /// (`1.*`) is appended directly (`maya>=2023`, `maya1.*`), while the
+    /// legacy `name-version` shorthand keeps its separating `-`.
     pub fn to_string(&self) -> String {
-        if let Some(ref version) = self.version_spec {
-            format!("{}-{}", self.name, version)
-        } else {
-            self.name.clone()
+        match &self.version_spec {
+            Some(version)
+                if version.starts_with(['>', '<', '=', '^', '~']) || version.ends_with('*') =>
+            {
+                format!("{}{}", self.name, version)
+            }
+            Some(version) => format!("{}-{}", self.name, version),
+            None => self.name.clone(),
         }
     }
 
@@ -74,22 +632,117 @@ impl PackageRequirement {
         self.to_string()
     }
 
-    /// Check if this requirement is satisfied by a version (simplified)
+    /// Check if this requirement is satisfied by a version. Parses
+    /// `version_spec` into a [`VersionSpec`] of ANDed comparators and
+    /// compares using `Version`'s ordering rather than a literal string
+    /// match, so `maya>=2023` matches `maya-2024`. An unparseable spec is
+    /// treated as unsatisfied rather than silently passing.
     pub fn satisfied_by(&self, version: &Version) -> bool {
-        // Simplified implementation - can be enhanced later
-        if let Some(ref version_spec) = self.version_spec {
-            // For now, just check if the version string matches
-            version.as_str() == version_spec
-        } else {
-            // No version constraint, always satisfied
-            true
+        match &self.version_spec {
+            Some(version_spec) => VersionSpec::parse(version_spec)
+                .map(|spec| spec.satisfied_by(version))
+                .unwrap_or(false),
+            None => true,
+        }
+    }
+}
+
+/// A dynamic value from a package's `config` override block. Mirrors the
+/// handful of types a `package.py`/`package.yaml` config assignment can
+/// actually hold, so `Package::config` round-trips through `Serialize`/
+/// `Deserialize` (and the bincode cache above) without Python present.
+/// Behind the `python-bindings` feature, [`ConfigValue::to_pyobject`] and
+/// [`ConfigValue::from_pyobject`] convert to and from Python objects, so
+/// the core model itself never stores a `PyObject` directly.
+/// `Package::from_dict` does not read a `config` entry, and `config` has
+/// no Python getter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    List(Vec<ConfigValue>),
+    Map(HashMap<String, ConfigValue>),
+}
+
+#[cfg(feature = "python-bindings")]
+impl ConfigValue {
+    /// Convert to the equivalent Python object.
+    pub fn to_pyobject(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(match self {
+            ConfigValue::Bool(b) => b.into_py(py),
+            ConfigValue::Int(i) => i.into_py(py),
+            ConfigValue::Float(f) => f.into_py(py),
+            ConfigValue::String(s) => s.into_py(py),
+            ConfigValue::List(items) => items
+                .iter()
+                .map(|item| item.to_pyobject(py))
+                .collect::<PyResult<Vec<_>>>()?
+                .into_py(py),
+            ConfigValue::Map(map) => map
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), v.to_pyobject(py)?)))
+                .collect::<PyResult<HashMap<_, _>>>()?
+                .into_py(py),
+        })
+    }
+
+    /// Convert from a Python object, rejecting anything that isn't one of
+    /// the supported dynamic shapes. Bool is checked before the numeric
+    /// types since Python `bool` also satisfies `extract::<i64>`.
+    pub fn from_pyobject(obj: &PyObject, py: Python<'_>) -> PyResult<Self> {
+        if let Ok(v) = obj.extract::<bool>(py) {
+            return Ok(ConfigValue::Bool(v));
+        }
+        if let Ok(v) = obj.extract::<i64>(py) {
+            return Ok(ConfigValue::Int(v));
+        }
+        if let Ok(v) = obj.extract::<f64>(py) {
+            return Ok(ConfigValue::Float(v));
+        }
+        if let Ok(v) = obj.extract::<String>(py) {
+            return Ok(ConfigValue::String(v));
+        }
+        if let Ok(items) = obj.extract::<Vec<PyObject>>(py) {
+            return Ok(ConfigValue::List(
+                items
+                    .iter()
+                    .map(|item| ConfigValue::from_pyobject(item, py))
+                    .collect::<PyResult<Vec<_>>>()?,
+            ));
+        }
+        if let Ok(map) = obj.extract::<HashMap<String, PyObject>>(py) {
+            return Ok(ConfigValue::Map(
+                map.iter()
+                    .map(|(k, v)| Ok((k.clone(), ConfigValue::from_pyobject(v, py)?)))
+                    .collect::<PyResult<HashMap<_, _>>>()?,
+            ));
         }
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "Unsupported config value type",
+        ))
     }
 }
 
+/// A requested operation on a package, modeled on rust-apt's `Mark`: lets
+/// resolver/CLI layers attach intent (what should happen to this package)
+/// directly to a `Package` value, so a change-set is just the packages
+/// with `marked.is_some()` rather than a separate side table.
+#[cfg_attr(feature = "python-bindings", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PackageMark {
+    Install,
+    Reinstall,
+    Remove,
+    Keep,
+    Hold,
+}
+
 /// High-performance package representation compatible with rez
 #[cfg_attr(feature = "python-bindings", pyclass)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Package {
     /// Package name
     #[cfg(feature = "python-bindings")]
@@ -132,6 +785,13 @@ pub struct Package {
     /// Private build requirements
     pub private_build_requires: Vec<String>,
 
+    /// [`PlatformGuard`] source text for entries of `requires`/
+    /// `build_requires`/`private_build_requires`, keyed by the exact
+    /// requirement string it guards. Populated from the manifest's
+    /// `{ "requires": ..., "when": ... }` form; see
+    /// [`Package::effective_requires`].
+    pub requirement_guards: HashMap<String, String>,
+
     /// Package variants
     pub variants: Vec<Vec<String>>,
 
@@ -173,13 +833,10 @@ pub struct Package {
     #[cfg(not(feature = "python-bindings"))]
     pub uuid: Option<String>,
 
-    /// Package config
-    #[cfg(feature = "python-bindings")]
-    pub config: HashMap<String, PyObject>,
-
-    /// Package config (non-Python version)
-    #[cfg(not(feature = "python-bindings"))]
-    pub config: HashMap<String, String>,
+    /// Package config overrides. Stored as [`ConfigValue`] rather than
+    /// `PyObject` so it survives `Serialize`/`Deserialize` regardless of
+    /// feature flags; the Python boundary converts on demand.
+    pub config: HashMap<String, ConfigValue>,
 
     /// Package help
     pub help: Option<String>,
@@ -228,102 +885,11 @@ pub struct Package {
 
     /// Package preprocess function
     pub preprocess: Option<String>,
-}
-
-#[cfg(feature = "python-bindings")]
-impl Clone for Package {
-    fn clone(&self) -> Self {
-        Python::with_gil(|py| {
-            let cloned_config: HashMap<String, PyObject> = self
-                .config
-                .iter()
-                .map(|(k, v)| (k.clone(), v.clone_ref(py)))
-                .collect();
-
-            Self {
-                name: self.name.clone(),
-                version: self.version.clone(),
-                description: self.description.clone(),
-                authors: self.authors.clone(),
-                requires: self.requires.clone(),
-                build_requires: self.build_requires.clone(),
-                private_build_requires: self.private_build_requires.clone(),
-                variants: self.variants.clone(),
-                tools: self.tools.clone(),
-                commands: self.commands.clone(),
-                build_command: self.build_command.clone(),
-                build_system: self.build_system.clone(),
-                pre_commands: self.pre_commands.clone(),
-                post_commands: self.post_commands.clone(),
-                pre_test_commands: self.pre_test_commands.clone(),
-                pre_build_commands: self.pre_build_commands.clone(),
-                tests: self.tests.clone(),
-                requires_rez_version: self.requires_rez_version.clone(),
-                uuid: self.uuid.clone(),
-                config: cloned_config,
-                help: self.help.clone(),
-                relocatable: self.relocatable,
-                cachable: self.cachable,
-                timestamp: self.timestamp,
-                revision: self.revision.clone(),
-                changelog: self.changelog.clone(),
-                release_message: self.release_message.clone(),
-                previous_version: self.previous_version.clone(),
-                previous_revision: self.previous_revision.clone(),
-                vcs: self.vcs.clone(),
-                format_version: self.format_version,
-                base: self.base.clone(),
-                has_plugins: self.has_plugins,
-                plugin_for: self.plugin_for.clone(),
-                hashed_variants: self.hashed_variants,
-                preprocess: self.preprocess.clone(),
-            }
-        })
-    }
-}
 
-#[cfg(not(feature = "python-bindings"))]
-impl Clone for Package {
-    fn clone(&self) -> Self {
-        Self {
-            name: self.name.clone(),
-            version: self.version.clone(),
-            description: self.description.clone(),
-            authors: self.authors.clone(),
-            requires: self.requires.clone(),
-            build_requires: self.build_requires.clone(),
-            private_build_requires: self.private_build_requires.clone(),
-            variants: self.variants.clone(),
-            tools: self.tools.clone(),
-            commands: self.commands.clone(),
-            build_command: self.build_command.clone(),
-            build_system: self.build_system.clone(),
-            pre_commands: self.pre_commands.clone(),
-            post_commands: self.post_commands.clone(),
-            pre_test_commands: self.pre_test_commands.clone(),
-            pre_build_commands: self.pre_build_commands.clone(),
-            tests: self.tests.clone(),
-            requires_rez_version: self.requires_rez_version.clone(),
-            uuid: self.uuid.clone(),
-            config: self.config.clone(),
-            help: self.help.clone(),
-            relocatable: self.relocatable,
-            cachable: self.cachable,
-            timestamp: self.timestamp,
-            revision: self.revision.clone(),
-            changelog: self.changelog.clone(),
-            release_message: self.release_message.clone(),
-            previous_version: self.previous_version.clone(),
-            previous_revision: self.previous_revision.clone(),
-            vcs: self.vcs.clone(),
-            format_version: self.format_version,
-            base: self.base.clone(),
-            has_plugins: self.has_plugins,
-            plugin_for: self.plugin_for.clone(),
-            hashed_variants: self.hashed_variants,
-            preprocess: self.preprocess.clone(),
-        }
-    }
+    /// Requested operation for this package, set via [`Package::mark`].
+    /// Not populated from manifests; resolver/CLI layers set it to build
+    /// a change-set of intended actions.
+    pub marked: Option<PackageMark>,
 }
 
 impl Serialize for Package {
@@ -332,14 +898,23 @@ impl Serialize for Package {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("Package", 24)?;
+        let mut state = serializer.serialize_struct("Package", 26)?;
         state.serialize_field("name", &self.name)?;
         state.serialize_field("version", &self.version)?;
         state.serialize_field("description", &self.description)?;
         state.serialize_field("authors", &self.authors)?;
-        state.serialize_field("requires", &self.requires)?;
-        state.serialize_field("build_requires", &self.build_requires)?;
-        state.serialize_field("private_build_requires", &self.private_build_requires)?;
+        state.serialize_field(
+            "requires",
+            &requirement_entries(&self.requires, &self.requirement_guards),
+        )?;
+        state.serialize_field(
+            "build_requires",
+            &requirement_entries(&self.build_requires, &self.requirement_guards),
+        )?;
+        state.serialize_field(
+            "private_build_requires",
+            &requirement_entries(&self.private_build_requires, &self.requirement_guards),
+        )?;
         state.serialize_field("variants", &self.variants)?;
         state.serialize_field("tools", &self.tools)?;
         state.serialize_field("commands", &self.commands)?;
@@ -352,7 +927,7 @@ impl Serialize for Package {
         state.serialize_field("tests", &self.tests)?;
         state.serialize_field("requires_rez_version", &self.requires_rez_version)?;
         state.serialize_field("uuid", &self.uuid)?;
-        // Skip config field as PyObject cannot be serialized
+        state.serialize_field("config", &self.config)?;
         state.serialize_field("help", &self.help)?;
         state.serialize_field("relocatable", &self.relocatable)?;
         state.serialize_field("cachable", &self.cachable)?;
@@ -369,6 +944,7 @@ impl Serialize for Package {
         state.serialize_field("plugin_for", &self.plugin_for)?;
         state.serialize_field("hashed_variants", &self.hashed_variants)?;
         state.serialize_field("preprocess", &self.preprocess)?;
+        state.serialize_field("marked", &self.marked)?;
         state.end()
     }
 }
@@ -403,6 +979,7 @@ impl<'de> Deserialize<'de> for Package {
             Tests,
             RequiresRezVersion,
             Uuid,
+            Config,
             Help,
             Relocatable,
             Cachable,
@@ -419,6 +996,7 @@ impl<'de> Deserialize<'de> for Package {
             PluginFor,
             HashedVariants,
             Preprocess,
+            Marked,
         }
 
         struct PackageVisitor;
@@ -441,6 +1019,7 @@ impl<'de> Deserialize<'de> for Package {
                 let mut requires = None;
                 let mut build_requires = None;
                 let mut private_build_requires = None;
+                let mut requirement_guards = HashMap::new();
                 let mut variants = None;
                 let mut tools = None;
                 let mut commands = None;
@@ -453,6 +1032,7 @@ impl<'de> Deserialize<'de> for Package {
                 let mut tests = None;
                 let mut requires_rez_version = None;
                 let mut uuid = None;
+                let mut config = None;
                 let mut help = None;
                 let mut relocatable = None;
                 let mut cachable = None;
@@ -469,6 +1049,7 @@ impl<'de> Deserialize<'de> for Package {
                 let mut plugin_for = None;
                 let mut hashed_variants = None;
                 let mut preprocess = None;
+                let mut marked = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -500,19 +1081,31 @@ impl<'de> Deserialize<'de> for Package {
                             if requires.is_some() {
                                 return Err(de::Error::duplicate_field("requires"));
                             }
-                            requires = Some(map.next_value()?);
+                            let entries: Vec<RequirementEntry> = map.next_value()?;
+                            requires = Some(split_requirement_entries(
+                                entries,
+                                &mut requirement_guards,
+                            ));
                         }
                         Field::BuildRequires => {
                             if build_requires.is_some() {
                                 return Err(de::Error::duplicate_field("build_requires"));
                             }
-                            build_requires = Some(map.next_value()?);
+                            let entries: Vec<RequirementEntry> = map.next_value()?;
+                            build_requires = Some(split_requirement_entries(
+                                entries,
+                                &mut requirement_guards,
+                            ));
                         }
                         Field::PrivateBuildRequires => {
                             if private_build_requires.is_some() {
                                 return Err(de::Error::duplicate_field("private_build_requires"));
                             }
-                            private_build_requires = Some(map.next_value()?);
+                            let entries: Vec<RequirementEntry> = map.next_value()?;
+                            private_build_requires = Some(split_requirement_entries(
+                                entries,
+                                &mut requirement_guards,
+                            ));
                         }
                         Field::Variants => {
                             if variants.is_some() {
@@ -586,6 +1179,12 @@ impl<'de> Deserialize<'de> for Package {
                             }
                             uuid = Some(map.next_value()?);
                         }
+                        Field::Config => {
+                            if config.is_some() {
+                                return Err(de::Error::duplicate_field("config"));
+                            }
+                            config = Some(map.next_value()?);
+                        }
                         Field::Help => {
                             if help.is_some() {
                                 return Err(de::Error::duplicate_field("help"));
@@ -682,6 +1281,12 @@ impl<'de> Deserialize<'de> for Package {
                             }
                             preprocess = Some(map.next_value()?);
                         }
+                        Field::Marked => {
+                            if marked.is_some() {
+                                return Err(de::Error::duplicate_field("marked"));
+                            }
+                            marked = Some(map.next_value()?);
+                        }
                     }
                 }
 
@@ -694,6 +1299,7 @@ impl<'de> Deserialize<'de> for Package {
                     requires: requires.unwrap_or_default(),
                     build_requires: build_requires.unwrap_or_default(),
                     private_build_requires: private_build_requires.unwrap_or_default(),
+                    requirement_guards,
                     variants: variants.unwrap_or_default(),
                     tools: tools.unwrap_or_default(),
                     commands: commands.unwrap_or(None),
@@ -706,7 +1312,7 @@ impl<'de> Deserialize<'de> for Package {
                     tests: tests.unwrap_or_default(),
                     requires_rez_version: requires_rez_version.unwrap_or(None),
                     uuid: uuid.unwrap_or(None),
-                    config: HashMap::new(), // Cannot deserialize PyObject
+                    config: config.unwrap_or_default(),
                     help: help.unwrap_or(None),
                     relocatable: relocatable.unwrap_or(None),
                     cachable: cachable.unwrap_or(None),
@@ -723,6 +1329,7 @@ impl<'de> Deserialize<'de> for Package {
                     plugin_for: plugin_for.unwrap_or_default(),
                     hashed_variants: hashed_variants.unwrap_or(None),
                     preprocess: preprocess.unwrap_or(None),
+                    marked: marked.unwrap_or(None),
                 })
             }
         }
@@ -747,6 +1354,7 @@ impl<'de> Deserialize<'de> for Package {
             "tests",
             "requires_rez_version",
             "uuid",
+            "config",
             "help",
             "relocatable",
             "cachable",
@@ -763,119 +1371,863 @@ impl<'de> Deserialize<'de> for Package {
             "plugin_for",
             "hashed_variants",
             "preprocess",
+            "marked",
         ];
         deserializer.deserialize_struct("Package", FIELDS, PackageVisitor)
     }
 }
 
-#[cfg(feature = "python-bindings")]
-#[pymethods]
-impl Package {
-    #[new]
-    pub fn new(name: String) -> Self {
-        Self {
-            name,
-            version: None,
-            description: None,
-            authors: Vec::new(),
-            requires: Vec::new(),
-            build_requires: Vec::new(),
-            private_build_requires: Vec::new(),
-            variants: Vec::new(),
-            tools: Vec::new(),
-            commands: None,
-            build_command: None,
-            build_system: None,
-            pre_commands: None,
-            post_commands: None,
-            pre_test_commands: None,
-            pre_build_commands: None,
-            tests: HashMap::new(),
-            requires_rez_version: None,
-            uuid: None,
-            config: HashMap::new(),
-            help: None,
-            relocatable: None,
-            cachable: None,
-            timestamp: None,
-            revision: None,
-            changelog: None,
-            release_message: None,
-            previous_version: None,
-            previous_revision: None,
-            vcs: None,
-            format_version: None,
-            base: None,
-            has_plugins: None,
-            plugin_for: Vec::new(),
-            hashed_variants: None,
-            preprocess: None,
-        }
-    }
+/// Which list a [`Requirement`] was declared in, analogous to cargo's
+/// `DepKind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequirementKind {
+    Run,
+    Build,
+    PrivateBuild,
+}
 
-    /// Get the qualified name of the package (name-version)
-    #[getter]
-    pub fn qualified_name(&self) -> String {
-        match &self.version {
-            Some(version) => format!("{}-{}", self.name, version.as_str()),
-            None => self.name.clone(),
-        }
+/// Which dependency list(s) [`Package::depends_on`]/[`Package::dependency_names`]
+/// should consider, bitflag-style so a query can span more than one scope
+/// (`DepScope::REQUIRES | DepScope::BUILD_REQUIRES`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepScope(u8);
+
+impl DepScope {
+    pub const REQUIRES: DepScope = DepScope(1 << 0);
+    pub const BUILD_REQUIRES: DepScope = DepScope(1 << 1);
+    pub const PRIVATE_BUILD_REQUIRES: DepScope = DepScope(1 << 2);
+    pub const VARIANTS: DepScope = DepScope(1 << 3);
+    pub const ALL: DepScope = DepScope(
+        Self::REQUIRES.0 | Self::BUILD_REQUIRES.0 | Self::PRIVATE_BUILD_REQUIRES.0 | Self::VARIANTS.0,
+    );
+
+    /// Whether every flag set in `other` is also set in `self`.
+    pub fn contains(self, other: DepScope) -> bool {
+        self.0 & other.0 == other.0
     }
+}
 
-    /// Get the package as an exact requirement string
-    pub fn as_exact_requirement(&self) -> String {
-        match &self.version {
-            Some(version) => format!("{}=={}", self.name, version.as_str()),
-            None => self.name.clone(),
-        }
-    }
+impl std::ops::BitOr for DepScope {
+    type Output = DepScope;
 
-    /// Check if this is a package (always true for Package)
-    #[getter]
-    pub fn is_package(&self) -> bool {
-        true
+    fn bitor(self, rhs: DepScope) -> DepScope {
+        DepScope(self.0 | rhs.0)
     }
+}
 
-    /// Check if this is a variant (always false for Package)
-    #[getter]
-    pub fn is_variant(&self) -> bool {
-        false
-    }
+/// A single requirement, carrying the [`RequirementKind`] it was declared
+/// under and whether it's a conflict ("must NOT be present") requirement,
+/// on top of the name/version-spec/weak data already in
+/// [`PackageRequirement`]. `Package` keeps storing `requires`,
+/// `build_requires`, and `private_build_requires` as plain strings (so
+/// every existing consumer of those fields keeps working unchanged);
+/// [`Package::typed_requires`] and friends parse them into this richer
+/// form on demand for callers that want to query kind/conflict status
+/// without re-parsing strings themselves.
+#[derive(Debug, Clone)]
+pub struct Requirement {
+    pub requirement: PackageRequirement,
+    pub kind: RequirementKind,
+    pub conflict: bool,
+}
 
-    /// Get the number of variants
-    #[getter]
-    pub fn num_variants(&self) -> usize {
-        self.variants.len()
+impl Requirement {
+    /// Parse a single requirement string (as stored in `Package::requires`
+    /// and friends) under the given `kind`. A leading `!` marks it as a
+    /// conflict requirement.
+    pub fn parse(s: &str, kind: RequirementKind) -> Result<Self, RezCoreError> {
+        let (conflict, rest) = match s.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let requirement = PackageRequirement::parse(rest)?;
+        Ok(Self {
+            requirement,
+            kind,
+            conflict,
+        })
     }
 
-    /// Set the package version
-    pub fn set_version(&mut self, version: Version) {
-        self.version = Some(version);
+    /// Whether `version` satisfies this requirement. For a conflict
+    /// requirement (`!name...`) the sense is inverted: it "matches" when
+    /// the version-spec does *not* admit `version`, since the requirement
+    /// exists to forbid that version from being present.
+    pub fn matches(&self, version: &Version) -> bool {
+        let admitted = self.requirement.satisfied_by(version);
+        if self.conflict {
+            !admitted
+        } else {
+            admitted
+        }
     }
 
-    /// Set the package description
-    pub fn set_description(&mut self, description: String) {
-        self.description = Some(description);
-    }
+    /// Combine `self` with `other` into the requirement that both must
+    /// satisfy, mirroring `cargo`'s dependency-unification intersection.
+    /// Returns `None` when the two disagree on package name, when either
+    /// side is a conflict (`!name...`) requirement (there's no single
+    /// "must be present and must be absent" requirement to return), or
+    /// when the resulting version bounds are unsatisfiable.
+    pub fn intersect(&self, other: &Requirement) -> Option<Requirement> {
+        if self.requirement.name != other.requirement.name {
+            return None;
+        }
+        if self.conflict || other.conflict {
+            return None;
+        }
 
-    /// Add an author
-    pub fn add_author(&mut self, author: String) {
-        self.authors.push(author);
-    }
+        let merged_spec = match (&self.requirement.version_spec, &other.requirement.version_spec) {
+            (None, None) => None,
+            (Some(spec), None) | (None, Some(spec)) => Some(spec.clone()),
+            (Some(a), Some(b)) => {
+                let a_spec = VersionSpec::parse(a).ok()?;
+                let b_spec = VersionSpec::parse(b).ok()?;
+                a_spec.intersect(&b_spec)?;
+                Some(format!("{},{}", a, b))
+            }
+        };
 
-    /// Add a requirement
-    pub fn add_requirement(&mut self, requirement: String) {
-        self.requires.push(requirement);
-    }
+        let requirement = match merged_spec {
+            Some(spec) => PackageRequirement::with_version(self.requirement.name.clone(), spec),
+            None => PackageRequirement::new(self.requirement.name.clone()),
+        };
 
-    /// Add a build requirement
-    pub fn add_build_requirement(&mut self, requirement: String) {
+        Some(Requirement {
+            requirement,
+            kind: self.kind,
+            conflict: false,
+        })
+    }
+}
+
+impl std::fmt::Display for Requirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.conflict {
+            write!(f, "!")?;
+        }
+        write!(f, "{}", self.requirement.to_string())
+    }
+}
+
+/// The environment a [`PlatformGuard`] is evaluated against, e.g.
+/// `{"platform": "windows", "arch": "x86_64"}`.
+pub type PlatformEnv = HashMap<String, String>;
+
+/// A boolean guard on a requirement, mirroring `cargo_platform::Platform`
+/// target expressions: a bare `key == "value"` predicate over the
+/// environment passed to [`Package::effective_requires`], or a
+/// `cfg(all(...))`/`cfg(any(...))`/`cfg(not(...))` combination of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlatformGuard {
+    Eq(String, String),
+    All(Vec<PlatformGuard>),
+    Any(Vec<PlatformGuard>),
+    Not(Box<PlatformGuard>),
+}
+
+impl PlatformGuard {
+    /// Parse a guard expression, with or without the outer `cfg(...)`
+    /// wrapper (`platform == "windows"` and `cfg(platform == "windows")`
+    /// are equivalent).
+    pub fn parse(expr: &str) -> Result<Self, RezCoreError> {
+        let trimmed = expr.trim();
+        let inner = match trimmed.strip_prefix("cfg(") {
+            Some(rest) => rest.strip_suffix(')').ok_or_else(|| {
+                RezCoreError::RequirementParse(format!(
+                    "Unterminated 'cfg(' in platform guard '{}'",
+                    expr
+                ))
+            })?,
+            None => trimmed,
+        };
+
+        let (guard, rest) = Self::parse_expr(inner)?;
+        if !rest.trim().is_empty() {
+            return Err(RezCoreError::RequirementParse(format!(
+                "Unexpected trailing input in platform guard '{}'",
+                expr
+            )));
+        }
+        Ok(guard)
+    }
+
+    fn parse_expr(s: &str) -> Result<(Self, &str), RezCoreError> {
+        let s = s.trim_start();
+        if let Some(rest) = s.strip_prefix("all(") {
+            let (items, rest) = Self::parse_list(rest)?;
+            return Ok((PlatformGuard::All(items), rest));
+        }
+        if let Some(rest) = s.strip_prefix("any(") {
+            let (items, rest) = Self::parse_list(rest)?;
+            return Ok((PlatformGuard::Any(items), rest));
+        }
+        if let Some(rest) = s.strip_prefix("not(") {
+            let (inner, rest) = Self::parse_expr(rest)?;
+            let rest = rest.trim_start().strip_prefix(')').ok_or_else(|| {
+                RezCoreError::RequirementParse("Expected ')' after 'not(' in platform guard".to_string())
+            })?;
+            return Ok((PlatformGuard::Not(Box::new(inner)), rest));
+        }
+        Self::parse_eq(s)
+    }
+
+    fn parse_list(s: &str) -> Result<(Vec<Self>, &str), RezCoreError> {
+        let mut items = Vec::new();
+        let mut rest = s;
+        loop {
+            let (item, next) = Self::parse_expr(rest)?;
+            items.push(item);
+            let next = next.trim_start();
+            if let Some(next) = next.strip_prefix(',') {
+                rest = next;
+                continue;
+            }
+            let next = next.strip_prefix(')').ok_or_else(|| {
+                RezCoreError::RequirementParse(
+                    "Expected ',' or ')' in platform guard list".to_string(),
+                )
+            })?;
+            return Ok((items, next));
+        }
+    }
+
+    fn parse_eq(s: &str) -> Result<(Self, &str), RezCoreError> {
+        let s = s.trim_start();
+        let key_end = s.find(|c: char| c.is_whitespace() || c == '=').ok_or_else(|| {
+            RezCoreError::RequirementParse(format!("Malformed platform guard '{}'", s))
+        })?;
+        let key = s[..key_end].trim().to_string();
+        if key.is_empty() {
+            return Err(RezCoreError::RequirementParse(
+                "Empty key in platform guard".to_string(),
+            ));
+        }
+
+        let rest = s[key_end..].trim_start();
+        let rest = rest.strip_prefix("==").ok_or_else(|| {
+            RezCoreError::RequirementParse(format!(
+                "Expected '==' in platform guard after '{}'",
+                key
+            ))
+        })?;
+        let rest = rest.trim_start();
+        let rest = rest.strip_prefix('"').ok_or_else(|| {
+            RezCoreError::RequirementParse(format!(
+                "Expected a quoted value in platform guard for '{}'",
+                key
+            ))
+        })?;
+        let end = rest.find('"').ok_or_else(|| {
+            RezCoreError::RequirementParse(format!(
+                "Unterminated quoted value in platform guard for '{}'",
+                key
+            ))
+        })?;
+        let value = rest[..end].to_string();
+        Ok((PlatformGuard::Eq(key, value), &rest[end + 1..]))
+    }
+
+    /// Evaluate this guard against `env`. A key absent from `env` never
+    /// matches (short-circuits as not-matching) rather than erroring.
+    pub fn evaluate(&self, env: &PlatformEnv) -> bool {
+        match self {
+            PlatformGuard::Eq(key, value) => env.get(key).map(|v| v == value).unwrap_or(false),
+            PlatformGuard::All(items) => items.iter().all(|g| g.evaluate(env)),
+            PlatformGuard::Any(items) => items.iter().any(|g| g.evaluate(env)),
+            PlatformGuard::Not(inner) => !inner.evaluate(env),
+        }
+    }
+}
+
+/// One entry of `requires`/`build_requires`/`private_build_requires` on the
+/// wire: either a bare requirement string, or `{ "requires": "...", "when":
+/// "..." }` carrying an optional [`PlatformGuard`] expression. Untagged so
+/// existing `package.py`/`package.yaml`/`package.json` manifests that only
+/// ever used bare strings keep loading unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RequirementEntry {
+    Guarded {
+        requires: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        when: Option<String>,
+    },
+    Bare(String),
+}
+
+/// Split deserialized `entries` back into the raw requirement strings
+/// `Package::requires`/`build_requires`/`private_build_requires` store,
+/// recording each guard (keyed by its requirement string) into `guards`.
+fn split_requirement_entries(
+    entries: Vec<RequirementEntry>,
+    guards: &mut HashMap<String, String>,
+) -> Vec<String> {
+    entries
+        .into_iter()
+        .map(|entry| match entry {
+            RequirementEntry::Bare(s) => s,
+            RequirementEntry::Guarded { requires, when: Some(when) } => {
+                guards.insert(requires.clone(), when);
+                requires
+            }
+            RequirementEntry::Guarded { requires, when: None } => requires,
+        })
+        .collect()
+}
+
+/// Render `items` back into [`RequirementEntry`] values for serialization,
+/// looking up each one's guard (if any) in `guards`.
+fn requirement_entries(items: &[String], guards: &HashMap<String, String>) -> Vec<RequirementEntry> {
+    items
+        .iter()
+        .map(|s| match guards.get(s) {
+            Some(when) => RequirementEntry::Guarded {
+                requires: s.clone(),
+                when: Some(when.clone()),
+            },
+            None => RequirementEntry::Bare(s.clone()),
+        })
+        .collect()
+}
+
+impl Package {
+    /// Parse `requires` into [`Requirement`]s tagged [`RequirementKind::Run`].
+    pub fn typed_requires(&self) -> Result<Vec<Requirement>, RezCoreError> {
+        self.requires
+            .iter()
+            .map(|s| Requirement::parse(s, RequirementKind::Run))
+            .collect()
+    }
+
+    /// Parse `build_requires` into [`Requirement`]s tagged
+    /// [`RequirementKind::Build`].
+    pub fn typed_build_requires(&self) -> Result<Vec<Requirement>, RezCoreError> {
+        self.build_requires
+            .iter()
+            .map(|s| Requirement::parse(s, RequirementKind::Build))
+            .collect()
+    }
+
+    /// Whether this package's own version satisfies `req`. Returns `false`
+    /// (rather than erroring) when this package has no version, since an
+    /// unversioned package can't be said to satisfy any version constraint.
+    pub fn satisfies(&self, req: &Requirement) -> bool {
+        match &self.version {
+            Some(version) => self.name == req.requirement.name && req.matches(version),
+            None => false,
+        }
+    }
+
+    /// Whether `self` and `other`'s marks are incompatible, e.g. one side
+    /// marked [`PackageMark::Remove`] while the other wants it installed,
+    /// reinstalled, or held. Only meaningful when `self` and `other` are
+    /// the same name/version; different packages never conflict here.
+    pub fn conflicts_with_mark(&self, other: &Package) -> bool {
+        if self.name != other.name || self.version != other.version {
+            return false;
+        }
+        match (self.marked, other.marked) {
+            (Some(a), Some(b)) if a != b => matches!(
+                (a, b),
+                (PackageMark::Install, PackageMark::Remove)
+                    | (PackageMark::Remove, PackageMark::Install)
+                    | (PackageMark::Reinstall, PackageMark::Remove)
+                    | (PackageMark::Remove, PackageMark::Reinstall)
+                    | (PackageMark::Hold, PackageMark::Install)
+                    | (PackageMark::Install, PackageMark::Hold)
+                    | (PackageMark::Hold, PackageMark::Remove)
+                    | (PackageMark::Remove, PackageMark::Hold)
+                    | (PackageMark::Hold, PackageMark::Reinstall)
+                    | (PackageMark::Reinstall, PackageMark::Hold)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Parse `private_build_requires` into [`Requirement`]s tagged
+    /// [`RequirementKind::PrivateBuild`].
+    pub fn typed_private_build_requires(&self) -> Result<Vec<Requirement>, RezCoreError> {
+        self.private_build_requires
+            .iter()
+            .map(|s| Requirement::parse(s, RequirementKind::PrivateBuild))
+            .collect()
+    }
+
+    /// Alias for [`Self::typed_requires`] under the name this crate's
+    /// dependency-introspection callers (e.g. [`Self::dependency_names`])
+    /// expect.
+    pub fn parsed_requires(&self) -> Result<Vec<Requirement>, RezCoreError> {
+        self.typed_requires()
+    }
+
+    /// Alias for [`Self::typed_build_requires`].
+    pub fn parsed_build_requires(&self) -> Result<Vec<Requirement>, RezCoreError> {
+        self.typed_build_requires()
+    }
+
+    /// Alias for [`Self::typed_private_build_requires`].
+    pub fn parsed_private_build_requires(&self) -> Result<Vec<Requirement>, RezCoreError> {
+        self.typed_private_build_requires()
+    }
+
+    /// Parse every requirement string across all [`Self::variants`] into
+    /// [`Requirement`]s tagged [`RequirementKind::Run`], one `Vec` per
+    /// variant in declaration order.
+    pub fn parsed_variant_requires(&self) -> Result<Vec<Vec<Requirement>>, RezCoreError> {
+        self.variants
+            .iter()
+            .map(|variant| {
+                variant
+                    .iter()
+                    .map(|s| Requirement::parse(s, RequirementKind::Run))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Whether this package depends on `name` within `scope`, i.e. whether
+    /// `name` appears as the bare package name of a requirement in any of
+    /// the dependency lists `scope` selects.
+    pub fn depends_on(&self, name: &str, scope: DepScope) -> bool {
+        self.dependency_names(scope).iter().any(|n| n == name)
+    }
+
+    /// The bare package names referenced across the dependency lists
+    /// `scope` selects. An unparseable requirement string is skipped
+    /// rather than failing the whole query.
+    pub fn dependency_names(&self, scope: DepScope) -> Vec<String> {
+        let names_in = |reqs: &[String]| -> Vec<String> {
+            reqs.iter()
+                .filter_map(|s| PackageRequirement::parse(s).ok())
+                .map(|r| r.name().to_string())
+                .collect()
+        };
+
+        let mut names = Vec::new();
+        if scope.contains(DepScope::REQUIRES) {
+            names.extend(names_in(&self.requires));
+        }
+        if scope.contains(DepScope::BUILD_REQUIRES) {
+            names.extend(names_in(&self.build_requires));
+        }
+        if scope.contains(DepScope::PRIVATE_BUILD_REQUIRES) {
+            names.extend(names_in(&self.private_build_requires));
+        }
+        if scope.contains(DepScope::VARIANTS) {
+            for variant in &self.variants {
+                names.extend(names_in(variant));
+            }
+        }
+        names
+    }
+
+    /// A `name>=<version><<next-major>` requirement string, cargo
+    /// `^`-style: matches anything not older than this package's own
+    /// version but sharing its major component (or, when major is `0`,
+    /// its minor component, following cargo's zero-major convention).
+    /// Returns `None` when this package has no version, or its version
+    /// string isn't a plain `major[.minor[.patch]]` [`PartialVersion`].
+    /// The generated `>=`/`<` range already excludes pre-releases unless
+    /// this package's own version carries one, since that's how
+    /// [`VersionSpec`] comparators match.
+    pub fn as_caret_requirement(&self) -> Option<String> {
+        let version = self.version.as_ref()?;
+        let partial = PartialVersion::parse(version.as_str()).ok()?;
+        let upper = partial.caret_upper_bound();
+        Some(format!(
+            "{}>={}<{}",
+            self.name,
+            version.as_str(),
+            upper.to_full_version_string()
+        ))
+    }
+
+    /// A `name>=<version><<next-segment>` requirement string, cargo
+    /// `~`-style: matches anything not older than this package's own
+    /// version but sharing its major.minor (or just major, if this
+    /// package's version has no minor component). Returns `None` under
+    /// the same conditions as [`Self::as_caret_requirement`].
+    pub fn as_compatible_requirement(&self) -> Option<String> {
+        let version = self.version.as_ref()?;
+        let partial = PartialVersion::parse(version.as_str()).ok()?;
+        let upper = partial.tilde_upper_bound();
+        Some(format!(
+            "{}>={}<{}",
+            self.name,
+            version.as_str(),
+            upper.to_full_version_string()
+        ))
+    }
+
+    /// `requires` filtered down to the requirements whose [`PlatformGuard`]
+    /// (if any, set via the `{ "requires": ..., "when": ... }` manifest
+    /// form) evaluates true against `env`. Requirements with no guard are
+    /// always included. Returns an error if a stored guard fails to parse.
+    pub fn effective_requires(&self, env: &PlatformEnv) -> Result<Vec<Requirement>, RezCoreError> {
+        self.requires
+            .iter()
+            .filter_map(|raw| match self.requirement_guards.get(raw) {
+                Some(guard_src) => match PlatformGuard::parse(guard_src) {
+                    Ok(guard) if guard.evaluate(env) => {
+                        Some(Requirement::parse(raw, RequirementKind::Run))
+                    }
+                    Ok(_) => None,
+                    Err(e) => Some(Err(e)),
+                },
+                None => Some(Requirement::parse(raw, RequirementKind::Run)),
+            })
+            .collect()
+    }
+
+    /// Load a package from a `package.yaml`-style YAML string, running the
+    /// same validation [`crate::serialization::PackageSerializer`] does.
+    pub fn from_yaml(content: &str) -> Result<Package, RezCoreError> {
+        crate::serialization::PackageSerializer::load_from_string(
+            content,
+            crate::serialization::PackageFormat::Yaml,
+        )
+    }
+
+    /// Load a package from a `package.json`-style JSON string.
+    pub fn from_json(content: &str) -> Result<Package, RezCoreError> {
+        crate::serialization::PackageSerializer::load_from_string(
+            content,
+            crate::serialization::PackageFormat::Json,
+        )
+    }
+
+    /// Load a package from `path`, dispatching on its extension
+    /// (`package.py`/`package.yaml`/`package.json`).
+    pub fn load(path: &std::path::Path) -> Result<Package, RezCoreError> {
+        crate::serialization::PackageSerializer::load_from_file(path)
+    }
+
+    /// Serialize to a `serde_json::Value`, the non-Python equivalent of the
+    /// `#[cfg(feature = "python-bindings")]` `from_dict`'s counterpart.
+    pub fn to_dict(&self) -> Result<serde_json::Value, RezCoreError> {
+        serde_json::to_value(self).map_err(RezCoreError::from)
+    }
+
+    /// Serialize to a `package.yaml`-style YAML string.
+    pub fn to_yaml(&self) -> Result<String, RezCoreError> {
+        serde_yaml::to_string(self)
+            .map_err(|e| RezCoreError::PackageParse(format!("Failed to serialize YAML: {}", e)))
+    }
+
+    /// Serialize to a `package.json`-style JSON string.
+    pub fn to_json(&self) -> Result<String, RezCoreError> {
+        serde_json::to_string_pretty(self).map_err(RezCoreError::from)
+    }
+
+    /// Rewrite each entry in `requires`/`build_requires` to track the
+    /// newest version `available_versions` reports for it, `cargo
+    /// upgrade`-style. The original operator (`^`, `~`, `>=`, or a `.*`
+    /// wildcard) is preserved, while a trailing `X+` lower bound is
+    /// rewritten in the `>=Y` form; half-open ranges and explicit `>`/`<` bounds are left
+    /// alone since widening them unambiguously isn't possible, and `==`
+    /// (or the bare legacy shorthand) is left alone unless `force_pinned`
+    /// is set. With `dry_run` set, `requires`/`build_requires` are left
+    /// untouched and only the proposed [`RequirementChange`]s are returned.
+    pub fn upgrade_requirements(
+        &mut self,
+        available_versions: &dyn Fn(&str) -> Vec<Version>,
+        mode: UpgradeMode,
+        dry_run: bool,
+        force_pinned: bool,
+    ) -> Result<Vec<RequirementChange>, RezCoreError> {
+        let mut changes = Self::upgrade_field(
+            &mut self.requires,
+            available_versions,
+            mode,
+            dry_run,
+            force_pinned,
+        )?;
+        changes.extend(Self::upgrade_field(
+            &mut self.build_requires,
+            available_versions,
+            mode,
+            dry_run,
+            force_pinned,
+        )?);
+        Ok(changes)
+    }
+
+    fn upgrade_field(
+        field: &mut [String],
+        available_versions: &dyn Fn(&str) -> Vec<Version>,
+        mode: UpgradeMode,
+        dry_run: bool,
+        force_pinned: bool,
+    ) -> Result<Vec<RequirementChange>, RezCoreError> {
+        let mut changes = Vec::new();
+
+        for entry in field.iter_mut() {
+            let req = PackageRequirement::parse(entry)?;
+            let Some(version_spec) = req.version_spec.clone() else {
+                continue;
+            };
+
+            let Some(new_version_spec) = Self::upgraded_version_spec(
+                &req.name,
+                &version_spec,
+                available_versions,
+                mode,
+                force_pinned,
+            )?
+            else {
+                continue;
+            };
+
+            if new_version_spec == version_spec {
+                continue;
+            }
+
+            changes.push(RequirementChange {
+                name: req.name.clone(),
+                old_constraint: Some(version_spec),
+                new_constraint: Some(new_version_spec.clone()),
+            });
+
+            if !dry_run {
+                *entry = PackageRequirement::with_version(req.name.clone(), new_version_spec)
+                    .to_string();
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Compute the upgraded `version_spec` for a single requirement, or
+    /// `None` if its style isn't one `upgrade_requirements` knows how to
+    /// rewrite, or no candidate version qualifies.
+    fn upgraded_version_spec(
+        name: &str,
+        version_spec: &str,
+        available_versions: &dyn Fn(&str) -> Vec<Version>,
+        mode: UpgradeMode,
+        force_pinned: bool,
+    ) -> Result<Option<String>, RezCoreError> {
+        let style = classify_constraint(version_spec);
+        if matches!(style, ConstraintStyle::Unsupported) {
+            return Ok(None);
+        }
+        if matches!(style, ConstraintStyle::Pinned(..)) && !force_pinned {
+            return Ok(None);
+        }
+
+        let mut candidates = available_versions(name);
+        candidates.sort_by(|a, b| b.cmp(a));
+
+        let original_spec = VersionSpec::parse(version_spec).ok();
+
+        let pick_newest_overall = |lower_text: &str| {
+            let admits_prerelease = PartialVersion::parse(lower_text)
+                .map(|p| p.prerelease.is_some())
+                .unwrap_or(false);
+            candidates
+                .iter()
+                .find(|v| admits_prerelease || !v.is_prerelease())
+        };
+
+        let pick_still_compatible = || {
+            original_spec
+                .as_ref()
+                .and_then(|spec| candidates.iter().find(|v| spec.satisfied_by(v)))
+        };
+
+        Ok(match style {
+            ConstraintStyle::Pinned(has_equals, template) => candidates
+                .first()
+                .map(|newest| render_with_precision(&template, newest))
+                .transpose()?
+                .map(|rendered| {
+                    if has_equals {
+                        format!("=={}", rendered)
+                    } else {
+                        rendered
+                    }
+                }),
+            ConstraintStyle::Ge(lower_text) => {
+                let chosen = match mode {
+                    UpgradeMode::Compatible => pick_still_compatible().or_else(|| pick_newest_overall(&lower_text)),
+                    UpgradeMode::Latest => pick_newest_overall(&lower_text),
+                };
+                chosen
+                    .map(|v| render_with_precision(&lower_text, v))
+                    .transpose()?
+                    .map(|rendered| format!(">={}", rendered))
+            }
+            ConstraintStyle::Caret(lower_text) => {
+                let chosen = match mode {
+                    UpgradeMode::Compatible => pick_still_compatible(),
+                    UpgradeMode::Latest => pick_newest_overall(&lower_text),
+                };
+                chosen
+                    .map(|v| render_with_precision(&lower_text, v))
+                    .transpose()?
+                    .map(|rendered| format!("^{}", rendered))
+            }
+            ConstraintStyle::Tilde(lower_text) => {
+                let chosen = match mode {
+                    UpgradeMode::Compatible => pick_still_compatible(),
+                    UpgradeMode::Latest => pick_newest_overall(&lower_text),
+                };
+                chosen
+                    .map(|v| render_with_precision(&lower_text, v))
+                    .transpose()?
+                    .map(|rendered| format!("~{}", rendered))
+            }
+            ConstraintStyle::Wildcard(lower_text) => {
+                let chosen = match mode {
+                    UpgradeMode::Compatible => pick_still_compatible(),
+                    UpgradeMode::Latest => pick_newest_overall(&lower_text),
+                };
+                chosen
+                    .map(|v| render_with_precision(&lower_text, v))
+                    .transpose()?
+                    .map(|rendered| format!("{}.*", rendered))
+            }
+            ConstraintStyle::Unsupported => unreachable!("handled above"),
+        })
+    }
+}
+
+#[cfg(feature = "python-bindings")]
+#[pymethods]
+impl Package {
+    #[new]
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            version: None,
+            description: None,
+            authors: Vec::new(),
+            requires: Vec::new(),
+            build_requires: Vec::new(),
+            private_build_requires: Vec::new(),
+            requirement_guards: HashMap::new(),
+            variants: Vec::new(),
+            tools: Vec::new(),
+            commands: None,
+            build_command: None,
+            build_system: None,
+            pre_commands: None,
+            post_commands: None,
+            pre_test_commands: None,
+            pre_build_commands: None,
+            tests: HashMap::new(),
+            requires_rez_version: None,
+            uuid: None,
+            config: HashMap::new(),
+            help: None,
+            relocatable: None,
+            cachable: None,
+            timestamp: None,
+            revision: None,
+            changelog: None,
+            release_message: None,
+            previous_version: None,
+            previous_revision: None,
+            vcs: None,
+            format_version: None,
+            base: None,
+            has_plugins: None,
+            plugin_for: Vec::new(),
+            hashed_variants: None,
+            preprocess: None,
+            marked: None,
+        }
+    }
+
+    /// Get the qualified name of the package (name-version)
+    #[getter]
+    pub fn qualified_name(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}-{}", self.name, version.as_str()),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Get the package as an exact requirement string
+    pub fn as_exact_requirement(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}=={}", self.name, version.as_str()),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Check if this is a package (always true for Package)
+    #[getter]
+    pub fn is_package(&self) -> bool {
+        true
+    }
+
+    /// Check if this is a variant (always false for Package)
+    #[getter]
+    pub fn is_variant(&self) -> bool {
+        false
+    }
+
+    /// Get the number of variants
+    #[getter]
+    pub fn num_variants(&self) -> usize {
+        self.variants.len()
+    }
+
+    /// Set the package version
+    pub fn set_version(&mut self, version: Version) {
+        self.version = Some(version);
+    }
+
+    /// Set the package description
+    pub fn set_description(&mut self, description: String) {
+        self.description = Some(description);
+    }
+
+    /// Add an author
+    pub fn add_author(&mut self, author: String) {
+        self.authors.push(author);
+    }
+
+    /// Add a requirement, validating `requirement` parses as a
+    /// [`PackageRequirement`] before storing it rather than pushing
+    /// unparseable text that would only fail later, at resolve time.
+    pub fn add_requirement(&mut self, requirement: String) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
+        self.requires.push(requirement);
+        Ok(())
+    }
+
+    /// Add a build requirement, validated the same way as [`Self::add_requirement`].
+    pub fn add_build_requirement(&mut self, requirement: String) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
         self.build_requires.push(requirement);
+        Ok(())
     }
 
-    /// Add a private build requirement
-    pub fn add_private_build_requirement(&mut self, requirement: String) {
+    /// Add a private build requirement, validated the same way as
+    /// [`Self::add_requirement`].
+    pub fn add_private_build_requirement(&mut self, requirement: String) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
         self.private_build_requires.push(requirement);
+        Ok(())
+    }
+
+    /// Add a `requires` entry guarded by a [`PlatformGuard`] marker
+    /// expression (`platform == "windows"`, `cfg(all(...))`, ...), so it
+    /// only takes effect under environments where the guard evaluates
+    /// true; see [`Package::effective_requires`]. Both `requirement` and
+    /// `marker` are validated up front rather than stored unparsed.
+    pub fn add_conditional_requirement(
+        &mut self,
+        requirement: String,
+        marker: String,
+    ) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
+        PlatformGuard::parse(&marker)?;
+        self.requirement_guards
+            .insert(requirement.clone(), marker);
+        self.requires.push(requirement);
+        Ok(())
     }
 
     /// Add a variant
@@ -893,6 +2245,18 @@ impl Package {
         self.commands = Some(commands);
     }
 
+    /// Mark this package with a requested operation (install/remove/etc.),
+    /// mirroring rust-apt's `Mark`.
+    pub fn mark(&mut self, mark: PackageMark) {
+        self.marked = Some(mark);
+    }
+
+    /// The package's currently marked operation, if any.
+    #[getter]
+    pub fn marked_state(&self) -> Option<PackageMark> {
+        self.marked
+    }
+
     /// Get string representation
     fn __str__(&self) -> String {
         self.qualified_name()
@@ -921,6 +2285,7 @@ impl Package {
             requires: Vec::new(),
             build_requires: Vec::new(),
             private_build_requires: Vec::new(),
+            requirement_guards: HashMap::new(),
             variants: Vec::new(),
             tools: Vec::new(),
             commands: None,
@@ -950,6 +2315,7 @@ impl Package {
             plugin_for: Vec::new(),
             hashed_variants: None,
             preprocess: None,
+            marked: None,
         }
     }
 
@@ -999,19 +2365,46 @@ impl Package {
         self.authors.push(author);
     }
 
-    /// Add a requirement
-    pub fn add_requirement(&mut self, requirement: String) {
+    /// Add a requirement, validating `requirement` parses as a
+    /// [`PackageRequirement`] before storing it rather than pushing
+    /// unparseable text that would only fail later, at resolve time.
+    pub fn add_requirement(&mut self, requirement: String) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
         self.requires.push(requirement);
+        Ok(())
     }
 
-    /// Add a build requirement
-    pub fn add_build_requirement(&mut self, requirement: String) {
+    /// Add a build requirement, validated the same way as [`Self::add_requirement`].
+    pub fn add_build_requirement(&mut self, requirement: String) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
         self.build_requires.push(requirement);
+        Ok(())
     }
 
-    /// Add a private build requirement
-    pub fn add_private_build_requirement(&mut self, requirement: String) {
+    /// Add a private build requirement, validated the same way as
+    /// [`Self::add_requirement`].
+    pub fn add_private_build_requirement(&mut self, requirement: String) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
         self.private_build_requires.push(requirement);
+        Ok(())
+    }
+
+    /// Add a `requires` entry guarded by a [`PlatformGuard`] marker
+    /// expression (`platform == "windows"`, `cfg(all(...))`, ...), so it
+    /// only takes effect under environments where the guard evaluates
+    /// true; see [`Package::effective_requires`]. Both `requirement` and
+    /// `marker` are validated up front rather than stored unparsed.
+    pub fn add_conditional_requirement(
+        &mut self,
+        requirement: String,
+        marker: String,
+    ) -> Result<(), RezCoreError> {
+        PackageRequirement::parse(&requirement)?;
+        PlatformGuard::parse(&marker)?;
+        self.requirement_guards
+            .insert(requirement.clone(), marker);
+        self.requires.push(requirement);
+        Ok(())
     }
 
     /// Add a variant
@@ -1029,6 +2422,17 @@ impl Package {
         self.commands = Some(commands);
     }
 
+    /// Mark this package with a requested operation (install/remove/etc.),
+    /// mirroring rust-apt's `Mark`.
+    pub fn mark(&mut self, mark: PackageMark) {
+        self.marked = Some(mark);
+    }
+
+    /// The package's currently marked operation, if any.
+    pub fn marked_state(&self) -> Option<PackageMark> {
+        self.marked
+    }
+
     /// Validate the package definition
     pub fn validate(&self) -> Result<(), RezCoreError> {
         // Check required fields
@@ -1060,39 +2464,40 @@ impl Package {
             }
         }
 
-        // Validate requirements format
+        // Validate requirements by actually parsing them, not just
+        // checking for emptiness, so a malformed constraint (e.g.
+        // "maya>=>2023") fails loudly at load time instead of silently
+        // passing through.
         for req in &self.requires {
-            if req.is_empty() {
-                return Err(RezCoreError::PackageParse(
-                    "Requirement cannot be empty".to_string(),
-                ));
-            }
+            PackageRequirement::parse(req).map_err(|e| {
+                RezCoreError::PackageParse(format!("Invalid requirement '{}': {}", req, e))
+            })?;
         }
 
         for req in &self.build_requires {
-            if req.is_empty() {
-                return Err(RezCoreError::PackageParse(
-                    "Build requirement cannot be empty".to_string(),
-                ));
-            }
+            PackageRequirement::parse(req).map_err(|e| {
+                RezCoreError::PackageParse(format!("Invalid build requirement '{}': {}", req, e))
+            })?;
         }
 
         for req in &self.private_build_requires {
-            if req.is_empty() {
-                return Err(RezCoreError::PackageParse(
-                    "Private build requirement cannot be empty".to_string(),
-                ));
-            }
+            PackageRequirement::parse(req).map_err(|e| {
+                RezCoreError::PackageParse(format!(
+                    "Invalid private build requirement '{}': {}",
+                    req, e
+                ))
+            })?;
         }
 
         // Validate variants
         for variant in &self.variants {
             for req in variant {
-                if req.is_empty() {
-                    return Err(RezCoreError::PackageParse(
-                        "Variant requirement cannot be empty".to_string(),
-                    ));
-                }
+                PackageRequirement::parse(req).map_err(|e| {
+                    RezCoreError::PackageParse(format!(
+                        "Invalid variant requirement '{}': {}",
+                        req, e
+                    ))
+                })?;
             }
         }
 
@@ -1239,3 +2644,719 @@ impl Package {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod prerelease_matching_tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_requirement_matches_stable_candidate() {
+        let req = PackageRequirement::with_version("maya".to_string(), ">=2023".to_string());
+        let candidate = Version::parse("2024").unwrap();
+        assert!(req.satisfied_by(&candidate));
+    }
+
+    #[test]
+    fn test_stable_requirement_rejects_prerelease_candidate() {
+        // A plain `>=2023` requirement must not silently resolve to a
+        // pre-release of a later version, even though 2024.0.0-rc1's
+        // numeric parts are in range.
+        let req = PackageRequirement::with_version("maya".to_string(), ">=2023".to_string());
+        let candidate = Version::parse("2024.0.0-rc1").unwrap();
+        assert!(!req.satisfied_by(&candidate));
+    }
+
+    #[test]
+    fn test_explicit_prerelease_opts_in_for_matching_tuple() {
+        let req =
+            PackageRequirement::with_version("maya".to_string(), ">=2024.0.0-alpha".to_string());
+        let candidate = Version::parse("2024.0.0-rc1").unwrap();
+        assert!(req.satisfied_by(&candidate));
+    }
+
+    #[test]
+    fn test_explicit_prerelease_does_not_admit_other_major_minor_patch() {
+        // Opting in to pre-releases for 2024.0.0 shouldn't also admit a
+        // pre-release of an unrelated 2025.0.0.
+        let req =
+            PackageRequirement::with_version("maya".to_string(), ">=2024.0.0-alpha".to_string());
+        let candidate = Version::parse("2025.0.0-rc1").unwrap();
+        assert!(!req.satisfied_by(&candidate));
+    }
+}
+
+#[cfg(test)]
+mod requirement_parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_preserves_dashed_name_with_no_version() {
+        let req = PackageRequirement::parse("open-exr").unwrap();
+        assert_eq!(req.name, "open-exr");
+        assert_eq!(req.version_spec, None);
+    }
+
+    #[test]
+    fn test_parse_operator_form() {
+        let req = PackageRequirement::parse("maya>=2023").unwrap();
+        assert_eq!(req.name, "maya");
+        assert_eq!(req.version_spec.as_deref(), Some(">=2023"));
+    }
+
+    #[test]
+    fn test_parse_trailing_plus_lower_bound() {
+        let req = PackageRequirement::parse("python-3.9+").unwrap();
+        assert_eq!(req.name, "python");
+        assert_eq!(req.version_spec.as_deref(), Some("3.9+"));
+    }
+
+    #[test]
+    fn test_parse_half_open_range() {
+        let req = PackageRequirement::parse("python-3.6..3.9").unwrap();
+        assert_eq!(req.name, "python");
+        assert_eq!(req.version_spec.as_deref(), Some("3.6..3.9"));
+    }
+
+    #[test]
+    fn test_parse_legacy_name_version_shorthand() {
+        let req = PackageRequirement::parse("python-3.9").unwrap();
+        assert_eq!(req.name, "python");
+        assert_eq!(req.version_spec.as_deref(), Some("3.9"));
+    }
+
+    #[test]
+    fn test_parse_round_trips_through_to_string() {
+        for s in ["maya>=2023", "open-exr", "python-3.9+", "python-3.6..3.9"] {
+            let req = PackageRequirement::parse(s).unwrap();
+            assert_eq!(req.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_empty_string() {
+        assert!(PackageRequirement::parse("").is_err());
+    }
+}
+
+#[cfg(test)]
+mod typed_requirement_tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_requires_parses_kind_and_conflict() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya>=2023".to_string(), "!python-2".to_string()];
+
+        let typed = package.typed_requires().unwrap();
+        assert_eq!(typed.len(), 2);
+        assert_eq!(typed[0].kind, RequirementKind::Run);
+        assert!(!typed[0].conflict);
+        assert_eq!(typed[0].requirement.name, "maya");
+
+        assert!(typed[1].conflict);
+        assert_eq!(typed[1].requirement.name, "python");
+    }
+
+    #[test]
+    fn test_typed_build_requires_tagged_with_build_kind() {
+        let mut package = Package::new("myapp".to_string());
+        package.build_requires = vec!["cmake-3.20".to_string()];
+
+        let typed = package.typed_build_requires().unwrap();
+        assert_eq!(typed[0].kind, RequirementKind::Build);
+    }
+
+    #[test]
+    fn test_requirement_display_round_trips_conflict_marker() {
+        let req = Requirement::parse("!python-2", RequirementKind::Run).unwrap();
+        assert_eq!(req.to_string(), "!python-2");
+    }
+}
+
+#[cfg(test)]
+mod wildcard_and_validate_tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_major_only_matches_any_minor_patch() {
+        let spec = VersionSpec::parse("1.*").unwrap();
+        assert!(spec.satisfied_by(&Version::parse("1.9.2").unwrap()));
+        assert!(!spec.satisfied_by(&Version::parse("2.0.0").unwrap()));
+    }
+
+    #[test]
+    fn test_wildcard_major_minor_matches_only_that_minor() {
+        let spec = VersionSpec::parse("1.2.*").unwrap();
+        assert!(spec.satisfied_by(&Version::parse("1.2.9").unwrap()));
+        assert!(!spec.satisfied_by(&Version::parse("1.3.0").unwrap()));
+    }
+
+    #[test]
+    fn test_package_requirement_roundtrip_with_wildcard() {
+        let req = PackageRequirement::parse("maya1.*").unwrap();
+        assert_eq!(req.name, "maya");
+        assert_eq!(req.version_spec.as_deref(), Some("1.*"));
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_requirement() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya>=>2023".to_string()];
+        assert!(package.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_requirements() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya>=2023".to_string(), "python-3.9+".to_string()];
+        package.build_requires = vec!["cmake-3.*".to_string()];
+        assert!(package.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_variant_requirement() {
+        let mut package = Package::new("myapp".to_string());
+        package.variants = vec![vec!["maya>=>2023".to_string()]];
+        assert!(package.validate().is_err());
+    }
+}
+
+#[cfg(test)]
+mod requirement_matches_tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_caret_on_zero_major_restricted_to_minor() {
+        let req = Requirement::parse("pkg^0.2", RequirementKind::Run).unwrap();
+        assert!(req.matches(&Version::parse("0.2.9").unwrap()));
+        assert!(!req.matches(&Version::parse("0.3.0").unwrap()));
+    }
+
+    #[test]
+    fn test_matches_rejects_prerelease_unless_opted_in() {
+        let stable_req = Requirement::parse("pkg>=2.0.0", RequirementKind::Run).unwrap();
+        assert!(!stable_req.matches(&Version::parse("2.0.0-beta1").unwrap()));
+
+        let prerelease_req = Requirement::parse("pkg>=2.0.0-alpha", RequirementKind::Run).unwrap();
+        assert!(prerelease_req.matches(&Version::parse("2.0.0-beta1").unwrap()));
+    }
+
+    #[test]
+    fn test_matches_wildcard() {
+        let req = Requirement::parse("pkg1.*", RequirementKind::Run).unwrap();
+        assert!(req.matches(&Version::parse("1.5.0").unwrap()));
+        assert!(!req.matches(&Version::parse("2.0.0").unwrap()));
+    }
+
+    #[test]
+    fn test_conflict_requirement_inverts_match() {
+        let req = Requirement::parse("!pkg-2", RequirementKind::Run).unwrap();
+        assert!(!req.matches(&Version::parse("2.0.0").unwrap()));
+        assert!(req.matches(&Version::parse("3.0.0").unwrap()));
+    }
+
+    #[test]
+    fn test_package_satisfies_checks_name_and_version() {
+        let mut package = Package::new("maya".to_string());
+        package.set_version(Version::parse("2023.1.0").unwrap());
+
+        let matching = Requirement::parse("maya>=2023", RequirementKind::Run).unwrap();
+        let wrong_name = Requirement::parse("houdini>=2023", RequirementKind::Run).unwrap();
+
+        assert!(package.satisfies(&matching));
+        assert!(!package.satisfies(&wrong_name));
+    }
+}
+
+#[cfg(test)]
+mod upgrade_requirements_tests {
+    use super::*;
+
+    fn versions(strs: &[&str]) -> Vec<Version> {
+        strs.iter().map(|s| Version::parse(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_compatible_mode_stays_within_caret_upper_bound() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya^1.2".to_string()];
+        let available = versions(&["1.5.0", "2.0.0"]);
+
+        let changes = package
+            .upgrade_requirements(
+                &|_| available.clone(),
+                UpgradeMode::Compatible,
+                false,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].old_constraint.as_deref(), Some("^1.2"));
+        assert_eq!(changes[0].new_constraint.as_deref(), Some("^1.5"));
+        assert_eq!(package.requires[0], "maya^1.5");
+    }
+
+    #[test]
+    fn test_latest_mode_ignores_original_upper_bound() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya^1.2".to_string()];
+        let available = versions(&["1.5.0", "2.0.0"]);
+
+        let changes = package
+            .upgrade_requirements(&|_| available.clone(), UpgradeMode::Latest, false, false)
+            .unwrap();
+
+        assert_eq!(changes[0].new_constraint.as_deref(), Some("^2.0"));
+        assert_eq!(package.requires[0], "maya^2.0");
+    }
+
+    #[test]
+    fn test_preserves_tilde_and_ge_operator_style() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya~1.2".to_string(), "houdini>=19.0".to_string()];
+        let maya_versions = versions(&["1.2.9", "1.9.0"]);
+        let houdini_versions = versions(&["19.5.0"]);
+
+        let changes = package
+            .upgrade_requirements(
+                &|name| match name {
+                    "maya" => maya_versions.clone(),
+                    "houdini" => houdini_versions.clone(),
+                    _ => Vec::new(),
+                },
+                UpgradeMode::Latest,
+                false,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(package.requires[0], "maya~1.9");
+        assert_eq!(package.requires[1], "houdini>=19.5");
+    }
+
+    #[test]
+    fn test_pinned_requirement_untouched_unless_forced() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya==2023".to_string()];
+        let available = versions(&["2024.0.0"]);
+
+        let changes = package
+            .upgrade_requirements(
+                &|_| available.clone(),
+                UpgradeMode::Latest,
+                false,
+                false,
+            )
+            .unwrap();
+        assert!(changes.is_empty());
+        assert_eq!(package.requires[0], "maya==2023");
+
+        let changes = package
+            .upgrade_requirements(&|_| available.clone(), UpgradeMode::Latest, false, true)
+            .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(package.requires[0], "maya==2024");
+    }
+
+    #[test]
+    fn test_dry_run_reports_without_mutating() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya^1.2".to_string()];
+        let available = versions(&["2.0.0"]);
+
+        let changes = package
+            .upgrade_requirements(&|_| available.clone(), UpgradeMode::Latest, true, false)
+            .unwrap();
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(package.requires[0], "maya^1.2");
+    }
+
+    #[test]
+    fn test_unsupported_range_left_alone() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["python-3.6..3.9".to_string()];
+        let available = versions(&["3.11.0"]);
+
+        let changes = package
+            .upgrade_requirements(&|_| available.clone(), UpgradeMode::Latest, false, false)
+            .unwrap();
+
+        assert!(changes.is_empty());
+        assert_eq!(package.requires[0], "python-3.6..3.9");
+    }
+}
+
+#[cfg(test)]
+mod requirement_intersect_tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect_overlapping_ranges_merges_comparators() {
+        let a = Requirement::parse("maya>=2022", RequirementKind::Run).unwrap();
+        let b = Requirement::parse("maya<2024", RequirementKind::Run).unwrap();
+
+        let merged = a.intersect(&b).unwrap();
+        assert!(merged
+            .requirement
+            .satisfied_by(&Version::parse("2023.0.0").unwrap()));
+        assert!(!merged
+            .requirement
+            .satisfied_by(&Version::parse("2021.0.0").unwrap()));
+        assert!(!merged
+            .requirement
+            .satisfied_by(&Version::parse("2024.0.0").unwrap()));
+    }
+
+    #[test]
+    fn test_intersect_disjoint_ranges_conflicts() {
+        let a = Requirement::parse("maya>=2024", RequirementKind::Run).unwrap();
+        let b = Requirement::parse("maya<2023", RequirementKind::Run).unwrap();
+
+        assert!(a.intersect(&b).is_none());
+    }
+
+    #[test]
+    fn test_intersect_rejects_mismatched_names() {
+        let a = Requirement::parse("maya>=2022", RequirementKind::Run).unwrap();
+        let b = Requirement::parse("houdini>=19", RequirementKind::Run).unwrap();
+
+        assert!(a.intersect(&b).is_none());
+    }
+
+    #[test]
+    fn test_intersect_rejects_conflict_requirements() {
+        let a = Requirement::parse("maya>=2022", RequirementKind::Run).unwrap();
+        let b = Requirement::parse("!maya-2023", RequirementKind::Run).unwrap();
+
+        assert!(a.intersect(&b).is_none());
+    }
+
+    #[test]
+    fn test_intersect_unconstrained_keeps_the_other_bound() {
+        let a = Requirement::parse("maya", RequirementKind::Run).unwrap();
+        let b = Requirement::parse("maya>=2022", RequirementKind::Run).unwrap();
+
+        let merged = a.intersect(&b).unwrap();
+        assert_eq!(merged.requirement.version_spec.as_deref(), Some(">=2022"));
+    }
+}
+
+#[cfg(test)]
+mod add_requirement_validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_add_requirement_accepts_valid_spec() {
+        let mut package = Package::new("myapp".to_string());
+        assert!(package.add_requirement("maya>=2023".to_string()).is_ok());
+        assert_eq!(package.requires, vec!["maya>=2023".to_string()]);
+    }
+
+    #[test]
+    fn test_add_requirement_rejects_malformed_spec() {
+        let mut package = Package::new("myapp".to_string());
+        assert!(package.add_requirement("maya>=".to_string()).is_err());
+        assert!(package.requires.is_empty());
+    }
+
+    #[test]
+    fn test_add_build_requirement_rejects_malformed_spec() {
+        let mut package = Package::new("myapp".to_string());
+        assert!(package
+            .add_build_requirement("cmake>=".to_string())
+            .is_err());
+        assert!(package.build_requires.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod platform_guard_tests {
+    use super::*;
+
+    fn env(pairs: &[(&str, &str)]) -> PlatformEnv {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_bare_eq_guard_matches() {
+        let guard = PlatformGuard::parse(r#"platform == "windows""#).unwrap();
+        assert!(guard.evaluate(&env(&[("platform", "windows")])));
+        assert!(!guard.evaluate(&env(&[("platform", "linux")])));
+    }
+
+    #[test]
+    fn test_unknown_key_does_not_match() {
+        let guard = PlatformGuard::parse(r#"platform == "windows""#).unwrap();
+        assert!(!guard.evaluate(&env(&[])));
+    }
+
+    #[test]
+    fn test_cfg_all_combination() {
+        let guard =
+            PlatformGuard::parse(r#"cfg(all(platform == "windows", arch == "x86_64"))"#).unwrap();
+        assert!(guard.evaluate(&env(&[("platform", "windows"), ("arch", "x86_64")])));
+        assert!(!guard.evaluate(&env(&[("platform", "windows"), ("arch", "arm64")])));
+    }
+
+    #[test]
+    fn test_cfg_any_and_not_combination() {
+        let guard =
+            PlatformGuard::parse(r#"cfg(any(not(platform == "windows"), arch == "x86_64"))"#)
+                .unwrap();
+        assert!(guard.evaluate(&env(&[("platform", "linux"), ("arch", "arm64")])));
+        assert!(guard.evaluate(&env(&[("platform", "windows"), ("arch", "x86_64")])));
+        assert!(!guard.evaluate(&env(&[("platform", "windows"), ("arch", "arm64")])));
+    }
+
+    #[test]
+    fn test_malformed_guard_is_rejected() {
+        assert!(PlatformGuard::parse("platform windows").is_err());
+        assert!(PlatformGuard::parse(r#"platform == "windows"#).is_err());
+    }
+
+    #[test]
+    fn test_effective_requires_filters_by_guard() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya>=2023".to_string(), "dx12-support".to_string()];
+        package
+            .requirement_guards
+            .insert("dx12-support".to_string(), r#"platform == "windows""#.to_string());
+
+        let linux_env = env(&[("platform", "linux")]);
+        let active = package.effective_requires(&linux_env).unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].requirement.name, "maya");
+
+        let windows_env = env(&[("platform", "windows")]);
+        let active = package.effective_requires(&windows_env).unwrap();
+        assert_eq!(active.len(), 2);
+    }
+
+    #[test]
+    fn test_effective_requires_surfaces_malformed_guard() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["dx12-support".to_string()];
+        package
+            .requirement_guards
+            .insert("dx12-support".to_string(), "platform windows".to_string());
+
+        assert!(package.effective_requires(&PlatformEnv::new()).is_err());
+    }
+
+    #[test]
+    fn test_requires_round_trips_guard_through_serde_json() {
+        let mut package = Package::new("myapp".to_string());
+        package.requires = vec!["maya>=2023".to_string(), "dx12-support".to_string()];
+        package
+            .requirement_guards
+            .insert("dx12-support".to_string(), r#"platform == "windows""#.to_string());
+
+        let json = serde_json::to_string(&package).unwrap();
+        assert!(json.contains("\"when\""));
+
+        let round_tripped: Package = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.requires, package.requires);
+        assert_eq!(
+            round_tripped.requirement_guards.get("dx12-support"),
+            Some(&r#"platform == "windows""#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_bare_requires_still_load_without_guards() {
+        let json = r#"{"name": "myapp", "requires": ["maya>=2023"]}"#;
+        let package: Package = serde_json::from_str(json).unwrap();
+        assert_eq!(package.requires, vec!["maya>=2023".to_string()]);
+        assert!(package.requirement_guards.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod config_value_tests {
+    use super::*;
+
+    #[test]
+    fn test_config_round_trips_through_serde_json() {
+        let mut package = Package::new("myapp".to_string());
+        package.config.insert(
+            "release_packages_path".to_string(),
+            ConfigValue::String("/packages/release".to_string()),
+        );
+        package
+            .config
+            .insert("build_thread_count".to_string(), ConfigValue::Int(4));
+        package.config.insert(
+            "plugins".to_string(),
+            ConfigValue::List(vec![
+                ConfigValue::String("build".to_string()),
+                ConfigValue::Bool(true),
+            ]),
+        );
+
+        let json = serde_json::to_string(&package).unwrap();
+        let round_tripped: Package = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.config, package.config);
+    }
+
+    #[test]
+    fn test_config_defaults_to_empty_when_absent() {
+        let json = r#"{"name": "myapp"}"#;
+        let package: Package = serde_json::from_str(json).unwrap();
+        assert!(package.config.is_empty());
+    }
+
+    #[test]
+    fn test_config_value_nested_map() {
+        let mut nested = HashMap::new();
+        nested.insert("timeout".to_string(), ConfigValue::Float(1.5));
+        let value = ConfigValue::Map(nested);
+
+        let json = serde_json::to_string(&value).unwrap();
+        let round_tripped: ConfigValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, value);
+    }
+}
+
+#[cfg(test)]
+mod package_mark_tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_sets_marked_state() {
+        let mut package = Package::new("myapp".to_string());
+        assert_eq!(package.marked_state(), None);
+
+        package.mark(PackageMark::Install);
+        assert_eq!(package.marked_state(), Some(PackageMark::Install));
+    }
+
+    #[test]
+    fn test_conflicts_with_mark_detects_install_vs_remove() {
+        let mut installed = Package::new("myapp".to_string());
+        installed.mark(PackageMark::Install);
+
+        let mut removed = Package::new("myapp".to_string());
+        removed.mark(PackageMark::Remove);
+
+        assert!(installed.conflicts_with_mark(&removed));
+        assert!(removed.conflicts_with_mark(&installed));
+    }
+
+    #[test]
+    fn test_conflicts_with_mark_allows_compatible_marks() {
+        let mut keep = Package::new("myapp".to_string());
+        keep.mark(PackageMark::Keep);
+
+        let mut installed = Package::new("myapp".to_string());
+        installed.mark(PackageMark::Install);
+
+        assert!(!keep.conflicts_with_mark(&installed));
+    }
+
+    #[test]
+    fn test_conflicts_with_mark_ignores_different_packages() {
+        let mut a = Package::new("myapp".to_string());
+        a.mark(PackageMark::Remove);
+
+        let mut b = Package::new("otherapp".to_string());
+        b.mark(PackageMark::Install);
+
+        assert!(!a.conflicts_with_mark(&b));
+    }
+
+    #[test]
+    fn test_marked_round_trips_through_serde_json() {
+        let mut package = Package::new("myapp".to_string());
+        package.mark(PackageMark::Hold);
+
+        let json = serde_json::to_string(&package).unwrap();
+        let round_tripped: Package = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.marked, Some(PackageMark::Hold));
+    }
+}
+
+#[cfg(test)]
+mod caret_compatible_requirement_tests {
+    use super::*;
+
+    #[test]
+    fn test_as_caret_requirement_shares_major_component() {
+        let mut package = Package::new("maya".to_string());
+        package.set_version(Version::parse("2023.1.0").unwrap());
+
+        assert_eq!(
+            package.as_caret_requirement().as_deref(),
+            Some("maya>=2023.1.0<2024.0.0")
+        );
+    }
+
+    #[test]
+    fn test_as_caret_requirement_zero_major_restricted_to_minor() {
+        let mut package = Package::new("maya".to_string());
+        package.set_version(Version::parse("0.2.5").unwrap());
+
+        assert_eq!(
+            package.as_caret_requirement().as_deref(),
+            Some("maya>=0.2.5<0.3.0")
+        );
+    }
+
+    #[test]
+    fn test_as_caret_requirement_none_without_version() {
+        let package = Package::new("maya".to_string());
+        assert_eq!(package.as_caret_requirement(), None);
+    }
+
+    #[test]
+    fn test_as_caret_requirement_none_for_unparsable_version() {
+        let mut package = Package::new("maya".to_string());
+        package.set_version(Version::parse("1.2.3.4").unwrap());
+
+        // PartialVersion only accepts up to major.minor.patch; a fourth
+        // numeric component isn't a valid PartialVersion even though it's
+        // a perfectly valid Version.
+        assert_eq!(package.as_caret_requirement(), None);
+    }
+
+    #[test]
+    fn test_as_compatible_requirement_shares_major_minor() {
+        let mut package = Package::new("maya".to_string());
+        package.set_version(Version::parse("2023.1.0").unwrap());
+
+        assert_eq!(
+            package.as_compatible_requirement().as_deref(),
+            Some("maya>=2023.1.0<2023.2.0")
+        );
+    }
+
+    #[test]
+    fn test_as_compatible_requirement_major_only_version() {
+        let mut package = Package::new("maya".to_string());
+        package.set_version(Version::parse("2023").unwrap());
+
+        assert_eq!(
+            package.as_compatible_requirement().as_deref(),
+            Some("maya>=2023<2024.0.0")
+        );
+    }
+
+    #[test]
+    fn test_as_compatible_requirement_none_without_version() {
+        let package = Package::new("maya".to_string());
+        assert_eq!(package.as_compatible_requirement(), None);
+    }
+
+    #[test]
+    fn test_as_compatible_requirement_none_for_unparsable_version() {
+        let mut package = Package::new("maya".to_string());
+        package.set_version(Version::parse("1.2.3.4").unwrap());
+
+        assert_eq!(package.as_compatible_requirement(), None);
+    }
+}
@@ -0,0 +1,644 @@
+//! Loading [`Package`] definitions directly from `package.py` / `package.yaml`
+//! / `package.json` manifest files on disk.
+
+use crate::Package;
+use rez_next_common::RezCoreError;
+use std::path::Path;
+
+/// The on-disk format of a package manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageFormat {
+    /// YAML format (`package.yaml`)
+    Yaml,
+    /// JSON format (`package.json`)
+    Json,
+    /// Python format (`package.py`)
+    Python,
+}
+
+impl PackageFormat {
+    /// Detect the format from a file's extension.
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "yaml" | "yml" => Some(Self::Yaml),
+            "json" => Some(Self::Json),
+            "py" => Some(Self::Python),
+            _ => None,
+        }
+    }
+
+    /// The canonical manifest file name for this format.
+    pub fn default_filename(&self) -> &'static str {
+        match self {
+            Self::Yaml => "package.yaml",
+            Self::Json => "package.json",
+            Self::Python => "package.py",
+        }
+    }
+}
+
+/// Loads and saves [`Package`] manifests in any of the formats rez supports.
+pub struct PackageSerializer;
+
+impl PackageSerializer {
+    /// Load a package from a manifest file, dispatching on its extension.
+    pub fn load_from_file(path: &Path) -> Result<Package, RezCoreError> {
+        let format = PackageFormat::from_extension(path).ok_or_else(|| {
+            RezCoreError::PackageParse(format!(
+                "Unsupported package manifest format: {}",
+                path.display()
+            ))
+        })?;
+
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            RezCoreError::PackageParse(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        Self::load_from_string(&content, format).map_err(|e| {
+            RezCoreError::PackageParse(format!("{}: {}", path.display(), e))
+        })
+    }
+
+    /// Load a package from a directory by looking for `package.py`,
+    /// `package.yaml`, then `package.json`, in that order of preference.
+    pub fn load_from_path(dir: &Path) -> Result<Package, RezCoreError> {
+        for format in [PackageFormat::Python, PackageFormat::Yaml, PackageFormat::Json] {
+            let candidate = dir.join(format.default_filename());
+            if candidate.is_file() {
+                return Self::load_from_file(&candidate);
+            }
+        }
+
+        Err(RezCoreError::PackageParse(format!(
+            "No package.py, package.yaml or package.json found in: {}",
+            dir.display()
+        )))
+    }
+
+    /// Load a package from a string already known to be in `format`.
+    pub fn load_from_string(content: &str, format: PackageFormat) -> Result<Package, RezCoreError> {
+        let mut package = match format {
+            PackageFormat::Yaml => serde_yaml::from_str::<Package>(content)
+                .map_err(|e| RezCoreError::PackageParse(format!("Invalid YAML: {}", e)))?,
+            PackageFormat::Json => serde_json::from_str::<Package>(content)
+                .map_err(|e| RezCoreError::PackageParse(format!("Invalid JSON: {}", e)))?,
+            PackageFormat::Python => Self::load_from_python(content)?,
+        };
+
+        // A manifest that parses but has an empty `name`, or a `version`
+        // that is present but empty, is still invalid. `version` itself
+        // may be omitted.
+        if package.name.is_empty() {
+            return Err(RezCoreError::PackageParse(
+                "Missing or invalid 'name' field".to_string(),
+            ));
+        }
+        if let Some(ref version) = package.version {
+            if version.as_str().is_empty() {
+                return Err(RezCoreError::PackageParse(
+                    "Invalid 'version' field: empty version string".to_string(),
+                ));
+            }
+        }
+
+        package.validate()?;
+        Ok(package)
+    }
+
+    /// Load a package from `package.py` content.
+    ///
+    /// `package.py` is an arbitrary Python module, but in practice every
+    /// real-world manifest is a flat sequence of top-level `name = value`
+    /// assignments. Rather than embed a full Python interpreter here, we
+    /// extract those assignments with a restricted literal parser that
+    /// understands strings, numbers, booleans, and `[...]`/`{...}` lists
+    /// and dicts. It fills `name`, `version`, `description`, `authors`,
+    /// the three requirement lists, `tools`, `commands`, `uuid`,
+    /// `variants`, `tests`, `requires_rez_version`, `relocatable`,
+    /// `cachable`, `vcs`, `format_version`, `has_plugins`, `plugin_for`
+    /// and `hashed_variants`. Other fields the `Deserialize` impl knows
+    /// about (`config`, `help`, `build_command`, `pre_commands`,
+    /// `timestamp`, ...) are not read from `package.py`. Those, and
+    /// anything it can't make sense of (function defs, conditionals,
+    /// computed values), are left at their defaults.
+    fn load_from_python(content: &str) -> Result<Package, RezCoreError> {
+        let assignments = parse_python_assignments(content)?;
+
+        let name = assignments
+            .get("name")
+            .and_then(PyLiteral::as_str)
+            .ok_or_else(|| {
+                RezCoreError::PackageParse("Missing or invalid 'name' field".to_string())
+            })?
+            .to_string();
+
+        let mut package = Package::new(name);
+
+        if let Some(version_str) = assignments.get("version").and_then(PyLiteral::as_str) {
+            let version = rez_next_version::Version::parse(version_str)
+                .map_err(|e| RezCoreError::PackageParse(format!("Invalid version: {}", e)))?;
+            package.set_version(version);
+        }
+
+        if let Some(description) = assignments.get("description").and_then(PyLiteral::as_str) {
+            package.set_description(description.to_string());
+        }
+
+        if let Some(authors) = assignments.get("authors").and_then(PyLiteral::as_str_list) {
+            package.authors = authors;
+        }
+
+        if let Some(requires) = assignments.get("requires").and_then(PyLiteral::as_str_list) {
+            package.requires = requires;
+        }
+
+        if let Some(build_requires) = assignments
+            .get("build_requires")
+            .and_then(PyLiteral::as_str_list)
+        {
+            package.build_requires = build_requires;
+        }
+
+        if let Some(private_build_requires) = assignments
+            .get("private_build_requires")
+            .and_then(PyLiteral::as_str_list)
+        {
+            package.private_build_requires = private_build_requires;
+        }
+
+        if let Some(tools) = assignments.get("tools").and_then(PyLiteral::as_str_list) {
+            package.tools = tools;
+        }
+
+        if let Some(commands) = assignments.get("commands").and_then(PyLiteral::as_str) {
+            package.commands = Some(commands.to_string());
+        }
+
+        if let Some(uuid) = assignments.get("uuid").and_then(PyLiteral::as_str) {
+            package.uuid = Some(uuid.to_string());
+        }
+
+        if let Some(variants) = assignments.get("variants").and_then(PyLiteral::as_str_list_list)
+        {
+            package.variants = variants;
+        }
+
+        if let Some(tests) = assignments.get("tests").and_then(PyLiteral::as_str_map) {
+            package.tests = tests;
+        }
+
+        if let Some(requires_rez_version) = assignments
+            .get("requires_rez_version")
+            .and_then(PyLiteral::as_str)
+        {
+            package.requires_rez_version = Some(requires_rez_version.to_string());
+        }
+
+        if let Some(relocatable) = assignments.get("relocatable").and_then(PyLiteral::as_bool) {
+            package.relocatable = Some(relocatable);
+        }
+
+        if let Some(cachable) = assignments.get("cachable").and_then(PyLiteral::as_bool) {
+            package.cachable = Some(cachable);
+        }
+
+        if let Some(vcs) = assignments.get("vcs").and_then(PyLiteral::as_str) {
+            package.vcs = Some(vcs.to_string());
+        }
+
+        if let Some(format_version) = assignments.get("format_version").and_then(PyLiteral::as_i64)
+        {
+            package.format_version = Some(format_version as i32);
+        }
+
+        if let Some(has_plugins) = assignments.get("has_plugins").and_then(PyLiteral::as_bool) {
+            package.has_plugins = Some(has_plugins);
+        }
+
+        if let Some(plugin_for) = assignments.get("plugin_for").and_then(PyLiteral::as_str_list) {
+            package.plugin_for = plugin_for;
+        }
+
+        if let Some(hashed_variants) = assignments
+            .get("hashed_variants")
+            .and_then(PyLiteral::as_bool)
+        {
+            package.hashed_variants = Some(hashed_variants);
+        }
+
+        Ok(package)
+    }
+}
+
+/// A restricted literal value recognized by the `package.py` mini-parser.
+enum PyLiteral {
+    Str(String),
+    Int(i64),
+    Bool(bool),
+    List(Vec<PyLiteral>),
+    Dict(Vec<(String, PyLiteral)>),
+}
+
+impl PyLiteral {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            PyLiteral::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            PyLiteral::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            PyLiteral::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn as_str_list(&self) -> Option<Vec<String>> {
+        match self {
+            PyLiteral::List(items) => items
+                .iter()
+                .map(|item| item.as_str().map(|s| s.to_string()))
+                .collect(),
+            _ => None,
+        }
+    }
+
+    /// A list-of-lists-of-strings, e.g. `variants = [['python-2.7'], ['python-3.9']]`.
+    fn as_str_list_list(&self) -> Option<Vec<Vec<String>>> {
+        match self {
+            PyLiteral::List(items) => items.iter().map(PyLiteral::as_str_list).collect(),
+            _ => None,
+        }
+    }
+
+    /// A `{'key': 'value', ...}` dict where every value is itself a string,
+    /// e.g. `tests = {'unit': 'python -m pytest'}`.
+    fn as_str_map(&self) -> Option<std::collections::HashMap<String, String>> {
+        match self {
+            PyLiteral::Dict(entries) => entries
+                .iter()
+                .map(|(key, value)| value.as_str().map(|v| (key.clone(), v.to_string())))
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Extract top-level `name = <literal>` assignments from a `package.py`
+/// source string. Lines are joined so that a list literal may span multiple
+/// lines; anything that isn't a simple `ident = literal` statement (function
+/// defs, `if`/`for`, comments-only lines) is ignored rather than rejected.
+fn parse_python_assignments(
+    content: &str,
+) -> Result<std::collections::HashMap<String, PyLiteral>, RezCoreError> {
+    let mut result = std::collections::HashMap::new();
+
+    for statement in split_top_level_statements(content) {
+        let statement = strip_comment(&statement);
+        let statement = statement.trim();
+        if statement.is_empty() {
+            continue;
+        }
+
+        let Some(eq_pos) = statement.find('=') else {
+            continue;
+        };
+        let name = statement[..eq_pos].trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let value = statement[eq_pos + 1..].trim();
+
+        if let Some(literal) = parse_py_literal(value) {
+            result.insert(name.to_string(), literal);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Split `content` into top-level statements, keeping any line whose bracket
+/// depth is still open joined onto the next.
+fn split_top_level_statements(content: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut depth: i32 = 0;
+
+    for line in content.lines() {
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(line);
+
+        depth += line.chars().filter(|c| matches!(c, '[' | '(' | '{')).count() as i32;
+        depth -= line.chars().filter(|c| matches!(c, ']' | ')' | '}')).count() as i32;
+
+        if depth <= 0 {
+            statements.push(std::mem::take(&mut current));
+            depth = 0;
+        }
+    }
+    if !current.is_empty() {
+        statements.push(current);
+    }
+
+    statements
+}
+
+/// Strip a trailing `# ...` comment, respecting quoted strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string: Option<char> = None;
+    for (i, c) in line.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => in_string = Some(c),
+            None if c == '#' => return &line[..i],
+            None => {}
+        }
+    }
+    line
+}
+
+/// Parse a single Python literal: a quoted string, an integer, `True`/
+/// `False`, or a `[...]` list or `{...}` dict of such literals. Returns
+/// `None` for anything else (floats, `None`, expressions).
+fn parse_py_literal(value: &str) -> Option<PyLiteral> {
+    let value = value.trim();
+
+    if let Some(inner) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        let items = split_list_items(inner)
+            .into_iter()
+            .filter_map(|item| parse_py_literal(&item))
+            .collect();
+        return Some(PyLiteral::List(items));
+    }
+
+    if let Some(inner) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+        let entries = split_list_items(inner)
+            .into_iter()
+            .filter_map(|entry| {
+                let (key, value) = split_dict_entry(&entry)?;
+                let key = parse_py_string(&key)?;
+                let value = parse_py_literal(&value)?;
+                Some((key, value))
+            })
+            .collect();
+        return Some(PyLiteral::Dict(entries));
+    }
+
+    match value {
+        "True" => return Some(PyLiteral::Bool(true)),
+        "False" => return Some(PyLiteral::Bool(false)),
+        _ => {}
+    }
+
+    if let Ok(n) = value.parse::<i64>() {
+        return Some(PyLiteral::Int(n));
+    }
+
+    parse_py_string(value).map(PyLiteral::Str)
+}
+
+/// Split a `'key': value` dict entry on its top-level colon (the first one
+/// outside of a quoted string), returning the raw (untrimmed-of-quotes) key
+/// and value text.
+fn split_dict_entry(entry: &str) -> Option<(String, String)> {
+    let mut in_string: Option<char> = None;
+    let mut depth = 0i32;
+
+    for (i, c) in entry.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => continue,
+            None if c == '\'' || c == '"' => in_string = Some(c),
+            None if c == '[' || c == '(' || c == '{' => depth += 1,
+            None if c == ']' || c == ')' || c == '}' => depth -= 1,
+            None if c == ':' && depth == 0 => {
+                return Some((entry[..i].to_string(), entry[i + 1..].to_string()));
+            }
+            None => {}
+        }
+    }
+
+    None
+}
+
+/// Parse a single quoted Python string literal (`'...'` or `"..."`), with
+/// implicit adjacent-literal concatenation (`"a" "b"`) treated as one value
+/// since rez manifests sometimes wrap long `commands` strings that way.
+fn parse_py_string(value: &str) -> Option<String> {
+    let mut chars = value.trim().chars().peekable();
+    let mut out = String::new();
+    let mut saw_any = false;
+
+    loop {
+        while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+            chars.next();
+        }
+        let Some(&quote) = chars.peek() else { break };
+        if quote != '\'' && quote != '"' {
+            break;
+        }
+        chars.next();
+        saw_any = true;
+        loop {
+            match chars.next() {
+                Some(c) if c == quote => break,
+                Some('\\') => {
+                    if let Some(escaped) = chars.next() {
+                        out.push(match escaped {
+                            'n' => '\n',
+                            't' => '\t',
+                            other => other,
+                        });
+                    }
+                }
+                Some(c) => out.push(c),
+                None => return None,
+            }
+        }
+    }
+
+    if saw_any {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+/// Split the inner contents of a `[...]` list on top-level commas.
+fn split_list_items(inner: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut in_string: Option<char> = None;
+
+    for c in inner.chars() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => in_string = Some(c),
+            None if c == '[' || c == '(' || c == '{' => depth += 1,
+            None if c == ']' || c == ')' || c == '}' => depth -= 1,
+            None if c == ',' && depth == 0 => {
+                items.push(std::mem::take(&mut current));
+                continue;
+            }
+            None => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        items.push(current);
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_extension() {
+        assert!(matches!(
+            PackageFormat::from_extension(Path::new("package.yaml")),
+            Some(PackageFormat::Yaml)
+        ));
+        assert!(matches!(
+            PackageFormat::from_extension(Path::new("package.json")),
+            Some(PackageFormat::Json)
+        ));
+        assert!(matches!(
+            PackageFormat::from_extension(Path::new("package.py")),
+            Some(PackageFormat::Python)
+        ));
+        assert!(PackageFormat::from_extension(Path::new("package.toml")).is_none());
+    }
+
+    #[test]
+    fn test_load_from_yaml() {
+        let yaml = "name: foo\nversion: \"1.2.3\"\nrequires:\n  - bar-1\n";
+        let package = PackageSerializer::load_from_string(yaml, PackageFormat::Yaml).unwrap();
+        assert_eq!(package.name, "foo");
+        assert_eq!(package.version.unwrap().as_str(), "1.2.3");
+        assert_eq!(package.requires, vec!["bar-1".to_string()]);
+    }
+
+    #[test]
+    fn test_load_from_json() {
+        let json = r#"{"name": "foo", "version": "1.0.0", "tools": ["footool"]}"#;
+        let package = PackageSerializer::load_from_string(json, PackageFormat::Json).unwrap();
+        assert_eq!(package.name, "foo");
+        assert_eq!(package.tools, vec!["footool".to_string()]);
+    }
+
+    #[test]
+    fn test_load_from_python_basic_assignments() {
+        let py = r#"
+name = 'foo'
+version = '1.2.3'
+description = "A test package"
+requires = [
+    'bar-1',
+    'baz-2+',
+]
+tools = ['footool']
+"#;
+        let package = PackageSerializer::load_from_string(py, PackageFormat::Python).unwrap();
+        assert_eq!(package.name, "foo");
+        assert_eq!(package.version.unwrap().as_str(), "1.2.3");
+        assert_eq!(package.description.as_deref(), Some("A test package"));
+        assert_eq!(
+            package.requires,
+            vec!["bar-1".to_string(), "baz-2+".to_string()]
+        );
+        assert_eq!(package.tools, vec!["footool".to_string()]);
+    }
+
+    #[test]
+    fn test_load_from_python_ignores_computed_values() {
+        let py = r#"
+name = 'foo'
+def commands():
+    env.PATH.append('{root}/bin')
+"#;
+        let package = PackageSerializer::load_from_string(py, PackageFormat::Python).unwrap();
+        assert_eq!(package.name, "foo");
+        assert!(package.commands.is_none());
+    }
+
+    #[test]
+    fn test_load_from_python_populates_remaining_deserialize_fields() {
+        let py = r#"
+name = 'foo'
+variants = [
+    ['python-2.7'],
+    ['python-3.9'],
+]
+tests = {
+    'unit': 'python -m pytest',
+}
+requires_rez_version = '2.0.0'
+relocatable = True
+cachable = False
+vcs = 'git'
+format_version = 2
+has_plugins = True
+plugin_for = ['otherpkg']
+hashed_variants = True
+"#;
+        let package = PackageSerializer::load_from_string(py, PackageFormat::Python).unwrap();
+        assert_eq!(
+            package.variants,
+            vec![
+                vec!["python-2.7".to_string()],
+                vec!["python-3.9".to_string()],
+            ]
+        );
+        assert_eq!(
+            package.tests.get("unit").map(String::as_str),
+            Some("python -m pytest")
+        );
+        assert_eq!(package.requires_rez_version.as_deref(), Some("2.0.0"));
+        assert_eq!(package.relocatable, Some(true));
+        assert_eq!(package.cachable, Some(false));
+        assert_eq!(package.vcs.as_deref(), Some("git"));
+        assert_eq!(package.format_version, Some(2));
+        assert_eq!(package.has_plugins, Some(true));
+        assert_eq!(package.plugin_for, vec!["otherpkg".to_string()]);
+        assert_eq!(package.hashed_variants, Some(true));
+    }
+
+    #[test]
+    fn test_load_from_string_missing_name_errors() {
+        let yaml = "version: \"1.0.0\"\n";
+        let err = PackageSerializer::load_from_string(yaml, PackageFormat::Yaml).unwrap_err();
+        assert!(matches!(err, RezCoreError::PackageParse(_)));
+    }
+
+    #[test]
+    fn test_load_from_path_prefers_python_over_yaml() {
+        let dir = std::env::temp_dir().join(format!(
+            "rez_next_package_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("package.py"), "name = 'pypkg'\n").unwrap();
+        std::fs::write(dir.join("package.yaml"), "name: yamlpkg\n").unwrap();
+
+        let package = PackageSerializer::load_from_path(&dir).unwrap();
+        assert_eq!(package.name, "pypkg");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
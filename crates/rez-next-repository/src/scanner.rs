@@ -5,7 +5,7 @@ use dashmap::DashMap;
 use futures::stream::{self, StreamExt};
 use memmap2::Mmap;
 use rez_next_common::RezCoreError;
-use rez_next_package::Package;
+use rez_next_package::{Package, PlatformEnv, PlatformGuard};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use std::collections::HashMap;
@@ -18,6 +18,16 @@ use tokio::sync::{RwLock, Semaphore};
 use tokio::task::JoinSet;
 use tokio::time::{interval, Instant};
 
+/// How the scan cache evicts entries once it grows past
+/// [`ScannerConfig::max_cache_entries`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvictionPolicy {
+    /// Drop the entry that was cached longest ago (by `cached_at`).
+    Lru,
+    /// Drop the entry with the fewest accesses (by `access_count`).
+    Lfu,
+}
+
 /// Enhanced scanner configuration with performance optimizations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScannerConfig {
@@ -49,12 +59,31 @@ pub struct ScannerConfig {
     pub enable_prefix_matching: bool,
     /// Enable intelligent cache preloading
     pub enable_cache_preload: bool,
-    /// Common paths to preload into cache
-    pub preload_paths: Vec<PathBuf>,
+    /// Common paths to preload into cache, each guarded by a
+    /// [`PlatformGuard`] expression (e.g. `cfg(platform == "windows")`) so a
+    /// path only preloads on the platforms it actually applies to. See
+    /// [`RepositoryScanner::effective_preload_paths`].
+    pub preload_paths: Vec<(String, PathBuf)>,
     /// Cache refresh interval in seconds (0 = disabled)
     pub cache_refresh_interval: u64,
     /// Enable background cache refresh
     pub enable_background_refresh: bool,
+    /// Disable on-disk persistence of the scan cache entirely, equivalent to
+    /// the CLI's `--no-cache` flag. The in-memory cache from
+    /// [`ScannerConfig::enable_scan_cache`] is unaffected; this only
+    /// controls whether it's loaded from / saved to disk.
+    pub no_cache: bool,
+    /// Directory the scan cache is persisted to. `None` resolves through
+    /// [`RepositoryScanner::resolved_cache_dir`] (the `REZ_CACHE_DIR`
+    /// environment variable, falling back to a platform cache directory).
+    pub cache_dir: Option<PathBuf>,
+    /// Maximum number of entries kept in the scan cache before
+    /// [`EvictionPolicy`] kicks in. Unlike [`ScannerConfig::max_cache_size_mb`]
+    /// (a rough byte-budget heuristic used during scanning), this is an
+    /// exact entry-count cap enforced by [`RepositoryScanner::evict_if_over_capacity`].
+    pub max_cache_entries: usize,
+    /// Eviction policy applied once the cache exceeds `max_cache_entries`.
+    pub eviction_policy: EvictionPolicy,
 }
 
 impl Default for ScannerConfig {
@@ -89,18 +118,31 @@ impl Default for ScannerConfig {
             enable_prefix_matching: true,
             enable_cache_preload: true,
             preload_paths: vec![
-                PathBuf::from("/usr/local/packages"),
-                PathBuf::from("/opt/packages"),
-                PathBuf::from("C:\\packages"),
+                (
+                    r#"cfg(not(platform == "windows"))"#.to_string(),
+                    PathBuf::from("/usr/local/packages"),
+                ),
+                (
+                    r#"cfg(not(platform == "windows"))"#.to_string(),
+                    PathBuf::from("/opt/packages"),
+                ),
+                (
+                    r#"cfg(platform == "windows")"#.to_string(),
+                    PathBuf::from("C:\\packages"),
+                ),
             ],
             cache_refresh_interval: 300, // 5 minutes
             enable_background_refresh: true,
+            no_cache: false,
+            cache_dir: None,
+            max_cache_entries: 10_000,
+            eviction_policy: EvictionPolicy::Lru,
         }
     }
 }
 
 /// Scan result for a single package
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PackageScanResult {
     /// The discovered package
     pub package: Package,
@@ -195,6 +237,55 @@ struct ScanCacheEntry {
     last_accessed: SystemTime,
 }
 
+/// On-disk form of a [`ScanCacheEntry`]. `SystemTime` has no `Serialize`
+/// impl, so timestamps are stored as Unix seconds, mirroring
+/// `rez-core-repository`'s `index.json` cache entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedCacheEntry {
+    result: PackageScanResult,
+    mtime_secs: u64,
+    size: u64,
+    cached_at_secs: u64,
+    access_count: u64,
+    last_accessed_secs: u64,
+}
+
+impl From<&ScanCacheEntry> for PersistedCacheEntry {
+    fn from(entry: &ScanCacheEntry) -> Self {
+        Self {
+            result: entry.result.clone(),
+            mtime_secs: system_time_to_secs(entry.mtime),
+            size: entry.size,
+            cached_at_secs: system_time_to_secs(entry.cached_at),
+            access_count: entry.access_count,
+            last_accessed_secs: system_time_to_secs(entry.last_accessed),
+        }
+    }
+}
+
+impl From<PersistedCacheEntry> for ScanCacheEntry {
+    fn from(entry: PersistedCacheEntry) -> Self {
+        Self {
+            result: entry.result,
+            mtime: secs_to_system_time(entry.mtime_secs),
+            size: entry.size,
+            cached_at: secs_to_system_time(entry.cached_at_secs),
+            access_count: entry.access_count,
+            last_accessed: secs_to_system_time(entry.last_accessed_secs),
+        }
+    }
+}
+
+fn system_time_to_secs(time: SystemTime) -> u64 {
+    time.duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn secs_to_system_time(secs: u64) -> SystemTime {
+    std::time::UNIX_EPOCH + Duration::from_secs(secs)
+}
+
 /// Enhanced cache statistics
 #[derive(Debug, Clone)]
 pub struct CacheStatistics {
@@ -386,12 +477,167 @@ impl RepositoryScanner {
         Ok(preloaded_count)
     }
 
-    /// Preload default common paths from configuration
+    /// Preload default common paths from configuration, skipping any whose
+    /// [`PlatformGuard`] doesn't match the current platform.
     pub async fn preload_default_paths(&self) -> Result<usize, RezCoreError> {
-        let paths = self.config.preload_paths.clone();
+        let paths = self.effective_preload_paths();
         self.preload_common_paths(&paths).await
     }
 
+    /// `preload_paths` filtered down to the entries whose guard expression
+    /// matches the platform this scanner is running on. A malformed guard
+    /// is treated as not matching, rather than erroring, so one bad entry
+    /// doesn't break preloading for the rest.
+    pub fn effective_preload_paths(&self) -> Vec<PathBuf> {
+        let env = Self::current_platform_env();
+
+        self.config
+            .preload_paths
+            .iter()
+            .filter_map(|(guard_src, path)| match PlatformGuard::parse(guard_src) {
+                Ok(guard) if guard.evaluate(&env) => Some(path.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The [`PlatformEnv`] this scanner evaluates `preload_paths` guards
+    /// against, built from `std::env::consts::OS`/`ARCH` the same way
+    /// `Package::effective_requires` callers typically do.
+    fn current_platform_env() -> PlatformEnv {
+        let mut env = PlatformEnv::new();
+        env.insert("platform".to_string(), std::env::consts::OS.to_string());
+        env.insert("arch".to_string(), std::env::consts::ARCH.to_string());
+        env
+    }
+
+    /// Directory the scan cache is persisted to: `ScannerConfig::cache_dir`
+    /// if set, else the `REZ_CACHE_DIR` environment variable, else a
+    /// platform cache directory. Returns `None` when persistence is
+    /// disabled (`ScannerConfig::no_cache`) or no directory can be resolved.
+    pub fn resolved_cache_dir(&self) -> Option<PathBuf> {
+        if self.config.no_cache {
+            return None;
+        }
+
+        if let Some(dir) = &self.config.cache_dir {
+            return Some(dir.clone());
+        }
+
+        if let Ok(dir) = std::env::var("REZ_CACHE_DIR") {
+            if !dir.is_empty() {
+                return Some(PathBuf::from(dir));
+            }
+        }
+
+        Self::default_cache_dir()
+    }
+
+    fn default_cache_dir() -> Option<PathBuf> {
+        if cfg!(windows) {
+            std::env::var("LOCALAPPDATA")
+                .ok()
+                .map(|base| PathBuf::from(base).join("rez").join("scan_cache"))
+        } else {
+            std::env::var("HOME")
+                .ok()
+                .map(|base| PathBuf::from(base).join(".cache").join("rez").join("scan_cache"))
+        }
+    }
+
+    /// Persist the current scan cache to disk under
+    /// [`RepositoryScanner::resolved_cache_dir`]. A no-op if persistence is
+    /// disabled or no cache directory can be resolved.
+    pub async fn save_to_disk(&self) -> Result<(), RezCoreError> {
+        let Some(cache_dir) = self.resolved_cache_dir() else {
+            return Ok(());
+        };
+
+        fs::create_dir_all(&cache_dir).await.map_err(|e| {
+            RezCoreError::Cache(format!(
+                "Failed to create cache directory {}: {}",
+                cache_dir.display(),
+                e
+            ))
+        })?;
+
+        let persisted: HashMap<String, PersistedCacheEntry> = self
+            .scan_cache
+            .iter()
+            .map(|entry| {
+                (
+                    entry.key().to_string_lossy().to_string(),
+                    PersistedCacheEntry::from(entry.value()),
+                )
+            })
+            .collect();
+
+        let content = serde_json::to_string_pretty(&persisted)
+            .map_err(|e| RezCoreError::Cache(format!("Failed to serialize scan cache: {}", e)))?;
+
+        fs::write(cache_dir.join("scan_cache.json"), content)
+            .await
+            .map_err(|e| RezCoreError::Cache(format!("Failed to write scan cache: {}", e)))
+    }
+
+    /// Load a previously persisted scan cache from disk, merging it into
+    /// the in-memory cache. A no-op if persistence is disabled or no cache
+    /// file exists yet. Returns the number of entries loaded.
+    pub async fn load_from_disk(&self) -> Result<usize, RezCoreError> {
+        let Some(cache_dir) = self.resolved_cache_dir() else {
+            return Ok(0);
+        };
+
+        let cache_file = cache_dir.join("scan_cache.json");
+        if !cache_file.exists() {
+            return Ok(0);
+        }
+
+        let content = fs::read_to_string(&cache_file)
+            .await
+            .map_err(|e| RezCoreError::Cache(format!("Failed to read scan cache: {}", e)))?;
+
+        let persisted: HashMap<String, PersistedCacheEntry> = serde_json::from_str(&content)
+            .map_err(|e| RezCoreError::Cache(format!("Failed to parse scan cache: {}", e)))?;
+
+        let loaded = persisted.len();
+        for (path, entry) in persisted {
+            self.scan_cache
+                .insert(PathBuf::from(path), entry.into());
+        }
+
+        self.evict_if_over_capacity();
+
+        Ok(loaded)
+    }
+
+    /// Evict entries once the cache exceeds `ScannerConfig::max_cache_entries`,
+    /// per the configured [`EvictionPolicy`].
+    fn evict_if_over_capacity(&self) {
+        let over_by = self
+            .scan_cache
+            .len()
+            .saturating_sub(self.config.max_cache_entries);
+        if over_by == 0 {
+            return;
+        }
+
+        let mut candidates: Vec<(PathBuf, SystemTime, u64)> = self
+            .scan_cache
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.cached_at, entry.access_count))
+            .collect();
+
+        match self.config.eviction_policy {
+            EvictionPolicy::Lru => candidates.sort_by_key(|(_, cached_at, _)| *cached_at),
+            EvictionPolicy::Lfu => candidates.sort_by_key(|(_, _, access_count)| *access_count),
+        }
+
+        for (path, _, _) in candidates.into_iter().take(over_by) {
+            self.scan_cache.remove(&path);
+        }
+    }
+
     /// Stop background cache refresh task
     pub async fn stop_background_refresh(&self) {
         if let mut refresh_handle = self.refresh_handle.write().await {
@@ -406,7 +652,7 @@ impl RepositoryScanner {
         let scan_cache = self.scan_cache.clone();
         let prefix_cache = self.prefix_cache.clone();
         let refresh_interval = self.config.cache_refresh_interval;
-        let preload_paths = self.config.preload_paths.clone();
+        let preload_paths = self.effective_preload_paths();
 
         let handle = tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(refresh_interval));
@@ -870,14 +1116,7 @@ impl RepositoryScanner {
             self.scan_cache
                 .insert(package_file.to_path_buf(), cache_entry);
 
-            // Limit cache size
-            if self.scan_cache.len() > self.config.max_cache_size_mb * 1000 {
-                // Simple cache eviction: remove oldest entries
-                // TODO: Implement LRU eviction
-                if self.scan_cache.len() > self.config.max_cache_size_mb * 1200 {
-                    self.scan_cache.clear();
-                }
-            }
+            self.evict_if_over_capacity();
         }
 
         Ok(result)
@@ -1064,3 +1303,152 @@ impl Default for RepositoryScanner {
         Self::new(ScannerConfig::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_effective_preload_paths_filters_by_platform() {
+        let mut config = ScannerConfig::default();
+        config.preload_paths = vec![
+            (
+                r#"cfg(platform == "definitely-not-a-real-platform")"#.to_string(),
+                PathBuf::from("/should/not/appear"),
+            ),
+            ("cfg(not(platform == \"nope\"))".to_string(), PathBuf::from("/should/appear")),
+        ];
+        config.enable_background_refresh = false;
+        let scanner = RepositoryScanner::new(config);
+
+        let paths = scanner.effective_preload_paths();
+        assert_eq!(paths, vec![PathBuf::from("/should/appear")]);
+    }
+
+    #[test]
+    fn test_resolved_cache_dir_respects_no_cache() {
+        let config = ScannerConfig {
+            no_cache: true,
+            cache_dir: Some(PathBuf::from("/some/explicit/dir")),
+            enable_background_refresh: false,
+            ..ScannerConfig::default()
+        };
+        let scanner = RepositoryScanner::new(config);
+
+        assert_eq!(scanner.resolved_cache_dir(), None);
+    }
+
+    #[test]
+    fn test_resolved_cache_dir_prefers_explicit_over_default() {
+        let config = ScannerConfig {
+            cache_dir: Some(PathBuf::from("/some/explicit/dir")),
+            enable_background_refresh: false,
+            ..ScannerConfig::default()
+        };
+        let scanner = RepositoryScanner::new(config);
+
+        assert_eq!(
+            scanner.resolved_cache_dir(),
+            Some(PathBuf::from("/some/explicit/dir"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_from_disk_round_trips_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_path = temp_dir.path().join("repo");
+        let cache_dir = temp_dir.path().join("cache");
+        tokio::fs::create_dir_all(&repo_path).await.unwrap();
+
+        let package_dir = repo_path.join("test_package").join("1.0.0");
+        tokio::fs::create_dir_all(&package_dir).await.unwrap();
+        tokio::fs::write(
+            package_dir.join("package.yaml"),
+            "name: test_package\nversion: \"1.0.0\"\n",
+        )
+        .await
+        .unwrap();
+
+        let config = ScannerConfig {
+            cache_dir: Some(cache_dir.clone()),
+            ..ScannerConfig::default()
+        };
+        let scanner = RepositoryScanner::new(config);
+        scanner.scan_repository(&repo_path).await.unwrap();
+        assert_eq!(scanner.cache_size(), 1);
+
+        scanner.save_to_disk().await.unwrap();
+        assert!(cache_dir.join("scan_cache.json").exists());
+
+        let reloaded_config = ScannerConfig {
+            cache_dir: Some(cache_dir),
+            ..ScannerConfig::default()
+        };
+        let reloaded = RepositoryScanner::new(reloaded_config);
+        let loaded = reloaded.load_from_disk().await.unwrap();
+        assert_eq!(loaded, 1);
+        assert_eq!(reloaded.cache_size(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_cache_skips_persistence() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_dir = temp_dir.path().join("cache");
+
+        let config = ScannerConfig {
+            no_cache: true,
+            cache_dir: Some(cache_dir.clone()),
+            ..ScannerConfig::default()
+        };
+        let scanner = RepositoryScanner::new(config);
+
+        scanner.save_to_disk().await.unwrap();
+        assert!(!cache_dir.exists());
+    }
+
+    #[test]
+    fn test_lru_eviction_drops_oldest_entry() {
+        let config = ScannerConfig {
+            max_cache_entries: 2,
+            eviction_policy: EvictionPolicy::Lru,
+            enable_background_refresh: false,
+            ..ScannerConfig::default()
+        };
+        let scanner = RepositoryScanner::new(config);
+
+        let make_entry = |name: &str, cached_at: SystemTime| ScanCacheEntry {
+            result: PackageScanResult {
+                package: serde_yaml::from_str::<Package>("name: test\n").unwrap(),
+                package_file: PathBuf::from(name),
+                package_dir: PathBuf::from(name),
+                file_size: 0,
+                scan_duration_ms: 0,
+            },
+            mtime: SystemTime::UNIX_EPOCH,
+            size: 0,
+            cached_at,
+            access_count: 1,
+            last_accessed: SystemTime::UNIX_EPOCH,
+        };
+
+        let now = SystemTime::now();
+        scanner
+            .scan_cache
+            .insert(PathBuf::from("oldest"), make_entry("oldest", now - Duration::from_secs(30)));
+        scanner
+            .scan_cache
+            .insert(PathBuf::from("newer"), make_entry("newer", now - Duration::from_secs(10)));
+
+        scanner.evict_if_over_capacity();
+        assert_eq!(scanner.cache_size(), 2);
+
+        scanner
+            .scan_cache
+            .insert(PathBuf::from("newest"), make_entry("newest", now));
+        scanner.evict_if_over_capacity();
+
+        assert_eq!(scanner.cache_size(), 2);
+        assert!(!scanner.scan_cache.contains_key(&PathBuf::from("oldest")));
+    }
+}
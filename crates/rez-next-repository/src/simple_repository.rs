@@ -30,6 +30,10 @@ pub trait PackageRepository {
 
     /// Get repository root path
     fn root_path(&self) -> &Path;
+
+    /// Get the on-disk directory a specific resolved package was loaded
+    /// from, if this repository knows about it
+    async fn find_package_path(&self, package: &Package) -> Result<Option<PathBuf>, RezCoreError>;
 }
 
 /// A simple file-based package repository
@@ -41,6 +45,10 @@ pub struct SimpleRepository {
     /// Cached packages
     package_cache: Arc<tokio::sync::RwLock<HashMap<String, Vec<Arc<Package>>>>>,
 
+    /// On-disk directory each cached package was loaded from, index-aligned
+    /// with the `Vec` for the same name in `package_cache`
+    package_paths: Arc<tokio::sync::RwLock<HashMap<String, Vec<PathBuf>>>>,
+
     /// Repository name
     name: String,
 }
@@ -51,6 +59,7 @@ impl SimpleRepository {
         Self {
             root_path: root_path.as_ref().to_path_buf(),
             package_cache: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            package_paths: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             name,
         }
     }
@@ -58,9 +67,12 @@ impl SimpleRepository {
     /// Scan the repository for packages
     pub async fn scan(&self) -> Result<(), RezCoreError> {
         let mut cache = self.package_cache.write().await;
+        let mut paths = self.package_paths.write().await;
         cache.clear();
+        paths.clear();
 
-        self.scan_directory(&self.root_path, &mut cache).await?;
+        self.scan_directory(&self.root_path, &mut cache, &mut paths)
+            .await?;
 
         Ok(())
     }
@@ -70,6 +82,7 @@ impl SimpleRepository {
         &'a self,
         dir_path: &'a Path,
         cache: &'a mut HashMap<String, Vec<Arc<Package>>>,
+        paths: &'a mut HashMap<String, Vec<PathBuf>>,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), RezCoreError>> + Send + 'a>>
     {
         Box::pin(async move {
@@ -85,13 +98,17 @@ impl SimpleRepository {
                         if let Ok(package) = self.load_package_from_path(&package_py).await {
                             let package_name = package.name.clone();
                             cache
-                                .entry(package_name)
+                                .entry(package_name.clone())
                                 .or_insert_with(Vec::new)
                                 .push(Arc::new(package));
+                            paths
+                                .entry(package_name)
+                                .or_insert_with(Vec::new)
+                                .push(path.clone());
                         }
                     } else {
                         // Recursively scan subdirectories
-                        self.scan_directory(&path, cache).await?;
+                        self.scan_directory(&path, cache, paths).await?;
                     }
                 }
             }
@@ -175,6 +192,27 @@ impl PackageRepository for SimpleRepository {
     fn root_path(&self) -> &Path {
         &self.root_path
     }
+
+    async fn find_package_path(&self, package: &Package) -> Result<Option<PathBuf>, RezCoreError> {
+        // Make sure the cache (and the path index alongside it) is
+        // populated for this package name
+        self.find_packages(&package.name).await?;
+
+        let cache = self.package_cache.read().await;
+        let paths = self.package_paths.read().await;
+
+        if let (Some(candidates), Some(candidate_paths)) =
+            (cache.get(&package.name), paths.get(&package.name))
+        {
+            for (candidate, path) in candidates.iter().zip(candidate_paths.iter()) {
+                if candidate.version == package.version {
+                    return Ok(Some(path.clone()));
+                }
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 /// Repository manager that manages multiple repositories
@@ -248,6 +286,20 @@ impl RepositoryManager {
     pub fn repository_count(&self) -> usize {
         self.repositories.len()
     }
+
+    /// Find the on-disk directory a resolved package was loaded from,
+    /// searching every repository in order
+    pub async fn find_package_path(
+        &self,
+        package: &Package,
+    ) -> Result<Option<PathBuf>, RezCoreError> {
+        for repository in &self.repositories {
+            if let Some(path) = repository.find_package_path(package).await? {
+                return Ok(Some(path));
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl Default for RepositoryManager {
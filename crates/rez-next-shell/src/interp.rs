@@ -0,0 +1,885 @@
+//! Command-line tokenizer, parser, and in-process executor.
+//!
+//! This intentionally covers the subset of shell syntax actually used in
+//! Rez package `commands()` blocks: variable expansion, quoting,
+//! pipelines (`|`), and `;`/`&&`/`||` sequencing. It does not attempt to
+//! be a POSIX-complete shell (no globbing, no subshells, no heredocs).
+
+use rez_next_common::RezCoreError;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command as AsyncCommand;
+
+/// A parse failure, with the byte offset of the offending character so
+/// callers can point a user at where in the command line it occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub byte_offset: usize,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.byte_offset)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for RezCoreError {
+    fn from(err: ParseError) -> Self {
+        RezCoreError::ExecutionError(err.to_string())
+    }
+}
+
+/// One piece of a [`Word`]: either literal text or a `$VAR`/`${VAR}`
+/// reference to be expanded against the executor's environment.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WordPart {
+    Literal(String),
+    Variable(String),
+}
+
+/// An argument, redirect target, or the left-hand side of an env
+/// assignment, as a sequence of literal and variable parts glued together
+/// with no whitespace in between (`foo$BAR baz` is two words).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Word(pub Vec<WordPart>);
+
+impl Word {
+    fn literal(s: impl Into<String>) -> Self {
+        Word(vec![WordPart::Literal(s.into())])
+    }
+
+    /// Expand all variable references against `environment`, missing
+    /// variables expanding to an empty string (matching shell behavior).
+    pub fn expand(&self, environment: &HashMap<String, String>) -> String {
+        self.0
+            .iter()
+            .map(|part| match part {
+                WordPart::Literal(s) => s.clone(),
+                WordPart::Variable(name) => environment.get(name).cloned().unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+/// Which file descriptor a [`Redirect`] targets and how it's opened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RedirectMode {
+    /// `>` — truncate and write.
+    Write,
+    /// `>>` — append.
+    Append,
+    /// `<` — read.
+    Read,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Redirect {
+    pub fd: u32,
+    pub target: Word,
+    pub mode: RedirectMode,
+}
+
+/// A single command: its argument words, any redirects, and any
+/// `NAME=value` assignments that precede it (scoped to this command only,
+/// per shell semantics — they don't persist past it).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ShellCommand {
+    pub argv: Vec<Word>,
+    pub redirects: Vec<Redirect>,
+    pub env_assignments: Vec<(String, String)>,
+}
+
+/// A list of commands joined by `|`, each stage's stdout feeding the
+/// next stage's stdin.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Pipeline {
+    pub commands: Vec<ShellCommand>,
+}
+
+/// How two adjacent pipelines in a [`Sequence`] are joined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SequenceOp {
+    /// `;` — always run the next pipeline.
+    Then,
+    /// `&&` — run the next pipeline only if the previous succeeded.
+    And,
+    /// `||` — run the next pipeline only if the previous failed.
+    Or,
+}
+
+/// A full parsed command line: pipelines joined by `;`/`&&`/`||`.
+/// `operators.len() == pipelines.len() - 1`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Sequence {
+    pub pipelines: Vec<Pipeline>,
+    pub operators: Vec<SequenceOp>,
+}
+
+/// Parse a command line into a [`Sequence`].
+pub fn parse(input: &str) -> Result<Sequence, ParseError> {
+    Parser::new(input).parse_sequence()
+}
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().collect(),
+            input,
+            pos: 0,
+        }
+    }
+
+    fn byte_offset(&self) -> usize {
+        self.input
+            .char_indices()
+            .nth(self.pos)
+            .map(|(b, _)| b)
+            .unwrap_or(self.input.len())
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace() && c != '\n') {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_sequence(&mut self) -> Result<Sequence, ParseError> {
+        let mut sequence = Sequence::default();
+        sequence.pipelines.push(self.parse_pipeline()?);
+
+        loop {
+            // A bare newline between commands acts as `;` — this is what
+            // lets a multi-line package `commands()` script parse as a
+            // sequence instead of one run-on command.
+            self.skip_whitespace();
+            let mut saw_newline = false;
+            while self.peek() == Some('\n') {
+                self.advance();
+                self.skip_whitespace();
+                saw_newline = true;
+            }
+
+            let op = match self.peek() {
+                Some(';') => {
+                    self.advance();
+                    SequenceOp::Then
+                }
+                Some('&') if self.peek_at(1) == Some('&') => {
+                    self.advance();
+                    self.advance();
+                    SequenceOp::And
+                }
+                Some('|') if self.peek_at(1) == Some('|') => {
+                    self.advance();
+                    self.advance();
+                    SequenceOp::Or
+                }
+                _ if saw_newline => SequenceOp::Then,
+                _ => break,
+            };
+            self.skip_whitespace();
+            while self.peek() == Some('\n') {
+                self.advance();
+                self.skip_whitespace();
+            }
+            if self.peek().is_none() {
+                // Trailing separator with nothing after it, e.g. "a;" —
+                // treat like a no-op terminator rather than an error, the
+                // same way a trailing newline in a script does.
+                break;
+            }
+            sequence.operators.push(op);
+            sequence.pipelines.push(self.parse_pipeline()?);
+        }
+
+        Ok(sequence)
+    }
+
+    fn parse_pipeline(&mut self) -> Result<Pipeline, ParseError> {
+        let mut pipeline = Pipeline::default();
+        pipeline.commands.push(self.parse_command()?);
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('|') && self.peek_at(1) != Some('|') {
+                self.advance();
+                self.skip_whitespace();
+                pipeline.commands.push(self.parse_command()?);
+            } else {
+                break;
+            }
+        }
+
+        Ok(pipeline)
+    }
+
+    fn parse_command(&mut self) -> Result<ShellCommand, ParseError> {
+        let mut command = ShellCommand::default();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None | Some(';') | Some('|') | Some('&') | Some('\n') => break,
+                Some('>') => {
+                    self.advance();
+                    let mode = if self.peek() == Some('>') {
+                        self.advance();
+                        RedirectMode::Append
+                    } else {
+                        RedirectMode::Write
+                    };
+                    self.skip_whitespace();
+                    let target = self.parse_word()?;
+                    command.redirects.push(Redirect {
+                        fd: 1,
+                        target,
+                        mode,
+                    });
+                }
+                Some('<') => {
+                    self.advance();
+                    self.skip_whitespace();
+                    let target = self.parse_word()?;
+                    command.redirects.push(Redirect {
+                        fd: 0,
+                        target,
+                        mode: RedirectMode::Read,
+                    });
+                }
+                _ => {
+                    let word = self.parse_word()?;
+                    if command.argv.is_empty() {
+                        if let Some((name, value)) = as_env_assignment(&word) {
+                            command.env_assignments.push((name, value));
+                            continue;
+                        }
+                    }
+                    command.argv.push(word);
+                }
+            }
+        }
+
+        if command.argv.is_empty() && command.env_assignments.is_empty() {
+            return Err(ParseError {
+                message: "Expected a command".to_string(),
+                byte_offset: self.byte_offset(),
+            });
+        }
+
+        Ok(command)
+    }
+
+    /// Parse one whitespace-delimited word, handling quotes and `$VAR` /
+    /// `${VAR}` expansion. Stops at unquoted whitespace or an operator
+    /// character (`|`, `&`, `;`, `<`, `>`).
+    fn parse_word(&mut self) -> Result<Word, ParseError> {
+        let mut parts: Vec<WordPart> = Vec::new();
+        let mut literal = String::new();
+
+        loop {
+            match self.peek() {
+                None => break,
+                Some(c) if c.is_whitespace() => break,
+                Some('|') | Some('&') | Some(';') | Some('<') | Some('>') => break,
+                Some('\'') => {
+                    self.advance();
+                    loop {
+                        match self.advance() {
+                            Some('\'') => break,
+                            Some(c) => literal.push(c),
+                            None => {
+                                return Err(ParseError {
+                                    message: "Unterminated single quote".to_string(),
+                                    byte_offset: self.byte_offset(),
+                                })
+                            }
+                        }
+                    }
+                }
+                Some('"') => {
+                    self.advance();
+                    self.parse_double_quoted(&mut parts, &mut literal)?;
+                }
+                Some('\\') => {
+                    self.advance();
+                    match self.advance() {
+                        Some(c) => literal.push(c),
+                        None => {
+                            return Err(ParseError {
+                                message: "Trailing backslash".to_string(),
+                                byte_offset: self.byte_offset(),
+                            })
+                        }
+                    }
+                }
+                Some('$') => {
+                    if !literal.is_empty() {
+                        parts.push(WordPart::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(self.parse_variable()?);
+                }
+                Some(c) => {
+                    self.advance();
+                    literal.push(c);
+                }
+            }
+        }
+
+        if !literal.is_empty() {
+            parts.push(WordPart::Literal(literal));
+        }
+
+        if parts.is_empty() {
+            return Err(ParseError {
+                message: "Expected a word".to_string(),
+                byte_offset: self.byte_offset(),
+            });
+        }
+
+        Ok(Word(parts))
+    }
+
+    /// Parse the inside of a double-quoted string into `parts`/`literal`,
+    /// honoring `$VAR` expansion but treating everything else literally.
+    fn parse_double_quoted(
+        &mut self,
+        parts: &mut Vec<WordPart>,
+        literal: &mut String,
+    ) -> Result<(), ParseError> {
+        loop {
+            match self.advance() {
+                Some('"') => return Ok(()),
+                Some('\\') => match self.advance() {
+                    Some(c) => literal.push(c),
+                    None => {
+                        return Err(ParseError {
+                            message: "Trailing backslash in quoted string".to_string(),
+                            byte_offset: self.byte_offset(),
+                        })
+                    }
+                },
+                Some('$') => {
+                    if !literal.is_empty() {
+                        parts.push(WordPart::Literal(std::mem::take(literal)));
+                    }
+                    parts.push(self.parse_variable()?);
+                }
+                Some(c) => literal.push(c),
+                None => {
+                    return Err(ParseError {
+                        message: "Unterminated double quote".to_string(),
+                        byte_offset: self.byte_offset(),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Parse a `$VAR` or `${VAR}` reference, `self.pos` sitting on the `$`.
+    fn parse_variable(&mut self) -> Result<WordPart, ParseError> {
+        self.advance(); // consume '$'
+
+        if self.peek() == Some('{') {
+            self.advance();
+            let mut name = String::new();
+            loop {
+                match self.advance() {
+                    Some('}') => break,
+                    Some(c) => name.push(c),
+                    None => {
+                        return Err(ParseError {
+                            message: "Unterminated ${...} variable reference".to_string(),
+                            byte_offset: self.byte_offset(),
+                        })
+                    }
+                }
+            }
+            return Ok(WordPart::Variable(name));
+        }
+
+        let mut name = String::new();
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            name.push(self.advance().unwrap());
+        }
+
+        if name.is_empty() {
+            // A bare `$` with nothing recognizable after it: treat as a
+            // literal dollar sign, same as most shells do.
+            return Ok(WordPart::Literal("$".to_string()));
+        }
+
+        Ok(WordPart::Variable(name))
+    }
+}
+
+/// If `word` is a single literal part of the form `NAME=value` (a valid
+/// shell identifier on the left), split it into an env assignment.
+fn as_env_assignment(word: &Word) -> Option<(String, String)> {
+    let [WordPart::Literal(s)] = word.0.as_slice() else {
+        return None;
+    };
+    let (name, value) = s.split_once('=')?;
+    if name.is_empty()
+        || !name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+    Some((name.to_string(), value.to_string()))
+}
+
+/// The result of executing a [`Sequence`]: the exit code of the last
+/// pipeline that ran, the stdout of that pipeline's final stage (empty if
+/// an earlier stage of it failed), and the concatenated stderr of every
+/// stage that ran.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionResult {
+    pub exit_code: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl ExecutionResult {
+    fn success(stdout: String) -> Self {
+        Self {
+            exit_code: 0,
+            stdout,
+            stderr: String::new(),
+        }
+    }
+
+    fn failure(exit_code: i32, stderr: String) -> Self {
+        Self {
+            exit_code,
+            stdout: String::new(),
+            stderr,
+        }
+    }
+}
+
+/// In-process interpreter for the `interp` command language: owns the
+/// environment and working directory a pipeline executes against, so
+/// `cd`/`export`/`unset` persist between calls to [`Self::run`] the same
+/// way they would in a real shell session.
+#[derive(Debug, Clone, Default)]
+pub struct Interpreter {
+    environment: HashMap<String, String>,
+    working_directory: Option<PathBuf>,
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_environment(environment: HashMap<String, String>) -> Self {
+        Self {
+            environment,
+            working_directory: None,
+        }
+    }
+
+    pub fn set_working_directory(&mut self, dir: PathBuf) {
+        self.working_directory = Some(dir);
+    }
+
+    pub fn environment(&self) -> &HashMap<String, String> {
+        &self.environment
+    }
+
+    /// Parse and run a full command line, returning a single aggregated
+    /// result.
+    pub async fn run(&mut self, input: &str) -> Result<ExecutionResult, RezCoreError> {
+        let sequence = parse(input)?;
+        self.run_sequence(&sequence).await
+    }
+
+    async fn run_sequence(&mut self, sequence: &Sequence) -> Result<ExecutionResult, RezCoreError> {
+        let mut result = ExecutionResult::success(String::new());
+        let mut stderr = String::new();
+
+        for (index, pipeline) in sequence.pipelines.iter().enumerate() {
+            if index > 0 {
+                match sequence.operators[index - 1] {
+                    SequenceOp::And if result.exit_code != 0 => continue,
+                    SequenceOp::Or if result.exit_code == 0 => continue,
+                    _ => {}
+                }
+            }
+            result = self.run_pipeline(pipeline).await?;
+            stderr.push_str(&result.stderr);
+        }
+
+        result.stderr = stderr;
+        Ok(result)
+    }
+
+    /// Run every stage of `pipeline`, feeding each stage's captured stdout
+    /// into the next stage's stdin. Stages run one at a time rather than
+    /// with true concurrent streaming — fine for the short-lived build and
+    /// launch commands this interpreter targets, but not a good fit for
+    /// long-running interactive filters.
+    async fn run_pipeline(&mut self, pipeline: &Pipeline) -> Result<ExecutionResult, RezCoreError> {
+        let mut stdin_data: Option<Vec<u8>> = None;
+        let mut stderr_parts = Vec::new();
+        let mut exit_code = 0;
+        let mut stdout_data = Vec::new();
+        let last = pipeline.commands.len().saturating_sub(1);
+
+        for (index, command) in pipeline.commands.iter().enumerate() {
+            let stage = self.run_stage(command, stdin_data.take()).await?;
+            exit_code = stage.exit_code;
+            if !stage.stderr.is_empty() {
+                stderr_parts.push(stage.stderr);
+            }
+            if index == last {
+                stdout_data = stage.stdout;
+            } else {
+                stdin_data = Some(stage.stdout);
+            }
+            if exit_code != 0 {
+                break;
+            }
+        }
+
+        Ok(ExecutionResult {
+            exit_code,
+            stdout: String::from_utf8_lossy(&stdout_data).to_string(),
+            stderr: stderr_parts.join(""),
+        })
+    }
+
+    async fn run_stage(
+        &mut self,
+        command: &ShellCommand,
+        stdin_data: Option<Vec<u8>>,
+    ) -> Result<StageOutput, RezCoreError> {
+        let argv: Vec<String> = command
+            .argv
+            .iter()
+            .map(|word| word.expand(&self.environment))
+            .collect();
+
+        if argv.is_empty() {
+            // Only env assignments, e.g. `FOO=bar` with no command —
+            // nothing to run, but not an error.
+            return Ok(StageOutput {
+                exit_code: 0,
+                stdout: Vec::new(),
+                stderr: String::new(),
+            });
+        }
+
+        if let Some(output) = self.run_builtin(&argv[0], &argv[1..], stdin_data.as_deref()) {
+            return Ok(output);
+        }
+
+        self.run_external(command, &argv, stdin_data).await
+    }
+
+    /// Dispatch a handful of built-ins that a subprocess couldn't
+    /// implement anyway (`cd`, `export`, `unset` need to mutate this
+    /// interpreter's own state) plus a few trivial ones (`echo`, `set`,
+    /// `true`, `false`) that are cheap to handle in-process.
+    fn run_builtin(&mut self, name: &str, args: &[String], _stdin: Option<&[u8]>) -> Option<StageOutput> {
+        match name {
+            "cd" => {
+                let target = args.first().cloned().unwrap_or_default();
+                self.working_directory = Some(PathBuf::from(target));
+                Some(StageOutput::ok(Vec::new()))
+            }
+            "export" => {
+                for arg in args {
+                    if let Some((name, value)) = arg.split_once('=') {
+                        self.environment.insert(name.to_string(), value.to_string());
+                    }
+                }
+                Some(StageOutput::ok(Vec::new()))
+            }
+            "unset" => {
+                for arg in args {
+                    self.environment.remove(arg);
+                }
+                Some(StageOutput::ok(Vec::new()))
+            }
+            "echo" => {
+                let mut line = args.join(" ");
+                line.push('\n');
+                Some(StageOutput::ok(line.into_bytes()))
+            }
+            "set" => {
+                let mut entries: Vec<_> = self.environment.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                let mut out = String::new();
+                for (key, value) in entries {
+                    out.push_str(&format!("{}={}\n", key, value));
+                }
+                Some(StageOutput::ok(out.into_bytes()))
+            }
+            "true" => Some(StageOutput::ok(Vec::new())),
+            "false" => Some(StageOutput {
+                exit_code: 1,
+                stdout: Vec::new(),
+                stderr: String::new(),
+            }),
+            _ => None,
+        }
+    }
+
+    async fn run_external(
+        &self,
+        command: &ShellCommand,
+        argv: &[String],
+        stdin_data: Option<Vec<u8>>,
+    ) -> Result<StageOutput, RezCoreError> {
+        let mut cmd = AsyncCommand::new(&argv[0]);
+        cmd.args(&argv[1..]);
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+        cmd.stdin(if stdin_data.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        });
+
+        if let Some(ref dir) = self.working_directory {
+            cmd.current_dir(dir);
+        }
+
+        for (key, value) in &self.environment {
+            cmd.env(key, value);
+        }
+        for (name, value) in &command.env_assignments {
+            cmd.env(name, value);
+        }
+
+        for redirect in &command.redirects {
+            self.apply_redirect(&mut cmd, redirect)?;
+        }
+
+        let mut child = cmd.spawn().map_err(|e| {
+            RezCoreError::ExecutionError(format!("Failed to spawn '{}': {}", argv[0], e))
+        })?;
+
+        if let Some(data) = stdin_data {
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(&data).await.map_err(|e| {
+                    RezCoreError::ExecutionError(format!("Failed to write stdin: {}", e))
+                })?;
+            }
+        }
+
+        let output = child.wait_with_output().await.map_err(|e| {
+            RezCoreError::ExecutionError(format!("Failed to run '{}': {}", argv[0], e))
+        })?;
+
+        Ok(StageOutput {
+            exit_code: output.status.code().unwrap_or(-1),
+            stdout: output.stdout,
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        })
+    }
+
+    /// Apply a `>`/`>>`/`<` redirect by attaching an opened file as the
+    /// relevant standard stream. Input (`<`) redirects take priority over
+    /// a piped-in previous pipeline stage, matching shell precedent that
+    /// an explicit redirect wins.
+    fn apply_redirect(&self, cmd: &mut AsyncCommand, redirect: &Redirect) -> Result<(), RezCoreError> {
+        let path = redirect.target.expand(&self.environment);
+        match redirect.mode {
+            RedirectMode::Write => {
+                let file = std::fs::File::create(&path).map_err(|e| {
+                    RezCoreError::ExecutionError(format!("Failed to open '{}': {}", path, e))
+                })?;
+                cmd.stdout(Stdio::from(file));
+            }
+            RedirectMode::Append => {
+                let file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .map_err(|e| {
+                        RezCoreError::ExecutionError(format!("Failed to open '{}': {}", path, e))
+                    })?;
+                cmd.stdout(Stdio::from(file));
+            }
+            RedirectMode::Read => {
+                let file = std::fs::File::open(&path).map_err(|e| {
+                    RezCoreError::ExecutionError(format!("Failed to open '{}': {}", path, e))
+                })?;
+                cmd.stdin(Stdio::from(file));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single pipeline stage's captured output, before being handed off as
+/// the next stage's stdin (or surfaced as the pipeline's own result).
+struct StageOutput {
+    exit_code: i32,
+    stdout: Vec<u8>,
+    stderr: String,
+}
+
+impl StageOutput {
+    fn ok(stdout: Vec<u8>) -> Self {
+        Self {
+            exit_code: 0,
+            stdout,
+            stderr: String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_pipeline() {
+        let sequence = parse("echo hello | sort").unwrap();
+        assert_eq!(sequence.pipelines.len(), 1);
+        assert_eq!(sequence.pipelines[0].commands.len(), 2);
+        assert_eq!(
+            sequence.pipelines[0].commands[0].argv[0].expand(&HashMap::new()),
+            "echo"
+        );
+        assert_eq!(
+            sequence.pipelines[0].commands[1].argv[0].expand(&HashMap::new()),
+            "sort"
+        );
+    }
+
+    #[test]
+    fn test_parse_sequence_operators() {
+        let sequence = parse("a ; b && c || d").unwrap();
+        assert_eq!(sequence.pipelines.len(), 4);
+        assert_eq!(
+            sequence.operators,
+            vec![SequenceOp::Then, SequenceOp::And, SequenceOp::Or]
+        );
+    }
+
+    #[test]
+    fn test_parse_double_quoted_variable_expansion() {
+        let sequence = parse("echo \"hello $NAME\"").unwrap();
+        let word = &sequence.pipelines[0].commands[0].argv[1];
+
+        let mut env = HashMap::new();
+        env.insert("NAME".to_string(), "world".to_string());
+        assert_eq!(word.expand(&env), "hello world");
+        assert_eq!(word.expand(&HashMap::new()), "hello ");
+    }
+
+    #[test]
+    fn test_parse_braced_variable_and_single_quote_is_literal() {
+        let sequence = parse("echo ${NAME} '$NAME'").unwrap();
+        let braced = &sequence.pipelines[0].commands[0].argv[1];
+        let quoted = &sequence.pipelines[0].commands[0].argv[2];
+
+        let mut env = HashMap::new();
+        env.insert("NAME".to_string(), "value".to_string());
+        assert_eq!(braced.expand(&env), "value");
+        assert_eq!(quoted.expand(&env), "$NAME");
+    }
+
+    #[test]
+    fn test_parse_env_assignment_scoped_to_command() {
+        let sequence = parse("FOO=bar echo hi").unwrap();
+        let command = &sequence.pipelines[0].commands[0];
+        assert_eq!(
+            command.env_assignments,
+            vec![("FOO".to_string(), "bar".to_string())]
+        );
+        assert_eq!(command.argv.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_redirects() {
+        let sequence = parse("cmd > out.txt").unwrap();
+        let command = &sequence.pipelines[0].commands[0];
+        assert_eq!(command.redirects.len(), 1);
+        assert_eq!(command.redirects[0].fd, 1);
+        assert_eq!(command.redirects[0].mode, RedirectMode::Write);
+        assert_eq!(command.redirects[0].target.expand(&HashMap::new()), "out.txt");
+    }
+
+    #[test]
+    fn test_parse_newline_separates_commands_like_semicolon() {
+        let sequence = parse("echo a\necho b\n").unwrap();
+        assert_eq!(sequence.pipelines.len(), 2);
+        assert_eq!(sequence.operators, vec![SequenceOp::Then]);
+    }
+
+    #[test]
+    fn test_parse_empty_command_is_error() {
+        assert!(parse("| echo hi").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_true_false_builtins() {
+        let mut interp = Interpreter::new();
+        assert_eq!(interp.run("true").await.unwrap().exit_code, 0);
+        assert_eq!(interp.run("false").await.unwrap().exit_code, 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_echo_builtin() {
+        let mut interp = Interpreter::new();
+        let result = interp.run("echo hello world").await.unwrap();
+        assert_eq!(result.exit_code, 0);
+        assert_eq!(result.stdout, "hello world\n");
+    }
+
+    #[tokio::test]
+    async fn test_run_export_persists_across_calls() {
+        let mut interp = Interpreter::new();
+        interp.run("export GREETING=hi").await.unwrap();
+        assert_eq!(
+            interp.environment().get("GREETING"),
+            Some(&"hi".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_and_or_short_circuit() {
+        let mut interp = Interpreter::new();
+        let result = interp.run("false && echo unreachable").await.unwrap();
+        assert_eq!(result.exit_code, 1);
+        assert_eq!(result.stdout, "");
+
+        let result = interp.run("false || echo fallback").await.unwrap();
+        assert_eq!(result.stdout, "fallback\n");
+    }
+}
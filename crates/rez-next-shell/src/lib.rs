@@ -0,0 +1,16 @@
+//! # Rez Next Shell
+//!
+//! Cross-platform command execution support for Rez package `commands()`
+//! blocks, independent of whatever shell happens to be installed on the
+//! host.
+//!
+//! This crate provides:
+//! - A tokenizer/recursive-descent parser for POSIX-ish command lines
+//! - In-process execution of pipelines and `;`/`&&`/`||` sequencing,
+//!   without spawning a user shell
+//! - A handful of built-in commands (`cd`, `export`, `echo`, `set`,
+//!   `unset`, `true`, `false`)
+
+pub mod interp;
+
+pub use interp::{ExecutionResult, Interpreter};
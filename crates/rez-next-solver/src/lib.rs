@@ -11,6 +11,7 @@
 // Temporarily simplified for compilation
 pub mod dependency_resolver;
 mod graph;
+pub mod pubgrub;
 mod solver;
 // mod resolution;
 // mod conflict;
@@ -20,6 +21,10 @@ mod solver;
 
 pub use dependency_resolver::*;
 pub use graph::*;
+pub use pubgrub::{
+    Conflict as PubGrubConflict, Derivation as PubGrubDerivation, InMemoryPackageSource,
+    PackageSource, Resolution as PubGrubResolution, Resolver as PubGrubResolver,
+};
 pub use solver::*;
 // pub use resolution::*;
 // pub use conflict::*;
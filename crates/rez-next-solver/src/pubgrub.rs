@@ -0,0 +1,462 @@
+//! A PubGrub-inspired dependency resolver over [`Package`] requirements.
+//!
+//! Real PubGrub reasons about requirements as algebraic version *ranges* and
+//! learns new incompatibilities by subtracting them. Here the repository
+//! side of the problem is an enumerable [`PackageSource`] (a handful of
+//! concrete candidate versions per name, same as a real package repository
+//! would return), so terms are tracked as "every requirement imposed on this
+//! package so far" rather than as a derived range. Unit propagation is the
+//! check against those accumulated requirements on every new derivation;
+//! conflict-driven backjumping is chronological backtracking over the
+//! single shared queue of pending requirements, which lets a conflict
+//! discovered deep in one branch (e.g. a shared transitive dependency)
+//! unwind all the way back to an earlier, still-open candidate choice
+//! rather than only the most recently opened one. The result is the same
+//! shape as PubGrub: either a consistent [`Solution`] or a [`Conflict`]
+//! carrying the chain of requirements that made the failing package
+//! unsatisfiable.
+
+use rez_next_common::RezCoreError;
+use rez_next_package::{Package, Requirement, RequirementKind};
+use rez_next_version::Version;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+/// A source of candidate package versions and their declared requirements,
+/// analogous to the repository callback a real resolver would query.
+pub trait PackageSource {
+    /// Every version known to exist for `name`. Order doesn't matter — the
+    /// resolver always tries newest-first.
+    fn candidate_versions(&self, name: &str) -> Vec<Version>;
+
+    /// The (already-parsed) `requires` of `name`@`version`.
+    fn requirements(&self, name: &str, version: &Version) -> Vec<Requirement>;
+
+    /// The `variants` of `name`@`version`: each entry is an alternative list
+    /// of extra requirement strings layered on top of `requirements`. An
+    /// empty list means "no variants", equivalent to a single empty variant.
+    fn variants(&self, name: &str, version: &Version) -> Vec<Vec<String>>;
+}
+
+/// The resolved `{package name -> version}` assignment.
+pub type Solution = HashMap<String, Version>;
+
+/// One link in the chain of requirements that led to a package being
+/// unsatisfiable: "`dependent`[@`dependent_version`] requires
+/// `requirement`".
+#[derive(Debug, Clone)]
+pub struct Derivation {
+    pub dependent: String,
+    pub dependent_version: Option<Version>,
+    pub requirement: Requirement,
+}
+
+impl fmt::Display for Derivation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.dependent_version {
+            Some(version) => write!(
+                f,
+                "{}-{} requires {}",
+                self.dependent,
+                version.as_str(),
+                self.requirement
+            ),
+            None => write!(f, "{} requires {}", self.dependent, self.requirement),
+        }
+    }
+}
+
+/// Why resolution failed: no candidate of `package` satisfied every
+/// requirement ever imposed on it.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub package: String,
+    pub derivations: Vec<Derivation>,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "no version of '{}' satisfies every requirement:",
+            self.package
+        )?;
+        for derivation in &self.derivations {
+            writeln!(f, "  because {}", derivation)?;
+        }
+        write!(f, "  so there is no version of '{}' to use", self.package)
+    }
+}
+
+/// The outcome of a resolve attempt.
+#[derive(Debug, Clone)]
+pub enum Resolution {
+    Solved(Solution),
+    Conflict(Conflict),
+}
+
+/// A requirement still waiting to be unit-propagated, along with who
+/// declared it (for the eventual [`Derivation`] / conflict explanation).
+#[derive(Clone)]
+struct Pending {
+    dependent: String,
+    dependent_version: Option<Version>,
+    requirement: Requirement,
+}
+
+/// Resolves a root [`Package`]'s dependency tree against a [`PackageSource`].
+pub struct Resolver<S: PackageSource> {
+    source: S,
+}
+
+impl<S: PackageSource> Resolver<S> {
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Resolve `root`, branching over its variants (if any) and backtracking
+    /// over candidate choices on conflict.
+    pub fn resolve(&self, root: &Package) -> Result<Resolution, RezCoreError> {
+        let root_requires = root.typed_requires()?;
+        let variant_sets: Vec<Vec<String>> = if root.variants.is_empty() {
+            vec![Vec::new()]
+        } else {
+            root.variants.clone()
+        };
+
+        let mut last_conflict = None;
+        for variant in &variant_sets {
+            let mut requirements = root_requires.clone();
+            if !Self::extend_with_variant(&mut requirements, variant) {
+                continue;
+            }
+
+            let pending: VecDeque<Pending> = requirements
+                .into_iter()
+                .map(|requirement| Pending {
+                    dependent: root.name.clone(),
+                    dependent_version: root.version.clone(),
+                    requirement,
+                })
+                .collect();
+
+            let mut solution = Solution::new();
+            // Assign the root up front so a dependency that requires it back
+            // is checked against its version like any other assigned package.
+            if let Some(ref version) = root.version {
+                solution.insert(root.name.clone(), version.clone());
+            }
+            let mut derivation_log: HashMap<String, Vec<Derivation>> = HashMap::new();
+            let mut stack = vec![root.name.clone()];
+
+            match self.resolve_queue(pending, &mut solution, &mut derivation_log, &mut stack) {
+                Ok(()) => {
+                    if let Some(ref version) = root.version {
+                        solution.insert(root.name.clone(), version.clone());
+                    }
+                    return Ok(Resolution::Solved(solution));
+                }
+                Err(conflict) => last_conflict = Some(conflict),
+            }
+        }
+
+        Ok(Resolution::Conflict(last_conflict.unwrap_or(Conflict {
+            package: root.name.clone(),
+            derivations: Vec::new(),
+        })))
+    }
+
+    /// Parse `variant`'s extra requirement strings onto `requirements`,
+    /// returning `false` if any of them fail to parse (the caller should
+    /// then skip this variant rather than use a partially-extended list).
+    fn extend_with_variant(requirements: &mut Vec<Requirement>, variant: &[String]) -> bool {
+        let mut extra = Vec::with_capacity(variant.len());
+        for s in variant {
+            match Requirement::parse(s, RequirementKind::Run) {
+                Ok(r) => extra.push(r),
+                Err(_) => return false,
+            }
+        }
+        requirements.extend(extra);
+        true
+    }
+
+    /// Unit-propagate the single requirement at the front of `pending`,
+    /// recursing on the rest. Keeping every sibling requirement in one
+    /// shared queue (rather than resolving each dependency's subtree to
+    /// completion before moving to the next) is what lets a conflict found
+    /// while satisfying a *later* requirement backtrack into an *earlier*
+    /// one's still-open candidate choice — the whole point of
+    /// conflict-driven backjumping over plain per-branch backtracking.
+    fn resolve_queue(
+        &self,
+        mut pending: VecDeque<Pending>,
+        solution: &mut Solution,
+        derivation_log: &mut HashMap<String, Vec<Derivation>>,
+        stack: &mut Vec<String>,
+    ) -> Result<(), Conflict> {
+        let Some(next) = pending.pop_front() else {
+            return Ok(());
+        };
+
+        let name = next.requirement.requirement.name.clone();
+        let origin = Derivation {
+            dependent: next.dependent,
+            dependent_version: next.dependent_version,
+            requirement: next.requirement.clone(),
+        };
+        derivation_log.entry(name.clone()).or_default().push(origin);
+
+        if let Some(existing) = solution.get(&name).cloned() {
+            return if next.requirement.matches(&existing) {
+                self.resolve_queue(pending, solution, derivation_log, stack)
+            } else {
+                Err(Conflict {
+                    package: name.clone(),
+                    derivations: derivation_log.get(&name).cloned().unwrap_or_default(),
+                })
+            };
+        }
+
+        if stack.contains(&name) {
+            // Cyclic dependency (A requires B, B requires A): accept
+            // optimistically rather than recursing forever. A cycle that's
+            // genuinely unsatisfiable is still caught once both ends are
+            // concretely assigned and cross-checked against each other.
+            return self.resolve_queue(pending, solution, derivation_log, stack);
+        }
+
+        let imposed = derivation_log.get(&name).cloned().unwrap_or_default();
+        let mut candidates = self.source.candidate_versions(&name);
+        candidates.sort_by(|a, b| b.cmp(a));
+
+        for candidate in &candidates {
+            if !imposed.iter().all(|d| d.requirement.matches(candidate)) {
+                continue;
+            }
+
+            let variant_sets = {
+                let variants = self.source.variants(&name, candidate);
+                if variants.is_empty() {
+                    vec![Vec::new()]
+                } else {
+                    variants
+                }
+            };
+            let base_requirements = self.source.requirements(&name, candidate);
+
+            for variant in &variant_sets {
+                let mut requirements = base_requirements.clone();
+                if !Self::extend_with_variant(&mut requirements, variant) {
+                    continue;
+                }
+
+                let solution_snapshot = solution.clone();
+                let derivation_snapshot = derivation_log.clone();
+
+                solution.insert(name.clone(), candidate.clone());
+                stack.push(name.clone());
+
+                let mut next_pending = pending.clone();
+                for requirement in requirements {
+                    next_pending.push_back(Pending {
+                        dependent: name.clone(),
+                        dependent_version: Some(candidate.clone()),
+                        requirement,
+                    });
+                }
+
+                let result = self.resolve_queue(next_pending, solution, derivation_log, stack);
+                stack.pop();
+
+                match result {
+                    Ok(()) => return Ok(()),
+                    Err(_) => {
+                        *solution = solution_snapshot;
+                        *derivation_log = derivation_snapshot;
+                    }
+                }
+            }
+        }
+
+        Err(Conflict {
+            package: name,
+            derivations: imposed,
+        })
+    }
+}
+
+/// A simple in-memory [`PackageSource`], handy for tests and for small
+/// embedded repositories that don't warrant a full [`Package`] per entry.
+#[derive(Default)]
+pub struct InMemoryPackageSource {
+    entries: HashMap<String, Vec<InMemoryEntry>>,
+}
+
+struct InMemoryEntry {
+    version: Version,
+    requires: Vec<String>,
+    variants: Vec<Vec<String>>,
+}
+
+impl InMemoryPackageSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a package version with no variants.
+    pub fn add(
+        &mut self,
+        name: &str,
+        version: &str,
+        requires: &[&str],
+    ) -> Result<&mut Self, RezCoreError> {
+        self.add_with_variants(name, version, requires, &[])
+    }
+
+    /// Register a package version with variants (each an extra list of
+    /// requirement strings layered on top of `requires`).
+    pub fn add_with_variants(
+        &mut self,
+        name: &str,
+        version: &str,
+        requires: &[&str],
+        variants: &[&[&str]],
+    ) -> Result<&mut Self, RezCoreError> {
+        let entry = InMemoryEntry {
+            version: Version::parse(version)?,
+            requires: requires.iter().map(|s| s.to_string()).collect(),
+            variants: variants
+                .iter()
+                .map(|variant| variant.iter().map(|s| s.to_string()).collect())
+                .collect(),
+        };
+        self.entries.entry(name.to_string()).or_default().push(entry);
+        Ok(self)
+    }
+}
+
+impl PackageSource for InMemoryPackageSource {
+    fn candidate_versions(&self, name: &str) -> Vec<Version> {
+        self.entries
+            .get(name)
+            .map(|entries| entries.iter().map(|e| e.version.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    fn requirements(&self, name: &str, version: &Version) -> Vec<Requirement> {
+        self.entries
+            .get(name)
+            .and_then(|entries| entries.iter().find(|e| &e.version == version))
+            .map(|entry| {
+                entry
+                    .requires
+                    .iter()
+                    .filter_map(|s| Requirement::parse(s, RequirementKind::Run).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn variants(&self, name: &str, version: &Version) -> Vec<Vec<String>> {
+        self.entries
+            .get(name)
+            .and_then(|entries| entries.iter().find(|e| &e.version == version))
+            .map(|entry| entry.variants.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_simple_chain() {
+        let mut source = InMemoryPackageSource::new();
+        source.add("maya", "2023.0.0", &["python>=3.9"]).unwrap();
+        source.add("python", "3.9.0", &[]).unwrap();
+        source.add("python", "2.7.0", &[]).unwrap();
+
+        let mut root = Package::new("myapp".to_string());
+        root.requires = vec!["maya>=2023".to_string()];
+
+        let resolver = Resolver::new(source);
+        match resolver.resolve(&root).unwrap() {
+            Resolution::Solved(solution) => {
+                assert_eq!(solution.get("maya").unwrap().as_str(), "2023.0.0");
+                assert_eq!(solution.get("python").unwrap().as_str(), "3.9.0");
+            }
+            Resolution::Conflict(c) => panic!("expected solution, got conflict: {}", c),
+        }
+    }
+
+    #[test]
+    fn test_conflict_reports_derivation_chain() {
+        let mut source = InMemoryPackageSource::new();
+        source.add("maya", "2023.0.0", &["python>=3.9"]).unwrap();
+        source.add("python", "2.7.0", &[]).unwrap();
+
+        let mut root = Package::new("myapp".to_string());
+        root.requires = vec!["maya>=2023".to_string()];
+
+        let resolver = Resolver::new(source);
+        match resolver.resolve(&root).unwrap() {
+            Resolution::Solved(_) => panic!("expected conflict"),
+            Resolution::Conflict(c) => {
+                assert_eq!(c.package, "python");
+                assert!(c.derivations.iter().any(|d| d.dependent == "maya"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_backtracks_over_incompatible_newest_candidate() {
+        let mut source = InMemoryPackageSource::new();
+        // Newest maya needs a python too new for houdini; the shared
+        // `python` conflict should unwind all the way back to maya's
+        // candidate choice, not just fail outright.
+        source.add("maya", "2024.0.0", &["python>=3.11"]).unwrap();
+        source
+            .add("maya", "2023.0.0", &["python>=3.9,<3.10"])
+            .unwrap();
+        source
+            .add("houdini", "19.0.0", &["python>=3.9,<3.10"])
+            .unwrap();
+        source.add("python", "3.9.0", &[]).unwrap();
+        source.add("python", "3.11.0", &[]).unwrap();
+
+        let mut root = Package::new("myapp".to_string());
+        root.requires = vec!["maya>=2023".to_string(), "houdini>=19".to_string()];
+
+        let resolver = Resolver::new(source);
+        match resolver.resolve(&root).unwrap() {
+            Resolution::Solved(solution) => {
+                assert_eq!(solution.get("maya").unwrap().as_str(), "2023.0.0");
+                assert_eq!(solution.get("python").unwrap().as_str(), "3.9.0");
+            }
+            Resolution::Conflict(c) => panic!("expected solution, got conflict: {}", c),
+        }
+    }
+
+    #[test]
+    fn test_branches_over_root_variants() {
+        let mut source = InMemoryPackageSource::new();
+        source.add("python", "2.7.0", &[]).unwrap();
+        source.add("python", "3.9.0", &[]).unwrap();
+
+        let mut root = Package::new("myapp".to_string());
+        root.variants = vec![
+            vec!["python-2.7".to_string()],
+            vec!["python-3.9".to_string()],
+        ];
+
+        let resolver = Resolver::new(source);
+        match resolver.resolve(&root).unwrap() {
+            Resolution::Solved(solution) => {
+                assert_eq!(solution.get("python").unwrap().as_str(), "2.7.0");
+            }
+            Resolution::Conflict(c) => panic!("expected solution, got conflict: {}", c),
+        }
+    }
+}
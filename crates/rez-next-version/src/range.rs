@@ -1,14 +1,422 @@
 //! Version range implementation
+//!
+//! A [`VersionRange`] normalizes into a sorted, coalesced union of disjoint
+//! [`Interval`]s. Parsing lowers a `||`-separated set of clauses (each
+//! itself a comma-separated, intersected set of comparators/shorthand) into
+//! that form up front, so `contains`/`intersects`/`intersect` only ever
+//! have to reason about flat interval arithmetic rather than re-parsing or
+//! special-casing the original syntax.
 
 use super::Version;
 use rez_next_common::RezCoreError;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
-/// Version range representation
+/// One side of an [`Interval`]. `Unbounded` means no constraint on that
+/// side; `Inclusive`/`Exclusive` wrap the boundary [`Version`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum Bound {
+    Unbounded,
+    Inclusive(Version),
+    Exclusive(Version),
+}
+
+/// A single contiguous, non-empty span of versions.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Interval {
+    lower: Bound,
+    upper: Bound,
+}
+
+/// `major[.minor[.patch]]` components of a version string, used only to
+/// compute `~`/`^`/wildcard/bare-version upper bounds without depending on
+/// a `Version::next()`-style method.
+struct VersionParts {
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+}
+
+impl VersionParts {
+    fn parse(s: &str) -> Result<Self, RezCoreError> {
+        let mut parts = s.split('.');
+        let major = parts
+            .next()
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| RezCoreError::VersionParse(format!("Invalid version '{}'", s)))?
+            .parse::<u64>()
+            .map_err(|_| RezCoreError::VersionParse(format!("Invalid version '{}'", s)))?;
+        let minor = parts
+            .next()
+            .map(|p| p.parse::<u64>())
+            .transpose()
+            .map_err(|_| RezCoreError::VersionParse(format!("Invalid version '{}'", s)))?;
+        let patch = parts
+            .next()
+            .map(|p| p.parse::<u64>())
+            .transpose()
+            .map_err(|_| RezCoreError::VersionParse(format!("Invalid version '{}'", s)))?;
+        if parts.next().is_some() {
+            return Err(RezCoreError::VersionParse(format!(
+                "Invalid version '{}': at most major.minor.patch",
+                s
+            )));
+        }
+        Ok(Self { major, minor, patch })
+    }
+
+    fn to_version_string(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            self.major,
+            self.minor.unwrap_or(0),
+            self.patch.unwrap_or(0)
+        )
+    }
+
+    /// The caret (`^`) upper bound: bump the left-most non-zero component
+    /// and zero everything after it (`^1.2.3` -> `2.0.0`, `^0.2.3` ->
+    /// `0.3.0`, `^0.0.3` -> `0.0.4`).
+    fn caret_upper(&self) -> Self {
+        let minor = self.minor.unwrap_or(0);
+        let patch = self.patch.unwrap_or(0);
+        if self.major > 0 {
+            Self { major: self.major + 1, minor: Some(0), patch: Some(0) }
+        } else if minor > 0 {
+            Self { major: 0, minor: Some(minor + 1), patch: Some(0) }
+        } else {
+            Self { major: 0, minor: Some(minor), patch: Some(patch + 1) }
+        }
+    }
+
+    /// The tilde (`~`) / wildcard upper bound: bump minor if given (`~1.2`,
+    /// `1.2.*` -> `1.3.0`), else bump major (`~1`, `1.*` -> `2.0.0`).
+    fn tilde_upper(&self) -> Self {
+        match self.minor {
+            Some(minor) => Self { major: self.major, minor: Some(minor + 1), patch: Some(0) },
+            None => Self { major: self.major + 1, minor: Some(0), patch: Some(0) },
+        }
+    }
+
+    /// The bare-version upper bound: bump the most precise component given
+    /// (`1.2.3` -> `1.2.4`, `1.2` -> `1.3.0`, `1` -> `2.0.0`).
+    fn bump_last_given(&self) -> Self {
+        if let Some(patch) = self.patch {
+            Self { major: self.major, minor: self.minor, patch: Some(patch + 1) }
+        } else if let Some(minor) = self.minor {
+            Self { major: self.major, minor: Some(minor + 1), patch: Some(0) }
+        } else {
+            Self { major: self.major + 1, minor: Some(0), patch: Some(0) }
+        }
+    }
+}
+
+/// Compares two [`Bound`]s as lower-bound positions (`Unbounded` is
+/// `-infinity`; at equal versions, `Inclusive` sorts before `Exclusive`
+/// since it admits that boundary point and `Exclusive` doesn't).
+fn cmp_lower(a: &Bound, b: &Bound) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Inclusive(v1), Bound::Inclusive(v2)) => v1.cmp(v2),
+        (Bound::Exclusive(v1), Bound::Exclusive(v2)) => v1.cmp(v2),
+        (Bound::Inclusive(v1), Bound::Exclusive(v2)) => match v1.cmp(v2) {
+            Ordering::Equal => Ordering::Less,
+            other => other,
+        },
+        (Bound::Exclusive(v1), Bound::Inclusive(v2)) => match v1.cmp(v2) {
+            Ordering::Equal => Ordering::Greater,
+            other => other,
+        },
+    }
+}
+
+/// Compares two [`Bound`]s as upper-bound positions (`Unbounded` is
+/// `+infinity`; at equal versions, `Inclusive` sorts after `Exclusive`).
+fn cmp_upper(a: &Bound, b: &Bound) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Inclusive(v1), Bound::Inclusive(v2)) => v1.cmp(v2),
+        (Bound::Exclusive(v1), Bound::Exclusive(v2)) => v1.cmp(v2),
+        (Bound::Inclusive(v1), Bound::Exclusive(v2)) => match v1.cmp(v2) {
+            Ordering::Equal => Ordering::Greater,
+            other => other,
+        },
+        (Bound::Exclusive(v1), Bound::Inclusive(v2)) => match v1.cmp(v2) {
+            Ordering::Equal => Ordering::Less,
+            other => other,
+        },
+    }
+}
+
+/// Whether an interval ending at `upper` and one starting at `lower` share
+/// at least one point, i.e. whether unioning them leaves no gap. This is
+/// intentionally symmetric in how it's used: `touches_or_overlaps(a, b) &&
+/// touches_or_overlaps(b, a)` (with the roles swapped) tells you whether
+/// two arbitrary intervals overlap regardless of which comes first.
+fn touches_or_overlaps(upper: &Bound, lower: &Bound) -> bool {
+    let (u, u_inclusive) = match upper {
+        Bound::Unbounded => return true,
+        Bound::Inclusive(v) => (v, true),
+        Bound::Exclusive(v) => (v, false),
+    };
+    let (l, l_inclusive) = match lower {
+        Bound::Unbounded => return true,
+        Bound::Inclusive(v) => (v, true),
+        Bound::Exclusive(v) => (v, false),
+    };
+    match u.cmp(l) {
+        Ordering::Greater => true,
+        Ordering::Less => false,
+        // Touching at the same version: only a gap-free union if at least
+        // one side actually includes that version.
+        Ordering::Equal => u_inclusive || l_inclusive,
+    }
+}
+
+/// Whether `[lower, upper]` describes a non-empty span.
+fn interval_is_valid(lower: &Bound, upper: &Bound) -> bool {
+    let lv = match lower {
+        Bound::Unbounded => return true,
+        Bound::Inclusive(v) | Bound::Exclusive(v) => v,
+    };
+    let (uv, u_inclusive) = match upper {
+        Bound::Unbounded => return true,
+        Bound::Inclusive(v) => (v, true),
+        Bound::Exclusive(v) => (v, false),
+    };
+    let l_inclusive = matches!(lower, Bound::Inclusive(_));
+    match lv.cmp(uv) {
+        Ordering::Less => true,
+        Ordering::Equal => l_inclusive && u_inclusive,
+        Ordering::Greater => false,
+    }
+}
+
+impl Interval {
+    fn any() -> Self {
+        Self { lower: Bound::Unbounded, upper: Bound::Unbounded }
+    }
+
+    fn contains(&self, version: &Version) -> bool {
+        let lower_ok = match &self.lower {
+            Bound::Unbounded => true,
+            Bound::Inclusive(b) => version >= b,
+            Bound::Exclusive(b) => version > b,
+        };
+        let upper_ok = match &self.upper {
+            Bound::Unbounded => true,
+            Bound::Inclusive(b) => version <= b,
+            Bound::Exclusive(b) => version < b,
+        };
+        lower_ok && upper_ok
+    }
+
+    fn overlaps(&self, other: &Interval) -> bool {
+        touches_or_overlaps(&self.upper, &other.lower) && touches_or_overlaps(&other.upper, &self.lower)
+    }
+
+    fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let lower = if cmp_lower(&self.lower, &other.lower) == Ordering::Less {
+            other.lower.clone()
+        } else {
+            self.lower.clone()
+        };
+        let upper = if cmp_upper(&self.upper, &other.upper) == Ordering::Greater {
+            other.upper.clone()
+        } else {
+            self.upper.clone()
+        };
+        interval_is_valid(&lower, &upper).then_some(Interval { lower, upper })
+    }
+
+    /// Remove the single point `v` from this interval, `!=v`-style,
+    /// splitting it into the (up to two) pieces either side of `v`. Returns
+    /// `self` unchanged, as the sole element, when `v` isn't inside it.
+    fn subtract_point(&self, v: &Version) -> Vec<Interval> {
+        if !self.contains(v) {
+            return vec![self.clone()];
+        }
+        let mut pieces = Vec::with_capacity(2);
+        let left = Interval { lower: self.lower.clone(), upper: Bound::Exclusive(v.clone()) };
+        if interval_is_valid(&left.lower, &left.upper) {
+            pieces.push(left);
+        }
+        let right = Interval { lower: Bound::Exclusive(v.clone()), upper: self.upper.clone() };
+        if interval_is_valid(&right.lower, &right.upper) {
+            pieces.push(right);
+        }
+        pieces
+    }
+
+    /// Render as a standalone range string (`1.0.0+,<2.0.0`, `==1.0.0`,
+    /// `1.0.0+`, `<2.0.0`, or `""` for the unbounded "any" interval).
+    fn to_range_string(&self) -> String {
+        match (&self.lower, &self.upper) {
+            (Bound::Unbounded, Bound::Unbounded) => String::new(),
+            (lower, Bound::Unbounded) => Self::lower_clause(lower),
+            (Bound::Unbounded, upper) => Self::upper_clause(upper),
+            (Bound::Inclusive(l), Bound::Inclusive(u)) if l == u => format!("=={}", l.as_str()),
+            (lower, upper) => format!("{},{}", Self::lower_clause(lower), Self::upper_clause(upper)),
+        }
+    }
+
+    fn lower_clause(lower: &Bound) -> String {
+        match lower {
+            Bound::Unbounded => String::new(),
+            Bound::Inclusive(v) => format!("{}+", v.as_str()),
+            Bound::Exclusive(v) => format!(">{}", v.as_str()),
+        }
+    }
+
+    fn upper_clause(upper: &Bound) -> String {
+        match upper {
+            Bound::Unbounded => String::new(),
+            Bound::Inclusive(v) => format!("<={}", v.as_str()),
+            Bound::Exclusive(v) => format!("<{}", v.as_str()),
+        }
+    }
+}
+
+enum Token {
+    /// A comparator/shorthand that constrains the accumulated interval set
+    /// by intersection.
+    Bound(Interval),
+    /// A `!=version` comparator, which subtracts a single point instead.
+    NotEqual(Version),
+}
+
+fn parse_token(token: &str) -> Result<Token, RezCoreError> {
+    let token = token.trim();
+
+    if let Some(rest) = token.strip_prefix("!=") {
+        return Ok(Token::NotEqual(Version::parse(rest)?));
+    }
+    if let Some(rest) = token.strip_prefix(">=") {
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Inclusive(Version::parse(rest)?),
+            upper: Bound::Unbounded,
+        }));
+    }
+    if let Some(rest) = token.strip_prefix('>') {
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Exclusive(Version::parse(rest)?),
+            upper: Bound::Unbounded,
+        }));
+    }
+    if let Some(rest) = token.strip_prefix("<=") {
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Unbounded,
+            upper: Bound::Inclusive(Version::parse(rest)?),
+        }));
+    }
+    if let Some(rest) = token.strip_prefix('<') {
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Unbounded,
+            upper: Bound::Exclusive(Version::parse(rest)?),
+        }));
+    }
+    if let Some(rest) = token.strip_prefix("==") {
+        let v = Version::parse(rest)?;
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Inclusive(v.clone()),
+            upper: Bound::Inclusive(v),
+        }));
+    }
+    if let Some(rest) = token.strip_suffix('+') {
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Inclusive(Version::parse(rest)?),
+            upper: Bound::Unbounded,
+        }));
+    }
+    if token == "*" {
+        return Ok(Token::Bound(Interval::any()));
+    }
+    if let Some(rest) = token.strip_prefix('~') {
+        let parts = VersionParts::parse(rest)?;
+        let lower = Version::parse(&parts.to_version_string())?;
+        let upper = Version::parse(&parts.tilde_upper().to_version_string())?;
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Inclusive(lower),
+            upper: Bound::Exclusive(upper),
+        }));
+    }
+    if let Some(rest) = token.strip_prefix('^') {
+        let parts = VersionParts::parse(rest)?;
+        let lower = Version::parse(&parts.to_version_string())?;
+        let upper = Version::parse(&parts.caret_upper().to_version_string())?;
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Inclusive(lower),
+            upper: Bound::Exclusive(upper),
+        }));
+    }
+    if let Some(prefix) = token.strip_suffix(".*") {
+        let parts = VersionParts::parse(prefix)?;
+        let lower = Version::parse(&parts.to_version_string())?;
+        let upper = Version::parse(&parts.tilde_upper().to_version_string())?;
+        return Ok(Token::Bound(Interval {
+            lower: Bound::Inclusive(lower),
+            upper: Bound::Exclusive(upper),
+        }));
+    }
+
+    // Bare version, e.g. "1.2.3": matches that version and anything more
+    // precise under it, up to (not including) the next bump of its most
+    // precise given component.
+    let parts = VersionParts::parse(token)?;
+    let lower = Version::parse(&parts.to_version_string())?;
+    let upper = Version::parse(&parts.bump_last_given().to_version_string())?;
+    Ok(Token::Bound(Interval {
+        lower: Bound::Inclusive(lower),
+        upper: Bound::Exclusive(upper),
+    }))
+}
+
+/// Intersect a `,`-separated clause of comparators into zero or more
+/// [`Interval`]s (more than one only when a `!=` splits the set).
+fn parse_clause(clause: &str) -> Result<Vec<Interval>, RezCoreError> {
+    let mut accumulated = vec![Interval::any()];
+    for token in clause.split(',') {
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(RezCoreError::VersionParse(format!(
+                "Empty comparator in version range clause '{}'",
+                clause
+            )));
+        }
+        match parse_token(token)? {
+            Token::Bound(bound) => {
+                accumulated = accumulated
+                    .iter()
+                    .filter_map(|interval| interval.intersect(&bound))
+                    .collect();
+            }
+            Token::NotEqual(v) => {
+                accumulated = accumulated
+                    .into_iter()
+                    .flat_map(|interval| interval.subtract_point(&v))
+                    .collect();
+            }
+        }
+        if accumulated.is_empty() {
+            break;
+        }
+    }
+    Ok(accumulated)
+}
+
+/// Version range representation: a sorted, coalesced union of disjoint
+/// [`Interval`]s.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VersionRange {
-    /// Cached string representation
-    pub range_str: String,
+    intervals: Vec<Interval>,
+    /// Cached string representation, derived from `intervals` so
+    /// round-tripping through [`Self::parse`]/[`Self::as_str`] is stable
+    /// regardless of how the original string was written.
+    range_str: String,
 }
 
 impl VersionRange {
@@ -17,19 +425,66 @@ impl VersionRange {
         Self::parse(&range_str)
     }
 
-    /// Parse a version range string
+    /// Parse a version range string: a `||`-separated set of clauses, each
+    /// a `,`-separated, intersected set of comparators (`==`, `>=`, `>`,
+    /// `<=`, `<`, `!=`) and shorthand (`~1.2`, `^1.2.3`, `1.2.*`, `*`, or a
+    /// bare version). An empty string is the universal "any" range.
     pub fn parse(range_str: &str) -> Result<Self, RezCoreError> {
-        // For now, implement basic parsing
-        // This is a simplified implementation
-        Ok(VersionRange {
-            range_str: range_str.to_string(),
-        })
+        if range_str.trim().is_empty() {
+            return Ok(Self::from_intervals(vec![Interval::any()]));
+        }
+
+        let mut intervals = Vec::new();
+        for clause in range_str.split("||") {
+            let clause = clause.trim();
+            if clause.is_empty() {
+                return Err(RezCoreError::VersionParse(format!(
+                    "Empty clause in version range '{}'",
+                    range_str
+                )));
+            }
+            intervals.extend(parse_clause(clause)?);
+        }
+        Ok(Self::from_intervals(intervals))
+    }
+
+    /// Sort and coalesce `intervals` into their normalized disjoint form,
+    /// merging any that touch or overlap, and derive `range_str` from the
+    /// result.
+    fn from_intervals(mut intervals: Vec<Interval>) -> Self {
+        intervals.sort_by(|a, b| cmp_lower(&a.lower, &b.lower));
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if last.overlaps(&interval) => {
+                    if cmp_upper(&interval.upper, &last.upper) == Ordering::Greater {
+                        last.upper = interval.upper;
+                    }
+                }
+                _ => merged.push(interval),
+            }
+        }
+
+        let range_str = if merged.is_empty() {
+            // A contradictory range (e.g. "==1.0.0,==2.0.0") that matches
+            // no version at all; doesn't round-trip through `parse`, but
+            // that input wouldn't either.
+            "<none>".to_string()
+        } else {
+            merged
+                .iter()
+                .map(Interval::to_range_string)
+                .collect::<Vec<_>>()
+                .join("||")
+        };
+
+        Self { intervals: merged, range_str }
     }
 
     /// Check if a version satisfies this range
-    pub fn contains(&self, _version: &Version) -> bool {
-        // Simplified implementation - always returns true for now
-        true
+    pub fn contains(&self, version: &Version) -> bool {
+        self.intervals.iter().any(|interval| interval.contains(version))
     }
 
     /// Get the string representation
@@ -38,20 +493,28 @@ impl VersionRange {
     }
 
     /// Check if this range intersects with another range
-    pub fn intersects(&self, _other: &VersionRange) -> bool {
-        // Simplified implementation - always returns true for now
-        true
+    pub fn intersects(&self, other: &VersionRange) -> bool {
+        self.intervals
+            .iter()
+            .any(|a| other.intervals.iter().any(|b| a.overlaps(b)))
     }
 
     /// Compute the intersection of two ranges
-    pub fn intersect(&self, _other: &VersionRange) -> Option<VersionRange> {
-        // Simplified implementation - return the first range
-        Some(self.clone())
+    pub fn intersect(&self, other: &VersionRange) -> Option<VersionRange> {
+        let intervals: Vec<Interval> = self
+            .intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(move |b| a.intersect(b)))
+            .collect();
+        if intervals.is_empty() {
+            return None;
+        }
+        Some(Self::from_intervals(intervals))
     }
 
     /// Check if this range is the "any" range (matches all versions)
     pub fn is_any(&self) -> bool {
-        self.range_str.is_empty()
+        matches!(self.intervals.as_slice(), [Interval { lower: Bound::Unbounded, upper: Bound::Unbounded }])
     }
 }
 
@@ -61,25 +524,87 @@ mod tests {
 
     #[test]
     fn test_version_range_parsing() {
-        // Test empty range (any)
         let range = VersionRange::parse("").unwrap();
         assert!(range.is_any());
 
-        // Test exact version
         let range = VersionRange::parse("==1.0.0").unwrap();
-        assert_eq!(range.range_str, "==1.0.0");
+        assert_eq!(range.as_str(), "==1.0.0");
 
-        // Test greater than or equal
         let range = VersionRange::parse(">=1.0.0").unwrap();
-        assert_eq!(range.range_str, ">=1.0.0");
+        assert_eq!(range.as_str(), "1.0.0+");
+    }
+
+    #[test]
+    fn test_contains_compound_range() {
+        let range = VersionRange::parse(">=1.0.0,<2.0.0").unwrap();
+        assert!(range.contains(&Version::parse("1.0.0").unwrap()));
+        assert!(range.contains(&Version::parse("1.9.9").unwrap()));
+        assert!(!range.contains(&Version::parse("2.0.0").unwrap()));
+        assert!(!range.contains(&Version::parse("0.9.0").unwrap()));
+    }
+
+    #[test]
+    fn test_tilde_and_caret_shorthand() {
+        let tilde = VersionRange::parse("~1.2.3").unwrap();
+        assert!(tilde.contains(&Version::parse("1.2.9").unwrap()));
+        assert!(!tilde.contains(&Version::parse("1.3.0").unwrap()));
+
+        let caret = VersionRange::parse("^1.2.3").unwrap();
+        assert!(caret.contains(&Version::parse("1.9.9").unwrap()));
+        assert!(!caret.contains(&Version::parse("2.0.0").unwrap()));
+
+        let caret_zero_major = VersionRange::parse("^0.2.3").unwrap();
+        assert!(caret_zero_major.contains(&Version::parse("0.2.9").unwrap()));
+        assert!(!caret_zero_major.contains(&Version::parse("0.3.0").unwrap()));
+    }
+
+    #[test]
+    fn test_wildcard_shorthand() {
+        let range = VersionRange::parse("1.2.*").unwrap();
+        assert!(range.contains(&Version::parse("1.2.5").unwrap()));
+        assert!(!range.contains(&Version::parse("1.3.0").unwrap()));
+
+        let any = VersionRange::parse("*").unwrap();
+        assert!(any.is_any());
+    }
+
+    #[test]
+    fn test_not_equal_splits_interval() {
+        let range = VersionRange::parse(">=1.0.0,<2.0.0,!=1.5.0").unwrap();
+        assert!(range.contains(&Version::parse("1.4.0").unwrap()));
+        assert!(!range.contains(&Version::parse("1.5.0").unwrap()));
+        assert!(range.contains(&Version::parse("1.6.0").unwrap()));
+    }
+
+    #[test]
+    fn test_or_union_of_clauses() {
+        let range = VersionRange::parse("<1.0.0||>=2.0.0").unwrap();
+        assert!(range.contains(&Version::parse("0.5.0").unwrap()));
+        assert!(!range.contains(&Version::parse("1.5.0").unwrap()));
+        assert!(range.contains(&Version::parse("2.5.0").unwrap()));
+    }
+
+    #[test]
+    fn test_coalesces_touching_intervals() {
+        // ">=2.0.0" and "<2.0.0" together cover every version with no gap,
+        // since one side includes the boundary; they should coalesce into
+        // a single "any" range rather than staying as two intervals.
+        let range = VersionRange::parse("<2.0.0||>=2.0.0").unwrap();
+        assert!(range.is_any());
     }
 
     #[test]
-    fn test_version_range_intersect() {
+    fn test_intersects_and_intersect() {
         let range1 = VersionRange::parse(">=1.0.0").unwrap();
         let range2 = VersionRange::parse("<=2.0.0").unwrap();
+        let range3 = VersionRange::parse(">=3.0.0").unwrap();
+
+        assert!(range1.intersects(&range2));
+        assert!(!range2.intersects(&range3));
 
         let intersection = range1.intersect(&range2).unwrap();
-        assert_eq!(intersection.range_str, ">=1.0.0");
+        assert!(intersection.contains(&Version::parse("1.5.0").unwrap()));
+        assert!(!intersection.contains(&Version::parse("2.5.0").unwrap()));
+        assert!(range2.intersect(&range3).is_none());
     }
 }
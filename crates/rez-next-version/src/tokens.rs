@@ -0,0 +1,9 @@
+//! Version token types, re-exported under a shorter path.
+//!
+//! This module exists so fuzzing/property-testing support can live
+//! alongside the token types without growing `version_token.rs` itself.
+
+pub use crate::version_token::{AlphanumericVersionToken, NumericToken, SubToken, VersionToken};
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
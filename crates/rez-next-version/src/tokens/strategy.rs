@@ -0,0 +1,126 @@
+//! `proptest` generators for version tokens, plus the property tests that
+//! exercise the comparison contract against them.
+//!
+//! The generators here are reusable by downstream fuzz/property tests;
+//! the `proptests` module below applies them to the contract this crate
+//! itself relies on (trichotomy, antisymmetry, transitivity, round-trip
+//! formatting, and the `next()` invariant).
+
+use crate::version_token::{AlphanumericVersionToken, NumericToken};
+use proptest::prelude::*;
+
+/// Generates valid `NumericToken` strings: a non-negative integer,
+/// optionally zero-padded. Shrinks toward smaller integers and shorter
+/// padding, so a failing case minimizes toward something like `"0"`.
+pub fn numeric_token_string() -> impl Strategy<Value = String> {
+    (0u64..1_000_000, 0usize..4).prop_map(|(n, extra_padding)| {
+        let width = n.to_string().len() + extra_padding;
+        format!("{:0width$}", n, width = width)
+    })
+}
+
+/// Generates valid `AlphanumericVersionToken` strings matching
+/// `[a-zA-Z0-9_]+`. Shrinks by dropping subtokens and shortening the
+/// alpha/numeric runs within them.
+pub fn alphanumeric_token_string() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop_oneof!["[a-zA-Z_]{1,4}", "[0-9]{1,4}"], 1..4)
+        .prop_map(|parts| parts.concat())
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+
+    fn numeric(s: String) -> NumericToken {
+        NumericToken::new(s).unwrap().0
+    }
+
+    fn alphanumeric(s: String) -> AlphanumericVersionToken {
+        AlphanumericVersionToken::new(s).unwrap().0
+    }
+
+    proptest! {
+        #[test]
+        fn numeric_token_trichotomy(a in numeric_token_string(), b in numeric_token_string()) {
+            let (ta, tb) = (numeric(a), numeric(b));
+            let outcomes = [ta.less_than(&tb), tb.less_than(&ta), ta.__eq__(&tb)];
+            prop_assert_eq!(outcomes.iter().filter(|ok| **ok).count(), 1);
+        }
+
+        #[test]
+        fn numeric_token_antisymmetry(a in numeric_token_string(), b in numeric_token_string()) {
+            let (ta, tb) = (numeric(a), numeric(b));
+            if ta.less_than(&tb) {
+                prop_assert!(!tb.less_than(&ta));
+            }
+        }
+
+        #[test]
+        fn numeric_token_transitivity(
+            a in numeric_token_string(),
+            b in numeric_token_string(),
+            c in numeric_token_string(),
+        ) {
+            let (ta, tb, tc) = (numeric(a), numeric(b), numeric(c));
+            if ta.less_than(&tb) && tb.less_than(&tc) {
+                prop_assert!(ta.less_than(&tc));
+            }
+        }
+
+        #[test]
+        fn numeric_token_round_trips(s in numeric_token_string()) {
+            let token = numeric(s.clone());
+            // `NumericToken` renders its integer value, so zero padding in
+            // the generated string does not survive the round trip.
+            prop_assert_eq!(token.__str__(), s.parse::<u64>().unwrap().to_string());
+        }
+
+        #[test]
+        fn numeric_token_next_is_strictly_greater(s in numeric_token_string()) {
+            let token = numeric(s);
+            let next_token: NumericToken = pyo3::Python::with_gil(|py| {
+                token.next().unwrap().extract(py).unwrap()
+            });
+            prop_assert!(token.less_than(&next_token));
+        }
+
+        #[test]
+        fn alphanumeric_token_trichotomy(
+            a in alphanumeric_token_string(),
+            b in alphanumeric_token_string(),
+        ) {
+            let (ta, tb) = (alphanumeric(a), alphanumeric(b));
+            let outcomes = [ta.less_than(&tb), tb.less_than(&ta), ta.__eq__(&tb)];
+            prop_assert_eq!(outcomes.iter().filter(|ok| **ok).count(), 1);
+        }
+
+        #[test]
+        fn alphanumeric_token_antisymmetry(
+            a in alphanumeric_token_string(),
+            b in alphanumeric_token_string(),
+        ) {
+            let (ta, tb) = (alphanumeric(a), alphanumeric(b));
+            if ta.less_than(&tb) {
+                prop_assert!(!tb.less_than(&ta));
+            }
+        }
+
+        #[test]
+        fn alphanumeric_token_transitivity(
+            a in alphanumeric_token_string(),
+            b in alphanumeric_token_string(),
+            c in alphanumeric_token_string(),
+        ) {
+            let (ta, tb, tc) = (alphanumeric(a), alphanumeric(b), alphanumeric(c));
+            if ta.less_than(&tb) && tb.less_than(&tc) {
+                prop_assert!(ta.less_than(&tc));
+            }
+        }
+
+        #[test]
+        fn alphanumeric_token_round_trips(s in alphanumeric_token_string()) {
+            let token = alphanumeric(s.clone());
+            prop_assert_eq!(token.__str__(), s);
+        }
+    }
+}
@@ -3,12 +3,13 @@
 //! A high-performance command-line interface for the Rez package manager,
 //! built with Rust for optimal performance.
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use std::process;
 use std::env;
 
 // Import CLI from the library
 use rez_core::cli::{RezCli, RezCommand};
+use rez_core::common::RezCoreConfig;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -18,10 +19,72 @@ fn main() {
         handle_grouped_command(args);
     } else {
         // Standard argument parsing
-        let cli = RezCli::parse();
-        if let Err(e) = cli.run() {
-            eprintln!("Error: {}", e);
-            process::exit(1);
+        match RezCli::try_parse_from(&args) {
+            Ok(cli) => {
+                if let Err(e) = cli.run() {
+                    eprintln!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+            Err(e) => handle_parse_error(e, &args),
+        }
+    }
+}
+
+/// Handle a clap parse failure for the top-level CLI: if it's an
+/// unrecognized-subcommand error, try expanding it as a config-defined
+/// alias (see `rez config aliases`) and re-dispatch; otherwise fall back
+/// to clap's error plus a "did you mean" suggestion.
+fn handle_parse_error(err: clap::Error, args: &[String]) {
+    if err.kind() == clap::error::ErrorKind::InvalidSubcommand {
+        if let Some(attempted) = args.get(1) {
+            if let Some(expanded) = resolve_alias(attempted) {
+                let mut new_args = vec![args[0].clone()];
+                new_args.extend(expanded);
+                new_args.extend_from_slice(&args[2..]);
+
+                match RezCli::try_parse_from(&new_args) {
+                    Ok(cli) => {
+                        if let Err(e) = cli.run() {
+                            eprintln!("Error: {}", e);
+                            process::exit(1);
+                        }
+                        return;
+                    }
+                    Err(e) => {
+                        eprintln!("Error expanding alias '{}': {}", attempted, e);
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+    }
+
+    err.print().ok();
+    print_suggestion(args);
+    process::exit(1);
+}
+
+/// Resolve `attempted` against the configured `[aliases]` table, if any.
+fn resolve_alias(attempted: &str) -> Option<Vec<String>> {
+    let config = RezCoreConfig::load();
+    let known_commands: Vec<String> = RezCli::command()
+        .get_subcommands()
+        .map(|cmd| cmd.get_name().to_string())
+        .collect();
+    config.resolve_alias(attempted, &known_commands)
+}
+
+/// Print a "did you mean ...?" suggestion for an unrecognized subcommand.
+fn print_suggestion(args: &[String]) {
+    if let Some(attempted) = args.get(1) {
+        let known_commands: Vec<String> = RezCli::command()
+            .get_subcommands()
+            .map(|cmd| cmd.get_name().to_string())
+            .collect();
+        if let Some(suggestion) = rez_core::cli::utils::suggest_closest(attempted, &known_commands)
+        {
+            eprintln!("  did you mean `{}`?", suggestion);
         }
     }
 }
@@ -75,7 +138,19 @@ fn handle_grouped_command(args: Vec<String>) {
             }
         }
         Err(e) => {
+            if e.kind() == clap::error::ErrorKind::InvalidSubcommand {
+                if let Some(attempted) = args.get(1) {
+                    if let Some(expanded) = resolve_alias(attempted) {
+                        let mut new_args = vec![args[0].clone()];
+                        new_args.extend(expanded);
+                        new_args.extend_from_slice(&args[2..]);
+                        return handle_grouped_command(new_args);
+                    }
+                }
+            }
+
             eprintln!("Error parsing arguments: {}", e);
+            print_suggestion(&args);
             process::exit(1);
         }
     }
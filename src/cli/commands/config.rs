@@ -110,15 +110,58 @@ fn show_config_field(config: &RezCoreConfig, field: &str, json_output: bool) ->
             }
         }
     } else {
-        return Err(RezCoreError::RequirementParse(format!(
-            "Unknown configuration field: '{}'",
-            field
-        )));
+        let mut message = format!("Unknown configuration field: '{}'", field);
+        if let Some(suggestion) = suggest_config_field(field, &config.field_paths()) {
+            message.push_str(&format!(", did you mean '{}'?", suggestion));
+        }
+        return Err(RezCoreError::RequirementParse(message));
     }
 
     Ok(())
 }
 
+/// Suggest the closest known field path to `field`, restricted to
+/// candidates at the same nesting level — a typo in a nested field like
+/// `cache.memory_size` is only matched against other `cache.*` fields (by
+/// comparing their last segment), not unrelated top-level fields. A match
+/// is only offered within `max(3, ceil(field.len() / 3))` edits of `field`,
+/// close enough to plausibly be a typo rather than an unrelated name.
+fn suggest_config_field(field: &str, candidates: &[String]) -> Option<String> {
+    let (prefix, leaf) = match field.rsplit_once('.') {
+        Some((prefix, leaf)) => (Some(prefix), leaf),
+        None => (None, field),
+    };
+
+    let leaves: Vec<&str> = candidates
+        .iter()
+        .filter_map(|candidate| match (prefix, candidate.rsplit_once('.')) {
+            (Some(p), Some((cp, cl))) if cp == p => Some(cl),
+            (None, None) => Some(candidate.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let threshold = field.chars().count().div_ceil(3).max(3);
+    let (suggested_leaf, distance) = leaves
+        .iter()
+        .map(|candidate_leaf| {
+            (
+                *candidate_leaf,
+                crate::cli::utils::levenshtein_distance(leaf, candidate_leaf),
+            )
+        })
+        .min_by_key(|(_, distance)| *distance)?;
+
+    if distance > threshold {
+        return None;
+    }
+
+    Some(match prefix {
+        Some(p) => format!("{}.{}", p, suggested_leaf),
+        None => suggested_leaf.to_string(),
+    })
+}
+
 /// Show the full configuration
 fn show_full_config(config: &RezCoreConfig, json_output: bool) -> RezCoreResult<()> {
     if json_output {
@@ -179,6 +222,11 @@ fn show_full_config(config: &RezCoreConfig, json_output: bool) -> RezCoreResult<
         println!("  enable_disk_cache: {}", config.cache.enable_disk_cache);
         println!("  memory_cache_size: {}", config.cache.memory_cache_size);
         println!("  cache_ttl_seconds: {}", config.cache.cache_ttl_seconds);
+
+        println!("aliases:");
+        for (name, value) in &config.aliases {
+            println!("  {}: {}", name, value.tokens().join(" "));
+        }
     }
 
     Ok(())
@@ -223,6 +271,21 @@ mod tests {
         assert!(show_config_field(&config, "cache.unknown_field", false).is_err());
     }
 
+    #[test]
+    fn test_show_config_field_suggests_close_match() {
+        let config = RezCoreConfig::default();
+
+        let err = show_config_field(&config, "packages_paths", false).unwrap_err();
+        assert!(err.to_string().contains("did you mean 'packages_path'?"));
+
+        let err = show_config_field(&config, "cache.memory_size", false).unwrap_err();
+        assert!(err.to_string().contains("did you mean 'cache.memory_cache_size'?"));
+
+        // Unrelated name: no suggestion offered
+        let err = show_config_field(&config, "completely_unrelated_nonsense", false).unwrap_err();
+        assert!(!err.to_string().contains("did you mean"));
+    }
+
     #[test]
     fn test_config_search_paths() {
         let search_paths = RezCoreConfig::get_search_paths();
@@ -235,6 +298,45 @@ mod tests {
         assert!(has_home_config);
     }
 
+    #[test]
+    fn test_alias_resolution() {
+        use rez_next_common::AliasValue;
+
+        let mut config = RezCoreConfig::default();
+        config
+            .aliases
+            .insert("co".to_string(), AliasValue::Single("context".to_string()));
+        config.aliases.insert(
+            "bl".to_string(),
+            AliasValue::List(vec!["build".to_string(), "--local".to_string()]),
+        );
+        // Chains to "co" -> "context"
+        config
+            .aliases
+            .insert("co2".to_string(), AliasValue::Single("co".to_string()));
+        // Shadows the built-in "status" command: refused.
+        config
+            .aliases
+            .insert("status".to_string(), AliasValue::Single("context".to_string()));
+
+        let known = vec!["status".to_string(), "context".to_string(), "build".to_string()];
+
+        assert_eq!(
+            config.resolve_alias("co", &known),
+            Some(vec!["context".to_string()])
+        );
+        assert_eq!(
+            config.resolve_alias("bl", &known),
+            Some(vec!["build".to_string(), "--local".to_string()])
+        );
+        assert_eq!(
+            config.resolve_alias("co2", &known),
+            Some(vec!["context".to_string()])
+        );
+        assert_eq!(config.resolve_alias("status", &known), None);
+        assert_eq!(config.resolve_alias("unknown", &known), None);
+    }
+
     #[test]
     fn test_config_field_access() {
         let config = RezCoreConfig::default();
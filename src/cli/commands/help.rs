@@ -2,11 +2,16 @@
 //!
 //! Implements the `rez pkg-help` command for displaying package help information.
 
-use clap::Args;
+use clap::{Args, CommandFactory};
 use rez_core_common::{RezCoreError, error::RezCoreResult};
 use rez_core_repository::simple_repository::{RepositoryManager, SimpleRepository};
 use rez_core_package::Package;
+use std::io::{IsTerminal, Write as IoWrite};
 use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use super::i18n;
+use fluent_bundle::FluentValue;
 
 /// Arguments for the pkg-help command
 #[derive(Args, Clone, Debug)]
@@ -34,6 +39,52 @@ pub struct PkgHelpArgs {
     /// Verbose output
     #[arg(short = 'v', long = "verbose")]
     pub verbose: bool,
+
+    /// Never page output, always print directly to stdout
+    #[arg(long = "no-pager")]
+    pub no_pager: bool,
+
+    /// With --manual, show the man page for this command instead of the
+    /// index of all commands
+    #[arg(long = "command", value_name = "NAME")]
+    pub command: Option<String>,
+
+    /// With --manual, write the raw roff man-page source to this path
+    /// instead of displaying it (for packaging into distro man directories)
+    #[arg(long = "emit-roff", value_name = "PATH")]
+    pub emit_roff: Option<PathBuf>,
+
+    /// Search help content across every package in the configured
+    /// repositories instead of requiring an exact package name
+    #[arg(long = "search", value_name = "QUERY")]
+    pub search: Option<String>,
+}
+
+/// How to act on a help section's content when it is "launched" rather
+/// than just printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpTargetKind {
+    /// A URL, opened in the user's default browser
+    Url,
+    /// A local file (HTML, PDF, etc.), opened with the platform file opener
+    FilePath,
+    /// A shell command, run in the current environment
+    Command,
+}
+
+impl HelpTargetKind {
+    /// Classify a help target: `scheme://...` is a URL, anything that names
+    /// an existing file on disk (taken literally, with no `~` or variable
+    /// expansion) is a file, everything else is run as a shell command.
+    fn classify(target: &str) -> Self {
+        if target.contains("://") {
+            Self::Url
+        } else if std::path::Path::new(target).is_file() {
+            Self::FilePath
+        } else {
+            Self::Command
+        }
+    }
 }
 
 /// Help section information
@@ -41,6 +92,9 @@ pub struct PkgHelpArgs {
 pub struct HelpSection {
     pub name: String,
     pub content: String,
+    /// `Some` when this section came from the package's `help` attribute
+    /// and can be launched; `None` for synthesized, print-only sections.
+    pub target_kind: Option<HelpTargetKind>,
 }
 
 /// Package help information
@@ -58,15 +112,19 @@ pub fn execute(args: PkgHelpArgs) -> RezCoreResult<()> {
         println!("📚 Rez Help - Displaying help information...");
     }
 
+    // Create async runtime, needed by both search and per-package help
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| RezCoreError::Io(e.into()))?;
+
+    if let Some(query) = args.search.clone() {
+        return runtime.block_on(async { execute_help_search_async(&args, &query).await });
+    }
+
     // Handle manual mode or no package specified
     if args.manual || args.package.is_none() {
         return show_rez_manual(&args);
     }
 
-    // Create async runtime for package help
-    let runtime = tokio::runtime::Runtime::new()
-        .map_err(|e| RezCoreError::Io(e.into()))?;
-
     runtime.block_on(async {
         execute_package_help_async(&args).await
     })
@@ -75,21 +133,15 @@ pub fn execute(args: PkgHelpArgs) -> RezCoreResult<()> {
 /// Show rez manual or general help
 fn show_rez_manual(args: &PkgHelpArgs) -> RezCoreResult<()> {
     if args.manual {
-        println!("📖 Rez Technical User Manual");
-        println!("============================");
-        println!();
-        println!("The Rez technical user manual provides comprehensive documentation");
-        println!("for using Rez package management system.");
-        println!();
-        println!("For the complete manual, visit:");
-        println!("  https://rez.readthedocs.io/");
-        println!();
-        println!("Quick Start:");
-        println!("  rez env python-3.9    # Create environment with Python 3.9");
-        println!("  rez search python     # Search for Python packages");
-        println!("  rez build             # Build current package");
-        println!("  rez help <package>    # Get help for specific package");
-        println!();
+        let roff = generate_manual_roff(args.command.as_deref())?;
+
+        if let Some(ref out_path) = args.emit_roff {
+            std::fs::write(out_path, &roff).map_err(RezCoreError::Io)?;
+            println!("Wrote man page roff to {}", out_path.display());
+            return Ok(());
+        }
+
+        render_manual(&roff, args.no_pager);
         return Ok(());
     }
 
@@ -97,15 +149,112 @@ fn show_rez_manual(args: &PkgHelpArgs) -> RezCoreResult<()> {
     show_command_help(args)
 }
 
+/// Render the man page for `command_name` (or an index of every top-level
+/// command when `None`) from the clap command definitions as roff, the
+/// format `man(1)` and `groff -man` both consume.
+fn generate_manual_roff(command_name: Option<&str>) -> RezCoreResult<Vec<u8>> {
+    let root = crate::cli::RezCli::command();
+
+    let target = match command_name {
+        Some(name) => root
+            .find_subcommand(name)
+            .cloned()
+            .ok_or_else(|| RezCoreError::RequirementParse(format!("Unknown command '{}'", name)))?,
+        None => root,
+    };
+
+    let mut roff = Vec::new();
+    clap_mangen::Man::new(target)
+        .render(&mut roff)
+        .map_err(RezCoreError::Io)?;
+    Ok(roff)
+}
+
+/// Display generated man page roff: hand it to `man -l -` (read a local man
+/// page from stdin) so it renders exactly as an installed man page would.
+/// If `man` can't be spawned the raw roff source is paged instead; with
+/// `--no-pager` the raw roff source is printed directly.
+fn render_manual(roff: &[u8], no_pager: bool) {
+    if !no_pager {
+        if let Ok(mut child) = Command::new("man")
+            .args(["-l", "-"])
+            .stdin(Stdio::piped())
+            .spawn()
+        {
+            if let Some(mut stdin) = child.stdin.take() {
+                let _ = stdin.write_all(roff);
+            }
+            let _ = child.wait();
+            return;
+        }
+    }
+
+    // No `man` binary available (or paging was disabled): fall back to
+    // showing the raw roff source.
+    page_output(&String::from_utf8_lossy(roff), no_pager);
+}
+
+/// Write `content` to the user's pager when stdout is an interactive
+/// terminal, falling back to direct printing when it isn't (piped or
+/// redirected output, `--no-pager`, or no usable pager binary found).
+/// Tries `$PAGER` first, then `less -R`, then `more`.
+fn page_output(content: &str, no_pager: bool) {
+    if no_pager || !std::io::stdout().is_terminal() {
+        println!("{}", content);
+        return;
+    }
+
+    for candidate in pager_candidates() {
+        let mut parts = candidate.split_whitespace();
+        let program = match parts.next() {
+            Some(program) => program,
+            None => continue,
+        };
+
+        let child = Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .spawn();
+
+        let mut child = match child {
+            Ok(child) => child,
+            Err(_) => continue,
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(content.as_bytes());
+        }
+        let _ = child.wait();
+        return;
+    }
+
+    // No pager could be spawned; fall back to direct printing.
+    println!("{}", content);
+}
+
+/// Candidate pager commands to try, in order: `$PAGER`, then `less -R`, then `more`.
+fn pager_candidates() -> Vec<String> {
+    let mut candidates = Vec::new();
+    if let Ok(pager) = std::env::var("PAGER") {
+        if !pager.trim().is_empty() {
+            candidates.push(pager);
+        }
+    }
+    candidates.push("less -R".to_string());
+    candidates.push("more".to_string());
+    candidates
+}
+
 /// Show general command help
 fn show_command_help(args: &PkgHelpArgs) -> RezCoreResult<()> {
-    println!("🚀 Rez Core - High-performance Rez package manager");
+    let t = i18n::catalog();
+    println!("{}", t.message("rez-banner", &[]));
     println!("==================================================");
     println!();
-    println!("USAGE:");
+    println!("{}", t.message("usage-label", &[]));
     println!("    rez <COMMAND> [OPTIONS]");
     println!();
-    println!("COMMANDS:");
+    println!("{}", t.message("commands-label", &[]));
     println!("    config      Show configuration information");
     println!("    context     Print information about the current rez context");
     println!("    view        View package information");
@@ -124,19 +273,19 @@ fn show_command_help(args: &PkgHelpArgs) -> RezCoreResult<()> {
     println!("    diff        Compare packages and show differences");
     println!("    help        Show help information");
     println!();
-    println!("OPTIONS:");
+    println!("{}", t.message("options-label", &[]));
     println!("    -h, --help       Print help");
     println!("    -V, --version    Print version");
     println!();
-    println!("For more information on a specific command, use:");
+    println!("{}", t.message("more-info-hint", &[]));
     println!("    rez <COMMAND> --help");
     println!();
-    println!("For package-specific help, use:");
+    println!("{}", t.message("package-help-hint", &[]));
     println!("    rez help <PACKAGE>");
     println!();
 
     if args.verbose {
-        println!("EXAMPLES:");
+        println!("{}", t.message("examples-label", &[]));
         println!("    rez help python           # Get help for Python package");
         println!("    rez help --manual          # Show technical manual");
         println!("    rez help --entries python  # List help sections for Python");
@@ -165,12 +314,179 @@ async fn execute_package_help_async(args: &PkgHelpArgs) -> RezCoreResult<()> {
     if args.entries {
         display_help_entries(&package_help);
     } else {
-        display_help_section(&package_help, args.section)?;
+        display_help_section(&package_help, args.section, args.no_pager)?;
     }
 
     Ok(())
 }
 
+/// Search help content across every package in the configured repositories
+/// and print matching sections ranked by relevance.
+async fn execute_help_search_async(args: &PkgHelpArgs, query: &str) -> RezCoreResult<()> {
+    let repo_manager = setup_repositories(args).await?;
+    let packages = repo_manager
+        .find_packages(&rez_core_repository::PackageSearchCriteria::default())
+        .await?;
+
+    // Only packages that actually carry help content are worth indexing;
+    // this keeps the candidate set small over large repositories instead
+    // of tokenizing every package's full section set.
+    let helps: Vec<PackageHelp> = packages
+        .iter()
+        .filter(|package| package.description.is_some() || package.help.is_some())
+        .map(|package| PackageHelp {
+            package_name: package.name.clone(),
+            package_version: package.version.as_ref().map(|v| v.as_str().to_string()),
+            description: package.description.clone(),
+            sections: extract_help_sections(package),
+        })
+        .collect();
+
+    let index = HelpSearchIndex::build(helps);
+    let hits = index.search(query, 20);
+
+    if hits.is_empty() {
+        println!("No help matches for '{}'", query);
+        return Ok(());
+    }
+
+    for hit in hits {
+        let version = hit.package_version.as_deref().unwrap_or("-");
+        println!("{} {} — {}", hit.package_name, version, hit.section_name);
+        println!("    {}", hit.snippet);
+    }
+
+    Ok(())
+}
+
+/// A scored search hit: which package/version/section matched, with a
+/// short snippet around the first match.
+#[derive(Debug, Clone)]
+struct HelpSearchHit {
+    package_name: String,
+    package_version: Option<String>,
+    section_name: String,
+    snippet: String,
+    score: f64,
+}
+
+/// An in-memory inverted index over tokenized help-section text, built
+/// once per search so a multi-term query only walks each term's postings
+/// instead of re-scanning every section.
+struct HelpSearchIndex {
+    /// token -> (help index, section index, term frequency)
+    postings: std::collections::HashMap<String, Vec<(usize, usize, usize)>>,
+    helps: Vec<PackageHelp>,
+}
+
+impl HelpSearchIndex {
+    fn build(helps: Vec<PackageHelp>) -> Self {
+        let mut postings: std::collections::HashMap<String, Vec<(usize, usize, usize)>> =
+            std::collections::HashMap::new();
+
+        for (help_idx, help) in helps.iter().enumerate() {
+            for (section_idx, section) in help.sections.iter().enumerate() {
+                let mut term_freq: std::collections::HashMap<String, usize> =
+                    std::collections::HashMap::new();
+                for token in tokenize(&section.content) {
+                    *term_freq.entry(token).or_insert(0) += 1;
+                }
+                for (token, freq) in term_freq {
+                    postings
+                        .entry(token)
+                        .or_default()
+                        .push((help_idx, section_idx, freq));
+                }
+            }
+        }
+
+        Self { postings, helps }
+    }
+
+    /// Score every section touched by the query's tokens (summed term
+    /// frequency, boosted for hits in the Description section) and return
+    /// the top `limit` as search hits.
+    fn search(&self, query: &str, limit: usize) -> Vec<HelpSearchHit> {
+        let mut scores: std::collections::HashMap<(usize, usize), f64> =
+            std::collections::HashMap::new();
+
+        for token in tokenize(query) {
+            let Some(postings) = self.postings.get(&token) else {
+                continue;
+            };
+            for &(help_idx, section_idx, term_freq) in postings {
+                let section = &self.helps[help_idx].sections[section_idx];
+                let boost = if section.name == "Description" { 2.0 } else { 1.0 };
+                *scores.entry((help_idx, section_idx)).or_insert(0.0) += term_freq as f64 * boost;
+            }
+        }
+
+        let mut ranked: Vec<_> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked.truncate(limit);
+
+        ranked
+            .into_iter()
+            .map(|((help_idx, section_idx), score)| {
+                let help = &self.helps[help_idx];
+                let section = &help.sections[section_idx];
+                HelpSearchHit {
+                    package_name: help.package_name.clone(),
+                    package_version: help.package_version.clone(),
+                    section_name: section.name.clone(),
+                    snippet: snippet_around_match(&section.content, query),
+                    score,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Lowercase, alphanumeric-run tokenization shared by indexing and querying.
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// A short excerpt of `content` around the first occurrence of `query`'s
+/// first token, for display alongside a search hit.
+fn snippet_around_match(content: &str, query: &str) -> String {
+    const CONTEXT_BEFORE: usize = 30;
+    const CONTEXT_AFTER: usize = 60;
+
+    let lower = content.to_lowercase();
+    let first_token = query
+        .to_lowercase()
+        .split_whitespace()
+        .next()
+        .unwrap_or_default();
+
+    let byte_pos = lower.find(&first_token).unwrap_or(0);
+    let start = content
+        .char_indices()
+        .rev()
+        .find(|&(i, _)| i <= byte_pos.saturating_sub(CONTEXT_BEFORE))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let end = content
+        .char_indices()
+        .find(|&(i, _)| i >= byte_pos + first_token.len() + CONTEXT_AFTER)
+        .map(|(i, _)| i)
+        .unwrap_or(content.len());
+
+    let mut snippet = content[start..end].trim().replace('\n', " ");
+    if start > 0 {
+        snippet = format!("...{}", snippet);
+    }
+    if end < content.len() {
+        snippet = format!("{}...", snippet);
+    }
+    snippet
+}
+
 /// Setup repository manager
 async fn setup_repositories(args: &PkgHelpArgs) -> RezCoreResult<RepositoryManager> {
     let mut repo_manager = RepositoryManager::new();
@@ -198,9 +514,10 @@ async fn find_package_help(
     let packages = repo_manager.find_packages(package_name).await?;
     
     if packages.is_empty() {
-        return Err(RezCoreError::RequirementParse(
-            format!("Package '{}' not found", package_name)
-        ));
+        return Err(RezCoreError::RequirementParse(i18n::catalog().message(
+            "package-not-found",
+            &[("package", FluentValue::from(package_name))],
+        )));
     }
 
     // Find the latest package (first in list)
@@ -217,9 +534,10 @@ async fn find_package_help(
     let help_sections = extract_help_sections(&package);
     
     if help_sections.is_empty() {
-        return Err(RezCoreError::RequirementParse(
-            format!("No help found for package '{}'", package_name)
-        ));
+        return Err(RezCoreError::RequirementParse(i18n::catalog().message(
+            "no-help-found",
+            &[("package", FluentValue::from(package_name))],
+        )));
     }
 
     Ok(PackageHelp {
@@ -239,6 +557,7 @@ fn extract_help_sections(package: &Package) -> Vec<HelpSection> {
         sections.push(HelpSection {
             name: "Description".to_string(),
             content: description.clone(),
+            target_kind: None,
         });
     }
 
@@ -265,6 +584,7 @@ fn extract_help_sections(package: &Package) -> Vec<HelpSection> {
     sections.push(HelpSection {
         name: "Package Information".to_string(),
         content: info_content,
+        target_kind: None,
     });
 
     // Add usage section
@@ -276,14 +596,28 @@ fn extract_help_sections(package: &Package) -> Vec<HelpSection> {
     sections.push(HelpSection {
         name: "Usage".to_string(),
         content: usage_content,
+        target_kind: None,
     });
 
+    // Add entries from the package's own `help` attribute, if any. These are
+    // launchable: URLs open in a browser, file paths open with the platform
+    // file opener, and everything else runs as a shell command.
+    for (label, target) in package.help_entries() {
+        let target_kind = HelpTargetKind::classify(&target);
+        sections.push(HelpSection {
+            name: label,
+            content: target,
+            target_kind: Some(target_kind),
+        });
+    }
+
     sections
 }
 
 /// Display help entries list
 fn display_help_entries(package_help: &PackageHelp) {
-    println!("Help found for:");
+    let t = i18n::catalog();
+    println!("{}", t.message("help-found-for", &[]));
     println!("  {}", package_help.package_name);
     if let Some(ref version) = package_help.package_version {
         println!("  Version: {}", version);
@@ -291,47 +625,107 @@ fn display_help_entries(package_help: &PackageHelp) {
     println!();
 
     if let Some(ref description) = package_help.description {
-        println!("Description:");
+        println!("{}", t.message("description-label", &[]));
         println!("  {}", description);
         println!();
     }
 
-    println!("Sections:");
+    println!("{}", t.message("sections-label", &[]));
     for (i, section) in package_help.sections.iter().enumerate() {
         println!("  {}: {}", i + 1, section.name);
     }
     println!();
-    println!("Use 'rez help {} <section_number>' to view a specific section.", package_help.package_name);
+    println!(
+        "{}",
+        t.message(
+            "use-section-hint",
+            &[("package", FluentValue::from(package_help.package_name.as_str()))],
+        )
+    );
 }
 
 /// Display specific help section
-fn display_help_section(package_help: &PackageHelp, section_num: u32) -> RezCoreResult<()> {
+fn display_help_section(package_help: &PackageHelp, section_num: u32, no_pager: bool) -> RezCoreResult<()> {
     let section_index = (section_num as usize).saturating_sub(1);
-    
+
     if section_index >= package_help.sections.len() {
-        return Err(RezCoreError::RequirementParse(
-            format!("No such help section {}. Available sections: 1-{}", 
-                section_num, package_help.sections.len())
-        ));
+        return Err(RezCoreError::RequirementParse(i18n::catalog().message(
+            "no-such-section",
+            &[
+                ("section", FluentValue::from(section_num)),
+                ("max", FluentValue::from(package_help.sections.len() as u32)),
+            ],
+        )));
     }
 
     let section = &package_help.sections[section_index];
-    
-    println!("Help for: {}", package_help.package_name);
+
+    // Sections sourced from the package's own `help` attribute are
+    // launched rather than printed: open URLs/files with the platform
+    // opener, run commands in a shell.
+    if let Some(target_kind) = section.target_kind {
+        println!("Launching: {} ({})", section.name, section.content);
+        return launch_help_target(target_kind, &section.content);
+    }
+
+    let mut content = String::new();
+    content.push_str(&format!("Help for: {}\n", package_help.package_name));
     if let Some(ref version) = package_help.package_version {
-        println!("Version: {}", version);
+        content.push_str(&format!("Version: {}\n", version));
     }
-    println!();
-    
-    println!("Section {}: {}", section_num, section.name);
-    println!("{}", "=".repeat(50));
-    println!();
-    println!("{}", section.content);
-    println!();
+    content.push('\n');
+    content.push_str(&format!("Section {}: {}\n", section_num, section.name));
+    content.push_str(&"=".repeat(50));
+    content.push('\n');
+    content.push('\n');
+    content.push_str(&section.content);
+    content.push('\n');
+
+    page_output(&content, no_pager);
 
     Ok(())
 }
 
+/// Launch a help entry's target according to its kind.
+fn launch_help_target(target_kind: HelpTargetKind, target: &str) -> RezCoreResult<()> {
+    let status = match target_kind {
+        HelpTargetKind::Url | HelpTargetKind::FilePath => open_with_platform_opener(target),
+        HelpTargetKind::Command => run_shell_command(target),
+    }?;
+
+    if !status.success() {
+        return Err(RezCoreError::Io(std::io::Error::other(format!(
+            "help target '{}' exited with {}",
+            target, status
+        ))));
+    }
+
+    Ok(())
+}
+
+/// Open a URL or file with the platform's default opener
+/// (`xdg-open`/`open`/`start`).
+fn open_with_platform_opener(target: &str) -> RezCoreResult<std::process::ExitStatus> {
+    if cfg!(target_os = "macos") {
+        Command::new("open").arg(target).status()
+    } else if cfg!(target_os = "windows") {
+        Command::new("cmd").args(["/C", "start", "", target]).status()
+    } else {
+        Command::new("xdg-open").arg(target).status()
+    }
+    .map_err(RezCoreError::Io)
+}
+
+/// Run a help entry's target as a shell command.
+fn run_shell_command(command: &str) -> RezCoreResult<std::process::ExitStatus> {
+    if cfg!(target_os = "windows") {
+        Command::new("cmd").args(["/C", command]).status()
+    } else {
+        Command::new("sh").args(["-c", command]).status()
+    }
+    .map_err(RezCoreError::Io)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,6 +739,10 @@ mod tests {
             entries: false,
             paths: vec![],
             verbose: false,
+            no_pager: false,
+            command: None,
+            emit_roff: None,
+            search: None,
         };
 
         assert!(args.package.is_none());
@@ -0,0 +1,78 @@
+//! Minimal Fluent-based i18n for the `pkg-help` command.
+//!
+//! The locale is selected from `LC_MESSAGES`/`LANG` (falling back to `en`).
+//! Only the `en` catalog is embedded in the binary today; additional
+//! `locales/<lang>/help.ftl` catalogs can be dropped in alongside it and
+//! wired into [`catalog`] without touching the strings in `help.rs`.
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use std::sync::OnceLock;
+use unic_langid::LanguageIdentifier;
+
+const EN_HELP_FTL: &str = include_str!("locales/en/help.ftl");
+
+/// A loaded message catalog for a single locale.
+pub struct Catalog {
+    bundle: FluentBundle<FluentResource>,
+}
+
+impl Catalog {
+    fn from_ftl(locale: LanguageIdentifier, source: &str) -> Self {
+        let resource =
+            FluentResource::try_new(source.to_string()).unwrap_or_else(|(res, _errors)| res);
+        let mut bundle = FluentBundle::new(vec![locale]);
+        bundle
+            .add_resource(resource)
+            .expect("embedded help catalog must be valid Fluent");
+        Self { bundle }
+    }
+
+    /// Resolve `id` with `args`, falling back to the id itself if the
+    /// message (or its value) isn't present in the catalog.
+    pub fn message(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        let Some(message) = self.bundle.get_message(id) else {
+            return id.to_string();
+        };
+        let Some(pattern) = message.value() else {
+            return id.to_string();
+        };
+
+        let mut fluent_args = FluentArgs::new();
+        for (key, value) in args {
+            fluent_args.set(*key, value.clone());
+        }
+
+        let mut errors = Vec::new();
+        self.bundle
+            .format_pattern(pattern, Some(&fluent_args), &mut errors)
+            .to_string()
+    }
+}
+
+/// Parse `LC_MESSAGES`/`LANG` into a language tag, defaulting to `en`.
+/// Shells export POSIX-style values like `en_US.UTF-8`, so only the part
+/// before `.`/`@` is used.
+pub fn locale_from_env() -> LanguageIdentifier {
+    let raw = std::env::var("LC_MESSAGES")
+        .or_else(|_| std::env::var("LANG"))
+        .unwrap_or_else(|_| "en".to_string());
+
+    let tag = raw.split(['.', '@']).next().unwrap_or("en");
+    tag.parse().unwrap_or_else(|_| en_tag())
+}
+
+fn en_tag() -> LanguageIdentifier {
+    "en".parse().expect("'en' is a valid language tag")
+}
+
+/// Catalog for the current locale, falling back to the embedded English
+/// catalog when no locale-specific one is available.
+pub fn catalog() -> &'static Catalog {
+    static CATALOG: OnceLock<Catalog> = OnceLock::new();
+    CATALOG.get_or_init(|| {
+        // Only `en` is compiled in today; the lookup still happens so a
+        // real multi-catalog loader has a single place to plug into.
+        let _locale = locale_from_env();
+        Catalog::from_ftl(en_tag(), EN_HELP_FTL)
+    })
+}
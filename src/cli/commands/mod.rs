@@ -18,6 +18,7 @@ pub mod cp;
 pub mod depends;
 pub mod diff;
 pub mod help;
+pub mod i18n;
 pub mod mv;
 pub mod pkg_cache;
 pub mod plugins;
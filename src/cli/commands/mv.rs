@@ -1,12 +1,23 @@
 //! Move command implementation
 //!
-//! Implements the `rez mv` command for moving packages between repositories.
+//! Implements the `rez mv` command for moving packages between
+//! repositories. Both source and destination must be reachable as local
+//! or shared-filesystem paths - there is no remote transport (S3, SSH,
+//! etc.) yet, so a URI-style destination is rejected outright rather
+//! than silently misinterpreted as a local one (see `is_local_path`).
+//! `--archive` only changes *how* the local transfer happens (packed
+//! and checksum-verified vs. a direct copy), not *where* it can go.
 
 use clap::Args;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use rez_core_common::{error::RezCoreResult, RezCoreError};
 use rez_core_package::Package;
 use rez_core_repository::simple_repository::{RepositoryManager, SimpleRepository};
-use std::path::PathBuf;
+use rez_core_version::Version;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -48,6 +59,15 @@ pub struct MvArgs {
     /// Keep source after move (essentially a copy operation)
     #[arg(long = "keep-source")]
     pub keep_source: bool,
+
+    /// Pack the package tree into a checksum-verified archive and unpack
+    /// it at the destination instead of copying files directly. This is
+    /// still a local-filesystem transfer (source and destination must
+    /// both be reachable as paths) - there is no remote transport (S3,
+    /// SSH, etc.) yet, so a URI-style destination is rejected outright
+    /// rather than silently treated as a relative path.
+    #[arg(long = "archive")]
+    pub archive: bool,
 }
 
 /// Move result information
@@ -65,6 +85,13 @@ pub struct MoveResult {
     pub error: Option<String>,
     /// Number of variants moved
     pub variants_moved: usize,
+    /// Per-file sizes in bytes, keyed by path relative to the package root
+    pub file_sizes: HashMap<String, u64>,
+    /// Total transferred size, formatted for humans (e.g. `"12.4 MiB"`)
+    pub total_size_human: String,
+    /// Whether this move used archive-based transfer (packed, streamed,
+    /// and checksum-verified) rather than a direct filesystem copy
+    pub archived: bool,
 }
 
 /// Execute the mv command
@@ -89,14 +116,10 @@ pub fn execute(args: MvArgs) -> RezCoreResult<()> {
 /// Execute move operation asynchronously
 async fn execute_move_async(args: &MvArgs) -> RezCoreResult<()> {
     // Parse package specification
-    let (package_name, version_spec) = parse_package_spec(&args.source_package)?;
+    let constraint = PackageVersionConstraint::parse(&args.source_package)?;
 
     if args.verbose {
-        println!(
-            "Parsed package: {} (version: {})",
-            package_name,
-            version_spec.as_deref().unwrap_or("latest")
-        );
+        println!("Parsed package: {} (constraint: {})", constraint.name, constraint);
     }
 
     // Setup source repositories
@@ -115,8 +138,7 @@ async fn execute_move_async(args: &MvArgs) -> RezCoreResult<()> {
 
     // Find source package and its location
     let (source_package, source_path) =
-        find_source_package_with_path(&repo_manager, &package_name, version_spec.as_deref())
-            .await?;
+        find_source_package_with_path(&repo_manager, &constraint).await?;
 
     if args.verbose {
         println!(
@@ -131,6 +153,19 @@ async fn execute_move_async(args: &MvArgs) -> RezCoreResult<()> {
         println!("Source location: {}", source_path.display());
     }
 
+    // There is no remote transport (S3, SSH, etc.) implemented yet, so a
+    // URI-style destination must be rejected here rather than silently
+    // treated as a literal (and almost certainly bogus) local path - see
+    // `transfer_package`, which would otherwise "succeed" against that
+    // bogus path and then delete the real source.
+    if !is_local_path(&args.destination_path) {
+        return Err(RezCoreError::RequirementParse(format!(
+            "Destination '{}' is not a local path; remote transport is not yet implemented. \
+             Move to a local or shared-filesystem path instead.",
+            args.destination_path.display()
+        )));
+    }
+
     // Check if destination exists
     if !args.force && package_exists_at_destination(&args.destination_path, &source_package).await?
     {
@@ -153,11 +188,21 @@ async fn execute_move_async(args: &MvArgs) -> RezCoreResult<()> {
         if args.keep_source {
             println!("  Note: Source would be kept (copy mode)");
         }
+        if args.archive {
+            println!("  Transfer mode: archive (packed, unpacked, checksum-verified)");
+        }
         return Ok(());
     }
 
     // Perform the move
-    let result = move_package(&source_package, &source_path, &args.destination_path, args).await?;
+    let result = move_package(
+        &source_package,
+        &source_path,
+        &args.destination_path,
+        &repo_manager,
+        args,
+    )
+    .await?;
 
     if result.success {
         if args.keep_source {
@@ -170,6 +215,11 @@ async fn execute_move_async(args: &MvArgs) -> RezCoreResult<()> {
         if args.all_variants && result.variants_moved > 1 {
             println!("   Variants processed: {}", result.variants_moved);
         }
+        if result.archived {
+            println!("   Transfer mode: archive ({})", result.total_size_human);
+        } else if args.verbose {
+            println!("   Total size: {}", result.total_size_human);
+        }
     } else {
         eprintln!(
             "❌ Failed to move package: {}",
@@ -181,42 +231,201 @@ async fn execute_move_async(args: &MvArgs) -> RezCoreResult<()> {
     Ok(())
 }
 
-/// Parse package specification into name and optional version
-fn parse_package_spec(spec: &str) -> RezCoreResult<(String, Option<String>)> {
-    if let Some(dash_pos) = spec.rfind('-') {
-        let name = spec[..dash_pos].to_string();
-        let version = spec[dash_pos + 1..].to_string();
+/// A single comparison against a version bound (e.g. the `>=3.9` in
+/// `python>=3.9`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConstraintOp {
+    Eq,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
+impl std::fmt::Display for ConstraintOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            ConstraintOp::Eq => "==",
+            ConstraintOp::Ge => ">=",
+            ConstraintOp::Gt => ">",
+            ConstraintOp::Le => "<=",
+            ConstraintOp::Lt => "<",
+        };
+        write!(f, "{}", op)
+    }
+}
 
-        // Check if version part looks like a version
-        if version.chars().next().map_or(false, |c| c.is_ascii_digit()) {
-            return Ok((name, Some(version)));
+#[derive(Debug, Clone)]
+struct VersionComparator {
+    op: ConstraintOp,
+    bound: Version,
+}
+
+impl VersionComparator {
+    fn matches(&self, candidate: &Version) -> bool {
+        // `cmp_for_range` follows bpkg's revision-ignoring rule: the
+        // candidate's revision is only considered when `bound` specifies
+        // one, so `<=`/`==` (and the range endpoints below) naturally
+        // match any revision of the matching base version.
+        let ordering = candidate.cmp_for_range(&self.bound);
+        match self.op {
+            ConstraintOp::Eq => ordering == std::cmp::Ordering::Equal,
+            ConstraintOp::Ge => ordering != std::cmp::Ordering::Less,
+            ConstraintOp::Gt => ordering == std::cmp::Ordering::Greater,
+            ConstraintOp::Le => ordering != std::cmp::Ordering::Greater,
+            ConstraintOp::Lt => ordering == std::cmp::Ordering::Less,
         }
     }
+}
+
+impl std::fmt::Display for VersionComparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.op, self.bound.as_str())
+    }
+}
 
-    Ok((spec.to_string(), None))
+/// A parsed `mv` package spec: a package name plus an optional version
+/// constraint (exact version, range, or explicit comparison operator).
+///
+/// A spec with no constraint (e.g. `python`) matches any available
+/// version of the package.
+#[derive(Debug, Clone)]
+struct PackageVersionConstraint {
+    name: String,
+    comparators: Vec<VersionComparator>,
 }
 
-/// Find source package and its path in repositories
+impl PackageVersionConstraint {
+    /// Parse a package spec such as `python`, `python-3.9.1`,
+    /// `python-3.9+`, `python-3.7..3.10`, or `python>=3.9`.
+    fn parse(spec: &str) -> RezCoreResult<Self> {
+        for (op_str, op) in [
+            (">=", ConstraintOp::Ge),
+            ("<=", ConstraintOp::Le),
+            ("==", ConstraintOp::Eq),
+            (">", ConstraintOp::Gt),
+            ("<", ConstraintOp::Lt),
+        ] {
+            if let Some(op_pos) = spec.find(op_str) {
+                let name = spec[..op_pos].to_string();
+                let bound = Version::parse(&spec[op_pos + op_str.len()..])?;
+                return Ok(Self {
+                    name,
+                    comparators: vec![VersionComparator { op, bound }],
+                });
+            }
+        }
+
+        if let Some(dash_pos) = spec.rfind('-') {
+            let tail = &spec[dash_pos + 1..];
+            if tail.chars().next().map_or(false, |c| c.is_ascii_digit()) {
+                return Self::parse_dash_tail(spec[..dash_pos].to_string(), tail);
+            }
+        }
+
+        // No recognizable version tail - match any version of this package
+        Ok(Self {
+            name: spec.to_string(),
+            comparators: Vec::new(),
+        })
+    }
+
+    /// Parse the portion of a spec after the package name's dash: an
+    /// exact version (optionally carrying a `+revision`), a `+`-suffixed
+    /// lower bound (`3.9+`), or a `..`-delimited range (`3.7..3.10`).
+    fn parse_dash_tail(name: String, tail: &str) -> RezCoreResult<Self> {
+        if let Some((lower, upper)) = tail.split_once("..") {
+            return Ok(Self {
+                name,
+                comparators: vec![
+                    VersionComparator {
+                        op: ConstraintOp::Ge,
+                        bound: Version::parse(lower)?,
+                    },
+                    VersionComparator {
+                        op: ConstraintOp::Lt,
+                        bound: Version::parse(upper)?,
+                    },
+                ],
+            });
+        }
+
+        if let Some(lower) = tail.strip_suffix('+') {
+            return Ok(Self {
+                name,
+                comparators: vec![VersionComparator {
+                    op: ConstraintOp::Ge,
+                    bound: Version::parse(lower)?,
+                }],
+            });
+        }
+
+        // An exact version, e.g. `3.9.1` or (bpkg-style) `1+2` for a
+        // specific revision of version `1`.
+        Ok(Self {
+            name,
+            comparators: vec![VersionComparator {
+                op: ConstraintOp::Eq,
+                bound: Version::parse(tail)?,
+            }],
+        })
+    }
+
+    /// Check whether `version` satisfies this constraint. A constraint
+    /// with no comparators (a bare package name) matches any version,
+    /// including packages with no version at all.
+    fn matches(&self, version: Option<&Version>) -> bool {
+        if self.comparators.is_empty() {
+            return true;
+        }
+        match version {
+            Some(version) => self.comparators.iter().all(|c| c.matches(version)),
+            None => false,
+        }
+    }
+}
+
+impl std::fmt::Display for PackageVersionConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.comparators.is_empty() {
+            return write!(f, "{} (any version)", self.name);
+        }
+        write!(f, "{}", self.name)?;
+        for comparator in &self.comparators {
+            write!(f, " {}", comparator)?;
+        }
+        Ok(())
+    }
+}
+
+/// Find source package and its path in repositories. Repositories
+/// return candidates sorted latest-first, so the first match that
+/// satisfies the constraint is the latest satisfying version.
 async fn find_source_package_with_path(
     repo_manager: &RepositoryManager,
-    package_name: &str,
-    _version_spec: Option<&str>,
+    constraint: &PackageVersionConstraint,
 ) -> RezCoreResult<(Package, PathBuf)> {
-    let packages = repo_manager.find_packages(package_name).await?;
-
-    if packages.is_empty() {
-        return Err(RezCoreError::RequirementParse(format!(
-            "Package '{}' not found",
-            package_name
-        )));
-    }
+    let packages = repo_manager.find_packages(&constraint.name).await?;
 
-    // Return the first package found and estimate its path - convert Arc<Package> to Package
-    let package_arc = packages.into_iter().next().unwrap();
+    let package_arc = packages
+        .into_iter()
+        .find(|package| constraint.matches(package.version.as_ref()))
+        .ok_or_else(|| {
+            RezCoreError::RequirementParse(format!("No package matching '{}' was found", constraint))
+        })?;
     let package = (*package_arc).clone();
-    let estimated_path = PathBuf::from("./local_packages"); // TODO: Get actual path from repository
 
-    Ok((package, estimated_path))
+    let source_path = repo_manager
+        .find_package_path(&package)
+        .await?
+        .ok_or_else(|| {
+            RezCoreError::RequirementParse(format!(
+                "Could not determine source directory for package '{}'",
+                constraint.name
+            ))
+        })?;
+
+    Ok((package, source_path))
 }
 
 /// Check if package already exists at destination
@@ -238,55 +447,37 @@ async fn move_package(
     source_package: &Package,
     source_path: &PathBuf,
     destination_path: &PathBuf,
+    repo_manager: &RepositoryManager,
     args: &MvArgs,
 ) -> RezCoreResult<MoveResult> {
-    let package_dir = if let Some(ref version) = source_package.version {
-        destination_path.join(format!("{}-{}", source_package.name, version.as_str()))
-    } else {
-        destination_path.join(&source_package.name)
-    };
+    let package_dir = package_dest_dir(destination_path, source_package);
 
-    // Create destination directory
-    std::fs::create_dir_all(&package_dir).map_err(|e| RezCoreError::Io(e.into()))?;
+    let outcome = match transfer_package(source_path, &package_dir, source_package, args) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            return Ok(MoveResult {
+                source_package: source_package.clone(),
+                source_path: source_path.clone(),
+                destination_path: package_dir,
+                success: false,
+                error: Some(e.to_string()),
+                variants_moved: 0,
+                file_sizes: HashMap::new(),
+                total_size_human: human_readable_size(0),
+                archived: false,
+            });
+        }
+    };
 
     if args.verbose {
-        println!("Created directory: {}", package_dir.display());
-    }
-
-    // TODO: Implement actual package moving logic
-    // This is a simplified implementation
-
-    // Create package.yaml at destination
-    let package_yaml = package_dir.join("package.yaml");
-    let yaml_content = format!(
-        "name: {}\nversion: {}\ndescription: {}\n",
-        source_package.name,
-        source_package
-            .version
-            .as_ref()
-            .map(|v| v.as_str())
-            .unwrap_or("1.0.0"),
-        source_package
-            .description
-            .as_deref()
-            .unwrap_or("Moved package")
-    );
-
-    std::fs::write(&package_yaml, yaml_content).map_err(|e| RezCoreError::Io(e.into()))?;
-
-    // Remove source if not keeping it
-    if !args.keep_source {
-        // TODO: Implement safe source removal
-        if args.verbose {
-            println!("Would remove source at: {}", source_path.display());
-        }
+        println!("Moved package payload to: {}", package_dir.display());
     }
 
-    let variants_moved = if args.all_variants {
-        source_package.variants.len().max(1)
-    } else {
-        1
-    };
+    if !args.no_deps {
+        move_dependency_closure(source_package, repo_manager, destination_path, args).await?;
+    }
+
+    let total_size_bytes: u64 = outcome.file_sizes.values().sum();
 
     Ok(MoveResult {
         source_package: source_package.clone(),
@@ -294,25 +485,579 @@ async fn move_package(
         destination_path: package_dir,
         success: true,
         error: None,
-        variants_moved,
+        variants_moved: outcome.variants_moved,
+        file_sizes: outcome.file_sizes,
+        total_size_human: human_readable_size(total_size_bytes),
+        archived: outcome.archived,
+    })
+}
+
+/// Compute the destination directory for a package (`name` or
+/// `name-version` under the given repository root)
+fn package_dest_dir(destination_path: &Path, package: &Package) -> PathBuf {
+    if let Some(ref version) = package.version {
+        destination_path.join(format!("{}-{}", package.name, version.as_str()))
+    } else {
+        destination_path.join(&package.name)
+    }
+}
+
+/// Outcome of transferring a package's tree to its destination, whatever
+/// transfer mode was used.
+struct TransferOutcome {
+    /// Number of variant directories transferred
+    variants_moved: usize,
+    /// Per-file sizes in bytes, keyed by path relative to the package root
+    file_sizes: HashMap<String, u64>,
+    /// Whether the transfer went through the archive path
+    archived: bool,
+}
+
+/// Copy a package's complete tree (definition file, payload, and variant
+/// subdirectories) from `source_path` to `dest_path`, both of which must
+/// be local/shared-filesystem paths (callers reject URI-style
+/// destinations before reaching here - see `is_local_path`). Transfers
+/// directly unless `--archive` was given, in which case the tree is
+/// packed into a checksum-verified archive and unpacked at the
+/// destination (see `transfer_package_via_archive`) - verify the transfer
+/// landed, and only then remove the source unless `--keep-source` was
+/// given.
+fn transfer_package(
+    source_path: &Path,
+    dest_path: &Path,
+    package: &Package,
+    args: &MvArgs,
+) -> RezCoreResult<TransferOutcome> {
+    // Defense in depth against the data-loss scenario this guard exists
+    // for: `dest_path` must never be a literal interpretation of a
+    // URI-style destination (there is no remote transport implemented),
+    // since we remove the source below once the destination "verifies".
+    if !is_local_path(dest_path) {
+        return Err(RezCoreError::RequirementParse(format!(
+            "Destination '{}' is not a local path; remote transport is not yet implemented. \
+             Move to a local or shared-filesystem path instead.",
+            dest_path.display()
+        )));
+    }
+
+    let outcome = if args.archive {
+        transfer_package_via_archive(source_path, dest_path, package, args)?
+    } else {
+        let variants_moved = copy_package_tree(source_path, dest_path, package, args.all_variants)?;
+        let file_sizes = measure_tree_sizes(dest_path)?;
+        TransferOutcome {
+            variants_moved,
+            file_sizes,
+            archived: false,
+        }
+    };
+
+    // Verify the definition file actually made it across before we
+    // touch the source - a partial or failed transfer must never delete
+    // the original
+    let definition_copied =
+        dest_path.join("package.py").exists() || dest_path.join("package.yaml").exists();
+    if !definition_copied {
+        return Err(RezCoreError::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Copy verification failed: no package definition found at {}",
+                dest_path.display()
+            ),
+        )));
+    }
+
+    if !args.keep_source {
+        std::fs::remove_dir_all(source_path).map_err(|e| RezCoreError::Io(e.into()))?;
+    } else if args.verbose {
+        println!("Keeping source at: {}", source_path.display());
+    }
+
+    Ok(outcome)
+}
+
+/// Treat destination paths that carry a URI scheme (`proto://...`) as
+/// non-local; everything else is assumed to be reachable as a shared
+/// filesystem path.
+fn is_local_path(path: &Path) -> bool {
+    !path.to_string_lossy().contains("://")
+}
+
+/// Recursively copy the top-level package files (definition, payload)
+/// plus the relevant variant subdirectories from `source_path` to
+/// `dest_path`. Variant subdirectories are named by index, matching the
+/// standard Rez on-disk layout. Returns the number of variant
+/// directories copied (0 if the package has no variants).
+fn copy_package_tree(
+    source_path: &Path,
+    dest_path: &Path,
+    package: &Package,
+    all_variants: bool,
+) -> RezCoreResult<usize> {
+    std::fs::create_dir_all(dest_path).map_err(|e| RezCoreError::Io(e.into()))?;
+
+    let variant_dirs: HashSet<String> = (0..package.variants.len())
+        .map(|index| index.to_string())
+        .collect();
+
+    for entry in std::fs::read_dir(source_path).map_err(|e| RezCoreError::Io(e.into()))? {
+        let entry = entry.map_err(|e| RezCoreError::Io(e.into()))?;
+        let file_name = entry.file_name();
+        let file_type = entry.file_type().map_err(|e| RezCoreError::Io(e.into()))?;
+
+        // Variant subdirectories are copied separately below, according
+        // to --all-variants
+        if file_type.is_dir() && variant_dirs.contains(&file_name.to_string_lossy().to_string()) {
+            continue;
+        }
+
+        let dest_entry = dest_path.join(&file_name);
+        if file_type.is_dir() {
+            copy_dir_recursive(&entry.path(), &dest_entry)?;
+        } else {
+            std::fs::copy(entry.path(), &dest_entry).map_err(|e| RezCoreError::Io(e.into()))?;
+        }
+    }
+
+    if package.variants.is_empty() {
+        return Ok(0);
+    }
+
+    let indices_to_copy: Vec<usize> = if all_variants {
+        (0..package.variants.len()).collect()
+    } else {
+        vec![0]
+    };
+
+    let mut variants_copied = 0;
+    for index in indices_to_copy {
+        let variant_source = source_path.join(index.to_string());
+        if variant_source.is_dir() {
+            copy_dir_recursive(&variant_source, &dest_path.join(index.to_string()))?;
+            variants_copied += 1;
+        }
+    }
+
+    Ok(variants_copied)
+}
+
+/// Recursively copy an entire directory tree
+fn copy_dir_recursive(source_path: &Path, dest_path: &Path) -> RezCoreResult<()> {
+    std::fs::create_dir_all(dest_path).map_err(|e| RezCoreError::Io(e.into()))?;
+
+    for entry in std::fs::read_dir(source_path).map_err(|e| RezCoreError::Io(e.into()))? {
+        let entry = entry.map_err(|e| RezCoreError::Io(e.into()))?;
+        let file_type = entry.file_type().map_err(|e| RezCoreError::Io(e.into()))?;
+        let dest_entry = dest_path.join(entry.file_name());
+
+        if file_type.is_dir() {
+            copy_dir_recursive(&entry.path(), &dest_entry)?;
+        } else {
+            std::fs::copy(entry.path(), &dest_entry).map_err(|e| RezCoreError::Io(e.into()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Walk `root` and record each file's size, keyed by its path relative to
+/// `root` (with forward slashes, matching archive entry naming).
+fn measure_tree_sizes(root: &Path) -> RezCoreResult<HashMap<String, u64>> {
+    let mut sizes = HashMap::new();
+    collect_tree_sizes(root, root, &mut sizes)?;
+    Ok(sizes)
+}
+
+fn collect_tree_sizes(
+    dir: &Path,
+    root: &Path,
+    sizes: &mut HashMap<String, u64>,
+) -> RezCoreResult<()> {
+    for entry in std::fs::read_dir(dir).map_err(|e| RezCoreError::Io(e.into()))? {
+        let entry = entry.map_err(|e| RezCoreError::Io(e.into()))?;
+        let path = entry.path();
+        let file_type = entry.file_type().map_err(|e| RezCoreError::Io(e.into()))?;
+
+        if file_type.is_dir() {
+            collect_tree_sizes(&path, root, sizes)?;
+        } else {
+            let metadata = entry.metadata().map_err(|e| RezCoreError::Io(e.into()))?;
+            sizes.insert(relative_entry_name(&path, root), metadata.len());
+        }
+    }
+    Ok(())
+}
+
+/// Format a path relative to `root` as a forward-slash archive entry name.
+fn relative_entry_name(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// A checksum/size manifest for an archived package transfer, analogous
+/// to the manifest Cargo's `cargo package`/verify flow produces.
+#[derive(Debug, Clone, Default)]
+struct ArchiveManifest {
+    /// Per-entry size and checksum, keyed by archive entry name
+    files: HashMap<String, FileManifestEntry>,
+    /// Sum of every entry's size
+    total_size_bytes: u64,
+}
+
+#[derive(Debug, Clone)]
+struct FileManifestEntry {
+    size_bytes: u64,
+    checksum: String,
+}
+
+/// Collect every file under a package's tree that should be archived: the
+/// top-level definition/payload files plus the selected variant
+/// subdirectories (by index, honoring `--all-variants`). Returns each
+/// file's archive entry name paired with its absolute source path, plus
+/// the number of variant directories included.
+fn collect_archive_entries(
+    source_path: &Path,
+    package: &Package,
+    all_variants: bool,
+) -> RezCoreResult<(Vec<(String, PathBuf)>, usize)> {
+    let variant_dirs: HashSet<String> = (0..package.variants.len())
+        .map(|index| index.to_string())
+        .collect();
+
+    let mut entries = Vec::new();
+    collect_files_recursive(source_path, source_path, &mut entries, Some(&variant_dirs))?;
+
+    if package.variants.is_empty() {
+        return Ok((entries, 0));
+    }
+
+    let indices_to_include: Vec<usize> = if all_variants {
+        (0..package.variants.len()).collect()
+    } else {
+        vec![0]
+    };
+
+    let mut variants_included = 0;
+    for index in indices_to_include {
+        let variant_source = source_path.join(index.to_string());
+        if variant_source.is_dir() {
+            collect_files_recursive(&variant_source, source_path, &mut entries, None)?;
+            variants_included += 1;
+        }
+    }
+
+    Ok((entries, variants_included))
+}
+
+/// Recursively collect files under `dir`, recording each as (archive
+/// entry name relative to `root`, absolute path). When `skip_top_level_dirs`
+/// is given, top-level subdirectories with those names are skipped (e.g.
+/// variant directories, which are collected separately).
+fn collect_files_recursive(
+    dir: &Path,
+    root: &Path,
+    entries: &mut Vec<(String, PathBuf)>,
+    skip_top_level_dirs: Option<&HashSet<String>>,
+) -> RezCoreResult<()> {
+    for entry in std::fs::read_dir(dir).map_err(|e| RezCoreError::Io(e.into()))? {
+        let entry = entry.map_err(|e| RezCoreError::Io(e.into()))?;
+        let path = entry.path();
+        let file_type = entry.file_type().map_err(|e| RezCoreError::Io(e.into()))?;
+
+        if file_type.is_dir() {
+            if let Some(skip) = skip_top_level_dirs {
+                if dir == root
+                    && skip.contains(&entry.file_name().to_string_lossy().to_string())
+                {
+                    continue;
+                }
+            }
+            collect_files_recursive(&path, root, entries, skip_top_level_dirs)?;
+        } else {
+            entries.push((relative_entry_name(&path, root), path));
+        }
+    }
+    Ok(())
+}
+
+/// Compute a SHA-256 digest of `data`, formatted as lowercase hex.
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Format a byte count for humans (e.g. `"12.4 MiB"`).
+fn human_readable_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    if unit_index == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit_index])
+    }
+}
+
+/// Pack `entries` into a gzip-compressed tar archive with deterministic
+/// entry ordering and normalized headers (fixed mode, no timestamps), and
+/// build the checksum manifest alongside it.
+fn build_package_archive(entries: &[(String, PathBuf)]) -> RezCoreResult<(Vec<u8>, ArchiveManifest)> {
+    let mut sorted_entries = entries.to_vec();
+    sorted_entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut manifest = ArchiveManifest::default();
+    let mut tar_bytes = Vec::new();
+    {
+        let mut builder = tar::Builder::new(&mut tar_bytes);
+        for (entry_name, absolute_path) in &sorted_entries {
+            let data = std::fs::read(absolute_path).map_err(|e| RezCoreError::Io(e.into()))?;
+
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(0);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, entry_name, data.as_slice())
+                .map_err(|e| RezCoreError::Io(e))?;
+
+            manifest.files.insert(
+                entry_name.clone(),
+                FileManifestEntry {
+                    size_bytes: data.len() as u64,
+                    checksum: sha256_hex(&data),
+                },
+            );
+        }
+        builder.finish().map_err(|e| RezCoreError::Io(e))?;
+    }
+
+    manifest.total_size_bytes = manifest.files.values().map(|entry| entry.size_bytes).sum();
+
+    let mut gz_bytes = Vec::new();
+    {
+        let mut encoder = GzEncoder::new(&mut gz_bytes, Compression::default());
+        encoder.write_all(&tar_bytes).map_err(|e| RezCoreError::Io(e.into()))?;
+        encoder.finish().map_err(|e| RezCoreError::Io(e.into()))?;
+    }
+
+    Ok((gz_bytes, manifest))
+}
+
+/// Unpack a gzip-compressed tar archive at `archive_path` into `dest_path`
+/// and verify every entry's size and checksum against `manifest`. Returns
+/// an error (leaving `dest_path` populated with whatever was extracted,
+/// but without touching the source) if any entry doesn't match.
+fn unpack_and_verify_archive(
+    archive_path: &Path,
+    dest_path: &Path,
+    manifest: &ArchiveManifest,
+) -> RezCoreResult<()> {
+    std::fs::create_dir_all(dest_path).map_err(|e| RezCoreError::Io(e.into()))?;
+
+    let archive_file = std::fs::File::open(archive_path).map_err(|e| RezCoreError::Io(e.into()))?;
+    let decoder = GzDecoder::new(archive_file);
+    let mut archive = tar::Archive::new(decoder);
+    archive
+        .unpack(dest_path)
+        .map_err(|e| RezCoreError::Io(e))?;
+
+    for (entry_name, entry) in &manifest.files {
+        let extracted_path = dest_path.join(entry_name);
+        let data = std::fs::read(&extracted_path).map_err(|e| RezCoreError::Io(e.into()))?;
+        if data.len() as u64 != entry.size_bytes || sha256_hex(&data) != entry.checksum {
+            return Err(RezCoreError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Checksum mismatch for '{}' after archive transfer",
+                    entry_name
+                ),
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Pack `source_path`'s package tree into a compressed, deterministically
+/// ordered archive with a checksum manifest, write it alongside
+/// `dest_path`, and unpack and verify it there - mirroring Cargo's `cargo
+/// package`/verify flow. This is still a local-filesystem transfer
+/// (`dest_path` must be a real path rez can create directories under,
+/// e.g. a different mount or a path a remote-mounted filesystem makes
+/// visible locally) - there is no network transport here, so callers
+/// must reject URI-style destinations before reaching this function (see
+/// `is_local_path`). The on-disk archive file is only a transport
+/// artifact; it's removed once the destination has been verified (or the
+/// transfer has failed), and the source is left untouched on failure.
+fn transfer_package_via_archive(
+    source_path: &Path,
+    dest_path: &Path,
+    package: &Package,
+    args: &MvArgs,
+) -> RezCoreResult<TransferOutcome> {
+    let (entries, variants_included) =
+        collect_archive_entries(source_path, package, args.all_variants)?;
+    let (archive_bytes, manifest) = build_package_archive(&entries)?;
+
+    let archive_path = dest_path.with_extension("rez-pkg.tar.gz");
+    if let Some(parent) = archive_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| RezCoreError::Io(e.into()))?;
+    }
+    std::fs::write(&archive_path, &archive_bytes).map_err(|e| RezCoreError::Io(e.into()))?;
+
+    let unpack_result = unpack_and_verify_archive(&archive_path, dest_path, &manifest);
+    let _ = std::fs::remove_file(&archive_path);
+    unpack_result?;
+
+    if args.verbose {
+        println!(
+            "  Archived and verified {} ({})",
+            dest_path.display(),
+            human_readable_size(manifest.total_size_bytes)
+        );
+    }
+
+    Ok(TransferOutcome {
+        variants_moved: variants_included,
+        file_sizes: manifest
+            .files
+            .into_iter()
+            .map(|(entry_name, entry)| (entry_name, entry.size_bytes))
+            .collect(),
+        archived: true,
     })
 }
 
+/// Resolve and move the transitive closure of `root_package`'s
+/// `requires`, skipping any dependency whose source location can't be
+/// determined (it may live outside the repositories being searched).
+async fn move_dependency_closure(
+    root_package: &Package,
+    repo_manager: &RepositoryManager,
+    destination_path: &PathBuf,
+    args: &MvArgs,
+) -> RezCoreResult<()> {
+    let mut visited: HashSet<String> = HashSet::new();
+    visited.insert(root_package.name.clone());
+
+    let mut queue: Vec<String> = root_package
+        .requires
+        .iter()
+        .filter_map(|req| PackageVersionConstraint::parse(req).ok())
+        .map(|constraint| constraint.name)
+        .collect();
+
+    while let Some(name) = queue.pop() {
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+
+        let packages = repo_manager.find_packages(&name).await?;
+        let Some(dep_package) = packages.into_iter().next() else {
+            if args.verbose {
+                println!("  Skipping dependency '{}': not found", name);
+            }
+            continue;
+        };
+        let dep_package = (*dep_package).clone();
+
+        let Some(dep_source_path) = repo_manager.find_package_path(&dep_package).await? else {
+            if args.verbose {
+                println!("  Skipping dependency '{}': source location unknown", name);
+            }
+            continue;
+        };
+
+        let dep_dest_dir = package_dest_dir(destination_path, &dep_package);
+        transfer_package(&dep_source_path, &dep_dest_dir, &dep_package, args)?;
+
+        if args.verbose {
+            println!(
+                "  Moved dependency '{}' to {}",
+                dep_package.name,
+                dep_dest_dir.display()
+            );
+        }
+
+        queue.extend(
+            dep_package
+                .requires
+                .iter()
+                .filter_map(|req| PackageVersionConstraint::parse(req).ok())
+                .map(|constraint| constraint.name),
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_package_spec() {
-        assert_eq!(
-            parse_package_spec("python").unwrap(),
-            ("python".to_string(), None)
-        );
+    fn test_package_version_constraint_parses_bare_name() {
+        let constraint = PackageVersionConstraint::parse("python").unwrap();
+        assert_eq!(constraint.name, "python");
+        assert!(constraint.comparators.is_empty());
+        assert!(constraint.matches(None));
+        assert!(constraint.matches(Some(&Version::parse("3.9").unwrap())));
+    }
 
-        assert_eq!(
-            parse_package_spec("python-3.9").unwrap(),
-            ("python".to_string(), Some("3.9".to_string()))
-        );
+    #[test]
+    fn test_package_version_constraint_parses_exact_version() {
+        let constraint = PackageVersionConstraint::parse("python-3.9.1").unwrap();
+        assert_eq!(constraint.name, "python");
+        assert!(constraint.matches(Some(&Version::parse("3.9.1").unwrap())));
+        assert!(!constraint.matches(Some(&Version::parse("3.9.2").unwrap())));
+    }
+
+    #[test]
+    fn test_package_version_constraint_parses_plus_range() {
+        let constraint = PackageVersionConstraint::parse("python-3.9+").unwrap();
+        assert!(constraint.matches(Some(&Version::parse("3.9").unwrap())));
+        assert!(constraint.matches(Some(&Version::parse("4.0").unwrap())));
+        assert!(!constraint.matches(Some(&Version::parse("3.8").unwrap())));
+    }
+
+    #[test]
+    fn test_package_version_constraint_parses_dotdot_range() {
+        let constraint = PackageVersionConstraint::parse("python-3.7..3.10").unwrap();
+        assert!(constraint.matches(Some(&Version::parse("3.7").unwrap())));
+        assert!(constraint.matches(Some(&Version::parse("3.9").unwrap())));
+        assert!(!constraint.matches(Some(&Version::parse("3.10").unwrap())));
+        assert!(!constraint.matches(Some(&Version::parse("3.6").unwrap())));
+    }
+
+    #[test]
+    fn test_package_version_constraint_parses_explicit_operator() {
+        let constraint = PackageVersionConstraint::parse("python>=3.9").unwrap();
+        assert_eq!(constraint.name, "python");
+        assert!(constraint.matches(Some(&Version::parse("3.9").unwrap())));
+        assert!(constraint.matches(Some(&Version::parse("3.10").unwrap())));
+        assert!(!constraint.matches(Some(&Version::parse("3.8").unwrap())));
+    }
+
+    #[test]
+    fn test_package_version_constraint_ignores_revision_unless_specified() {
+        // An unqualified exact version matches any revision of that version.
+        let constraint = PackageVersionConstraint::parse("python-1").unwrap();
+        assert!(constraint.matches(Some(&Version::parse("1+2").unwrap())));
+
+        // But an explicit revision must match exactly.
+        let constraint = PackageVersionConstraint::parse("python-1+2").unwrap();
+        assert!(constraint.matches(Some(&Version::parse("1+2").unwrap())));
+        assert!(!constraint.matches(Some(&Version::parse("1+3").unwrap())));
+        assert!(!constraint.matches(Some(&Version::parse("1").unwrap())));
     }
 
     #[test]
@@ -327,10 +1072,35 @@ mod tests {
             verbose: false,
             no_deps: false,
             keep_source: false,
+            archive: false,
         };
 
         assert_eq!(args.source_package, "test");
         assert!(!args.force);
         assert!(!args.keep_source);
     }
+
+    #[test]
+    fn test_is_local_path_flags_uri_destinations() {
+        assert!(is_local_path(Path::new("/tmp/packages")));
+        assert!(!is_local_path(Path::new("s3://bucket/packages")));
+        assert!(!is_local_path(Path::new("ssh://host/packages")));
+    }
+
+    #[test]
+    fn test_human_readable_size_formats_units() {
+        assert_eq!(human_readable_size(0), "0 B");
+        assert_eq!(human_readable_size(512), "512 B");
+        assert_eq!(human_readable_size(2048), "2.0 KiB");
+        assert_eq!(human_readable_size(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_sha256_hex_is_deterministic() {
+        let a = sha256_hex(b"rez-pkg-contents");
+        let b = sha256_hex(b"rez-pkg-contents");
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 64);
+        assert_ne!(a, sha256_hex(b"different-contents"));
+    }
 }
@@ -5,9 +5,9 @@
 use clap::Args;
 use rez_next_common::{error::RezCoreResult, RezCoreError};
 use rez_next_package::Package;
-use rez_next_repository::simple_repository::RepositoryManager;
-use rez_next_repository::PackageSearchCriteria;
+use rez_next_repository::{RepositoryScanner, ScannerConfig};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Arguments for the search command
 #[derive(Args, Clone, Debug)]
@@ -65,6 +65,18 @@ pub struct SearchArgs {
     /// Verbose output
     #[arg(short = 'v', long = "verbose")]
     pub verbose: bool,
+
+    /// Disable the on-disk scan cache; always rescan repositories from
+    /// disk. Mirrors ruff's `--no-cache`.
+    #[arg(long = "no-cache")]
+    pub no_cache: bool,
+
+    /// Directory the scan cache is persisted to. Defaults to
+    /// `REZ_CACHE_DIR`, falling back to a platform cache directory (see
+    /// `RepositoryScanner::resolved_cache_dir`). Mirrors ruff's
+    /// `--cache-dir`.
+    #[arg(long = "cache-dir", value_name = "DIR")]
+    pub cache_dir: Option<PathBuf>,
 }
 
 /// Search result item
@@ -97,44 +109,57 @@ pub fn execute(args: SearchArgs) -> RezCoreResult<()> {
         }
     }
 
-    // Create repository manager
-    let repo_manager = RepositoryManager::new();
+    // The scanner owns both the in-memory scan cache and (unless
+    // --no-cache was given) its on-disk persistence under --cache-dir /
+    // REZ_CACHE_DIR.
+    let scanner = RepositoryScanner::new(ScannerConfig {
+        no_cache: args.no_cache,
+        cache_dir: args.cache_dir.clone(),
+        ..ScannerConfig::default()
+    });
 
     // Execute search
     let runtime = tokio::runtime::Runtime::new().map_err(|e| RezCoreError::Io(e.into()))?;
 
-    runtime.block_on(async {
-        execute_search_async(&repo_manager, &args, before_time, after_time).await
-    })
+    runtime.block_on(async { execute_search_async(&scanner, &args, before_time, after_time).await })
 }
 
 /// Execute search asynchronously
 async fn execute_search_async(
-    repo_manager: &RepositoryManager,
+    scanner: &RepositoryScanner,
     args: &SearchArgs,
     before_time: Option<i64>,
     after_time: Option<i64>,
 ) -> RezCoreResult<()> {
-    // Create search criteria
-    let criteria = create_search_criteria(args, before_time, after_time)?;
+    if !args.no_cache {
+        scanner.load_from_disk().await?;
+    }
+
+    let search_paths = resolve_search_paths(args);
 
     if args.verbose {
         println!("Search criteria:");
-        if let Some(ref pattern) = criteria.name_pattern {
+        if let Some(ref pattern) = args.package {
             println!("  Name pattern: {}", pattern);
         }
-        if let Some(ref version) = criteria.version_requirement {
-            println!("  Version requirement: {}", version);
-        }
-        println!("  Include prerelease: {}", criteria.include_prerelease);
-        if let Some(limit) = criteria.limit {
-            println!("  Limit: {}", limit);
-        }
+        println!("  Repository paths: {}", search_paths.len());
         println!();
     }
 
-    // Search for packages
-    let packages = repo_manager.find_packages(&criteria).await?;
+    let mut packages = Vec::new();
+    for path in &search_paths {
+        if !path.is_dir() {
+            continue;
+        }
+        let scan_result = scanner.scan_repository(path).await?;
+        packages.extend(scan_result.packages.into_iter().map(|p| p.package));
+    }
+
+    if !args.no_cache {
+        scanner.save_to_disk().await?;
+    }
+
+    let packages = filter_by_name_pattern(packages, args.package.as_deref());
 
     if packages.is_empty() {
         let resource_type = determine_resource_type(&args.resource_type, &args.package);
@@ -164,28 +189,48 @@ async fn execute_search_async(
     Ok(())
 }
 
-/// Create search criteria from arguments
-fn create_search_criteria(
-    args: &SearchArgs,
-    _before_time: Option<i64>,
-    _after_time: Option<i64>,
-) -> RezCoreResult<PackageSearchCriteria> {
-    let mut criteria = PackageSearchCriteria::default();
-
-    // Set name pattern
-    if let Some(ref package) = args.package {
-        criteria.name_pattern = Some(package.clone());
+/// Resolve the repository paths to scan: `--paths` if given (ignores
+/// `--no-local`, matching its documented behavior), otherwise
+/// `$REZ_PACKAGES_PATH` unless `--no-local` was passed.
+fn resolve_search_paths(args: &SearchArgs) -> Vec<PathBuf> {
+    if let Some(ref paths) = args.paths {
+        return std::env::split_paths(paths).collect();
     }
 
-    // Set limits
-    if args.latest {
-        criteria.limit = Some(1); // Only latest version per package
+    if args.no_local {
+        return Vec::new();
     }
 
-    // Include prerelease versions by default (can be configured)
-    criteria.include_prerelease = true;
+    std::env::var("REZ_PACKAGES_PATH")
+        .ok()
+        .map(|packages_path| std::env::split_paths(&packages_path).collect())
+        .unwrap_or_default()
+}
+
+/// Keep only packages whose name matches `pattern` (supporting `*`/`?`
+/// glob wildcards, as advertised by [`SearchArgs::package`]'s doc
+/// comment). Returns every package unfiltered if no pattern was given.
+fn filter_by_name_pattern(packages: Vec<Package>, pattern: Option<&str>) -> Vec<Package> {
+    let Some(pattern) = pattern else {
+        return packages;
+    };
 
-    Ok(criteria)
+    if pattern == "*" {
+        return packages;
+    }
+
+    let regex_pattern = pattern
+        .replace("**", ".*")
+        .replace('*', "[^/]*")
+        .replace('?', ".");
+
+    match regex::Regex::new(&format!("^{}$", regex_pattern)) {
+        Ok(regex) => packages
+            .into_iter()
+            .filter(|p| regex.is_match(&p.name))
+            .collect(),
+        Err(_) => packages.into_iter().filter(|p| p.name == pattern).collect(),
+    }
 }
 
 /// Determine resource type from arguments
@@ -365,6 +410,8 @@ mod tests {
             before: "0".to_string(),
             after: "0".to_string(),
             verbose: false,
+            no_cache: false,
+            cache_dir: None,
         };
 
         assert_eq!(args.package, Some("python".to_string()));
@@ -372,6 +419,26 @@ mod tests {
         assert!(args.latest);
     }
 
+    #[test]
+    fn test_filter_by_name_pattern() {
+        let make_package = |name: &str| Package::new(name.to_string());
+        let packages = vec![
+            make_package("python"),
+            make_package("python3"),
+            make_package("maya"),
+        ];
+
+        let filtered = filter_by_name_pattern(packages.clone(), Some("python"));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "python");
+
+        let filtered = filter_by_name_pattern(packages.clone(), Some("python*"));
+        assert_eq!(filtered.len(), 2);
+
+        let filtered = filter_by_name_pattern(packages, None);
+        assert_eq!(filtered.len(), 3);
+    }
+
     #[test]
     fn test_determine_resource_type() {
         assert_eq!(determine_resource_type("package", &None), "packages");
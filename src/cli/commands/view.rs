@@ -41,8 +41,10 @@ pub enum ViewFormat {
 
 /// Execute the view command
 pub fn execute(args: ViewArgs) -> RezCoreResult<()> {
-    // Validate package name
-    crate::cli::utils::validate_package_name(&args.package)?;
+    // Validate package name, suggesting a close match from $REZ_PACKAGES_PATH
+    // when the name looks like a typo of a package that actually exists.
+    let package_name = args.package.split('-').next().unwrap_or(&args.package);
+    crate::cli::utils::validate_package_name_known(package_name, &known_package_names())?;
 
     if args.current {
         return view_current_package(&args);
@@ -51,6 +53,24 @@ pub fn execute(args: ViewArgs) -> RezCoreResult<()> {
     view_package(&args)
 }
 
+/// List the package names found as subdirectories of each path in
+/// `$REZ_PACKAGES_PATH`, for "did you mean?" suggestions. Returns an empty
+/// list (rather than erroring) when the variable isn't set or no paths are
+/// readable, since an inconclusive listing shouldn't block `view`.
+fn known_package_names() -> Vec<String> {
+    let Ok(packages_path) = std::env::var("REZ_PACKAGES_PATH") else {
+        return Vec::new();
+    };
+
+    std::env::split_paths(&packages_path)
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect()
+}
+
 /// View a package from the current context
 fn view_current_package(args: &ViewArgs) -> RezCoreResult<()> {
     // TODO: Implement current context package viewing
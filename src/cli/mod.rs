@@ -81,7 +81,8 @@ pub enum RezCommand {
     /// Copy packages between repositories
     Cp(commands::cp::CpArgs),
 
-    /// Move packages between repositories
+    /// Move packages between repositories on a local or shared filesystem
+    /// (remote transports such as S3/SSH are not implemented yet)
     Mv(commands::mv::MvArgs),
 
     /// Remove packages from repositories
@@ -3,7 +3,7 @@
 //! Common utilities and helper functions for CLI commands.
 
 use rez_next_common::{error::RezCoreResult, RezCoreError};
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 
 /// Print formatted output with proper error handling
 pub fn print_output(content: &str) -> RezCoreResult<()> {
@@ -12,6 +12,67 @@ pub fn print_output(content: &str) -> RezCoreResult<()> {
     Ok(())
 }
 
+/// Machine-readable output mode, chosen by [`OutputFormat::resolve`] from
+/// an explicit value or the `REZ_OUTPUT_FORMAT` env var and applied by
+/// [`emit`]. `Human` defers to the caller's hand-formatted text;
+/// `Json`/`NdJson` serialize the value instead. No command declares a
+/// `--format`/`-o` argument of this type or calls [`emit`], so no
+/// command's output depends on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text (the default, unchanged from today's output)
+    #[default]
+    Human,
+    /// Pretty-printed JSON
+    Json,
+    /// Newline-delimited JSON (one compact JSON value per line)
+    NdJson,
+}
+
+impl OutputFormat {
+    /// Resolve the effective format: an explicit CLI flag value wins, then
+    /// `$REZ_OUTPUT_FORMAT`, then [`OutputFormat::Human`].
+    pub fn resolve(flag: Option<OutputFormat>) -> OutputFormat {
+        flag.or_else(|| {
+            std::env::var("REZ_OUTPUT_FORMAT")
+                .ok()
+                .and_then(|value| Self::from_str_loose(&value))
+        })
+        .unwrap_or_default()
+    }
+
+    fn from_str_loose(value: &str) -> Option<OutputFormat> {
+        match value.to_lowercase().as_str() {
+            "human" => Some(OutputFormat::Human),
+            "json" => Some(OutputFormat::Json),
+            "ndjson" | "nd-json" | "jsonl" => Some(OutputFormat::NdJson),
+            _ => None,
+        }
+    }
+}
+
+/// Emit `value` in `fmt`, falling through to `human` for [`OutputFormat::Human`]
+/// and serializing `value` as pretty or line-delimited JSON otherwise.
+/// Errors (via [`print_error`]) stay on stderr regardless of `fmt`, so a
+/// caller piping structured stdout into another tool still sees failures.
+pub fn emit<T: serde::Serialize>(
+    value: &T,
+    fmt: OutputFormat,
+    human: impl FnOnce() -> RezCoreResult<()>,
+) -> RezCoreResult<()> {
+    match fmt {
+        OutputFormat::Human => human(),
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(value).map_err(RezCoreError::Serde)?;
+            print_output(&format!("{}\n", json))
+        }
+        OutputFormat::NdJson => {
+            let json = serde_json::to_string(value).map_err(RezCoreError::Serde)?;
+            print_output(&format!("{}\n", json))
+        }
+    }
+}
+
 /// Print formatted error message to stderr
 pub fn print_error(message: &str) -> RezCoreResult<()> {
     eprintln!("Error: {}", message);
@@ -19,31 +80,84 @@ pub fn print_error(message: &str) -> RezCoreResult<()> {
     Ok(())
 }
 
-/// Format a list of items in columns
+/// Padding inserted after each column in [`format_columns`]'s layout.
+const COLUMN_PADDING: usize = 2;
+
+/// Format a list of items in columns, `ls`-style: laid out column-major
+/// (items fill down the first column before starting the second) with as
+/// many columns as fit `max_width`, each sized to its own widest entry.
+/// When stdout isn't a TTY, falls back to one item per line so piped
+/// output isn't column-padded for a human who isn't reading it.
 pub fn format_columns(items: &[String], max_width: usize) -> String {
     if items.is_empty() {
         return String::new();
     }
 
-    // Simple column formatting - can be enhanced later
-    let max_item_width = items.iter().map(|s| s.len()).max().unwrap_or(0);
-    let columns = if max_item_width > 0 {
-        (max_width / (max_item_width + 2)).max(1)
-    } else {
-        1
-    };
+    if !io::stdout().is_terminal() {
+        return items.join("\n");
+    }
+
+    let widths: Vec<usize> = items.iter().map(|s| s.chars().count()).collect();
+    let columns = best_column_count(&widths, max_width);
+    let rows = items.len().div_ceil(columns);
+
+    // Column-major: column c's width is the widest item among rows [c*rows, (c+1)*rows).
+    let column_widths: Vec<usize> = (0..columns)
+        .map(|c| {
+            (0..rows)
+                .filter_map(|r| widths.get(c * rows + r))
+                .copied()
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
 
     let mut result = String::new();
-    for (i, item) in items.iter().enumerate() {
-        if i > 0 && i % columns == 0 {
-            result.push('\n');
+    for r in 0..rows {
+        for c in 0..columns {
+            let Some(item) = items.get(c * rows + r) else {
+                continue;
+            };
+            let is_last_in_row = c == columns - 1 || c * rows + r + rows >= items.len();
+            if is_last_in_row {
+                result.push_str(item);
+            } else {
+                result.push_str(&format!(
+                    "{:<width$}",
+                    item,
+                    width = column_widths[c] + COLUMN_PADDING
+                ));
+            }
         }
-        result.push_str(&format!("{:<width$}", item, width = max_item_width + 2));
+        result.push('\n');
     }
+    result.pop(); // drop the trailing newline to match the old single-block-of-text contract
 
     result
 }
 
+/// Largest number of column-major columns whose per-column max widths
+/// (plus padding) still sum within `max_width`.
+fn best_column_count(widths: &[usize], max_width: usize) -> usize {
+    for columns in (1..=widths.len()).rev() {
+        let rows = widths.len().div_ceil(columns);
+        let total: usize = (0..columns)
+            .map(|c| {
+                (0..rows)
+                    .filter_map(|r| widths.get(c * rows + r))
+                    .copied()
+                    .max()
+                    .unwrap_or(0)
+                    + COLUMN_PADDING
+            })
+            .sum();
+        if total <= max_width {
+            return columns;
+        }
+    }
+    1
+}
+
 /// Validate package name format
 pub fn validate_package_name(name: &str) -> RezCoreResult<()> {
     if name.is_empty() {
@@ -62,6 +176,63 @@ pub fn validate_package_name(name: &str) -> RezCoreResult<()> {
     Ok(())
 }
 
+/// Check `name` against a list of known package names, failing with a
+/// "did you mean `<name>`?" hint (cargo-style) when it's close to one of
+/// them but doesn't match exactly. A `known_packages` list that doesn't
+/// contain `name` is otherwise treated as inconclusive (e.g. an empty
+/// repository listing), not an error.
+pub fn validate_package_name_known(name: &str, known_packages: &[String]) -> RezCoreResult<()> {
+    validate_package_name(name)?;
+
+    if known_packages.is_empty() || known_packages.iter().any(|p| p == name) {
+        return Ok(());
+    }
+
+    let mut message = format!("Unknown package '{}'", name);
+    if let Some(suggestion) = suggest_closest(name, known_packages) {
+        message.push_str(&format!(", did you mean `{}`?", suggestion));
+    }
+    Err(RezCoreError::PackageParse(message))
+}
+
+/// Find the candidate closest to `input` by case-insensitive Levenshtein
+/// distance, if any candidate is within `max(input.len() / 3, 1)`
+/// (capped at 3) edits — close enough to plausibly be a typo rather than
+/// an unrelated name.
+pub fn suggest_closest(input: &str, candidates: &[String]) -> Option<String> {
+    let threshold = (input.chars().count() / 3).max(1).min(3);
+    let input_lower = input.to_lowercase();
+
+    candidates
+        .iter()
+        .map(|candidate| (candidate, levenshtein_distance(&input_lower, &candidate.to_lowercase())))
+        .min_by_key(|(_, distance)| *distance)
+        .filter(|(_, distance)| *distance <= threshold)
+        .map(|(candidate, _)| candidate.clone())
+}
+
+/// Classic Levenshtein edit distance between `a` and `b`, computed with a
+/// two-row dynamic-programming table so only O(n) extra space is needed.
+pub(crate) fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let n = b.len();
+
+    let mut prev: Vec<usize> = (0..=n).collect();
+    let mut cur: Vec<usize> = vec![0; n + 1];
+
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=n {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[n]
+}
+
 /// Parse environment variable style arguments (KEY=VALUE)
 pub fn parse_env_var(arg: &str) -> RezCoreResult<(String, String)> {
     if let Some(pos) = arg.find('=') {
@@ -85,20 +256,101 @@ pub fn parse_env_var(arg: &str) -> RezCoreResult<(String, String)> {
 
 /// Get terminal width for formatting
 pub fn get_terminal_width() -> usize {
-    // Default width if we can't determine terminal size
     const DEFAULT_WIDTH: usize = 80;
 
-    // Try to get terminal width from environment or system
+    if let Some(width) = terminal_size::query_width() {
+        return width;
+    }
+
     if let Ok(width_str) = std::env::var("COLUMNS") {
         if let Ok(width) = width_str.parse::<usize>() {
             return width;
         }
     }
 
-    // TODO: Use a proper terminal size detection library if needed
     DEFAULT_WIDTH
 }
 
+/// Terminal column-width detection: `TIOCGWINSZ` on Unix,
+/// `GetConsoleScreenBufferInfo` on Windows. Returns `None` when stdout
+/// isn't backed by a real console (piped/redirected), so callers fall
+/// back to `$COLUMNS` or a fixed default.
+mod terminal_size {
+    #[cfg(unix)]
+    pub fn query_width() -> Option<usize> {
+        use std::os::unix::io::AsRawFd;
+
+        // `TIOCGWINSZ`'s value is platform-specific (e.g. Linux vs.
+        // macOS/BSD), so use `libc`'s per-target constant rather than a
+        // hand-rolled, Linux-only magic number.
+        let mut winsize: libc::winsize = unsafe { std::mem::zeroed() };
+
+        let fd = std::io::stdout().as_raw_fd();
+        let result = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut winsize) };
+
+        if result == 0 && winsize.ws_col > 0 {
+            Some(winsize.ws_col as usize)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn query_width() -> Option<usize> {
+        use std::os::windows::io::AsRawHandle;
+
+        #[repr(C)]
+        struct Coord {
+            x: i16,
+            y: i16,
+        }
+
+        #[repr(C)]
+        struct SmallRect {
+            left: i16,
+            top: i16,
+            right: i16,
+            bottom: i16,
+        }
+
+        #[repr(C)]
+        struct ConsoleScreenBufferInfo {
+            dw_size: Coord,
+            dw_cursor_position: Coord,
+            w_attributes: u16,
+            sr_window: SmallRect,
+            dw_maximum_window_size: Coord,
+        }
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetConsoleScreenBufferInfo(
+                console_output: *mut std::ffi::c_void,
+                console_screen_buffer_info: *mut ConsoleScreenBufferInfo,
+            ) -> i32;
+        }
+
+        let handle = std::io::stdout().as_raw_handle();
+        let mut info: ConsoleScreenBufferInfo = unsafe { std::mem::zeroed() };
+
+        let result =
+            unsafe { GetConsoleScreenBufferInfo(handle as *mut std::ffi::c_void, &mut info) };
+
+        if result != 0 {
+            let width = info.sr_window.right - info.sr_window.left + 1;
+            if width > 0 {
+                return Some(width as usize);
+            }
+        }
+        None
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    pub fn query_width() -> Option<usize> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +365,26 @@ mod tests {
         assert!(validate_package_name("invalid package").is_err());
     }
 
+    #[test]
+    fn test_suggest_closest() {
+        let candidates = vec!["python".to_string(), "numpy".to_string(), "scipy".to_string()];
+
+        assert_eq!(suggest_closest("pyhton", &candidates), Some("python".to_string()));
+        assert_eq!(suggest_closest("PYTHON", &candidates), Some("python".to_string()));
+        assert_eq!(suggest_closest("completely_unrelated_name", &candidates), None);
+    }
+
+    #[test]
+    fn test_validate_package_name_known() {
+        let known = vec!["python".to_string(), "numpy".to_string()];
+
+        assert!(validate_package_name_known("python", &known).is_ok());
+        assert!(validate_package_name_known("anything", &[]).is_ok());
+
+        let err = validate_package_name_known("pyhton", &known).unwrap_err();
+        assert!(err.to_string().contains("did you mean `python`?"));
+    }
+
     #[test]
     fn test_parse_env_var() {
         assert_eq!(
@@ -138,4 +410,57 @@ mod tests {
         let result = format_columns(&items, 80);
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn test_best_column_count_packs_more_columns_into_wider_terminal() {
+        let widths = vec![4, 4, 4, 4, 4, 4];
+        assert_eq!(best_column_count(&widths, 11), 1);
+        assert!(best_column_count(&widths, 40) > 1);
+    }
+
+    #[test]
+    fn test_output_format_resolve_prefers_explicit_flag() {
+        assert_eq!(
+            OutputFormat::resolve(Some(OutputFormat::Json)),
+            OutputFormat::Json
+        );
+    }
+
+    #[test]
+    fn test_output_format_from_str_loose() {
+        assert_eq!(OutputFormat::from_str_loose("json"), Some(OutputFormat::Json));
+        assert_eq!(OutputFormat::from_str_loose("NDJSON"), Some(OutputFormat::NdJson));
+        assert_eq!(OutputFormat::from_str_loose("human"), Some(OutputFormat::Human));
+        assert_eq!(OutputFormat::from_str_loose("bogus"), None);
+    }
+
+    #[test]
+    fn test_emit_json_serializes_value_instead_of_calling_human() {
+        #[derive(serde::Serialize)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let called_human = std::cell::Cell::new(false);
+        emit(&Point { x: 1, y: 2 }, OutputFormat::Json, || {
+            called_human.set(true);
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(!called_human.get());
+    }
+
+    #[test]
+    fn test_emit_human_calls_fallback() {
+        let called_human = std::cell::Cell::new(false);
+        emit(&"ignored", OutputFormat::Human, || {
+            called_human.set(true);
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(called_human.get());
+    }
 }